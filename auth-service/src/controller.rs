@@ -11,10 +11,14 @@ use common::{
 };
 use mongodb::Database;
 use utoipa::OpenApi;
+use validator::Validate;
 
 use crate::{
     doc::ApiDoc,
-    model::{Auth, CreateAuthRequest, LoginRequest, LoginResponse},
+    model::{
+        AcceptInvitationRequest, Auth, CreateAuthRequest, CreateInvitationRequest,
+        GetUserRolesRequest, LoginRequest, LoginResponse, UserRole,
+    },
     service,
 };
 
@@ -25,8 +29,11 @@ pub fn config(cfg: &mut web::ServiceConfig) {
         .service(health_check)
         .service(get_auths)
         .service(get_me)
+        .service(get_user_roles)
         .service(register)
         .service(login)
+        .service(create_invitation)
+        .service(accept_invitation)
         .service(delete_auth_by_user_id)
         .service(web::resource("/doc").route(web::get().to(|| async {
             HttpResponse::Found()
@@ -128,6 +135,58 @@ async fn get_me(req: HttpRequest) -> impl Responder {
 
 // =============================================================================================================================
 
+#[utoipa::path(
+    post,
+    path = "/api/auth/roles",
+    tag = "Internal Endpoints",
+    summary = "Retrieve user roles",
+    description = "Returns the role of each credential matching the given user ids and/or roles. Used by the other services to join role information. Restricted to internal requests using an internal JWT.",
+    request_body = GetUserRolesRequest,
+    responses(
+        (status = 200, description = "User roles were successfully retrieved.", body = DocSuccessApiResponse<Vec<UserRole>>),
+        (status = 400, description = "A user id is invalid.", body = DocErrorApiResponse),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred during the user roles retrieval.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/roles")]
+async fn get_user_roles(
+    db: Data<Database>,
+    payload: Json<GetUserRolesRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(jwt_payload) => jwt_payload,
+        Err(err_res) => return err_res,
+    };
+
+    let payload = payload.into_inner();
+    if let Err(e) = payload.validate() {
+        let response: ApiResponse<()> = ApiResponse::error(
+            "An error occured during the user roles retrieving.",
+            e.to_string(),
+        );
+        return HttpResponse::BadRequest().json(response);
+    }
+
+    match service::get_user_roles(&db, payload).await {
+        Ok(roles) => {
+            let response: ApiResponse<Vec<UserRole>> =
+                ApiResponse::success("User roles were successfully retrieved.", Some(roles));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured during the user roles retrieving.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
 #[utoipa::path(
     delete,
     path = "/api/auth/{user_id}",
@@ -245,3 +304,85 @@ async fn login(db: Data<Database>, payload: Json<LoginRequest>) -> impl Responde
 }
 
 // =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/invitations",
+    tag = "Internal Endpoints",
+    summary = "Invite a user",
+    description = "Creates pending credentials for an existing user profile and sends them an invitation to set their password. Inviting a user whose invitation is still pending sends a new one. Restricted to internal requests using an internal JWT.",
+    request_body = CreateInvitationRequest,
+    responses(
+        (status = 200, description = "Invitation was successfully sent.", body = DocSuccessApiResponse<Auth>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred during the invitation.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/invitations")]
+async fn create_invitation(
+    db: Data<Database>,
+    payload: Json<CreateInvitationRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(jwt_payload) => jwt_payload,
+        Err(err_res) => return err_res,
+    };
+
+    let payload = payload.into_inner();
+
+    match service::create_invitation(&db, payload).await {
+        Ok(credentials) => {
+            let response: ApiResponse<Auth> =
+                ApiResponse::success("Invitation was successfully sent.", Some(credentials));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("An error occured during the invitation.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/invitations/accept",
+    tag = "Public Endpoints",
+    summary = "Accept an invitation",
+    description = "Sets the password of a pending account using the token received in the invitation. Returns a JWT token upon success.",
+    request_body = AcceptInvitationRequest,
+    responses(
+        (status = 200, description = "Invitation was successfully accepted.", body = DocSuccessApiResponse<LoginResponse>),
+        (status = 500, description = "An error occurred while accepting the invitation.", body = DocErrorApiResponse)
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[post("/invitations/accept")]
+async fn accept_invitation(
+    db: Data<Database>,
+    payload: Json<AcceptInvitationRequest>,
+) -> impl Responder {
+    let data = payload.into_inner();
+
+    match service::accept_invitation(&db, data).await {
+        Ok(token) => {
+            let response: ApiResponse<LoginResponse> =
+                ApiResponse::success("Invitation was successfully accepted.", Some(token));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured while accepting the invitation.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
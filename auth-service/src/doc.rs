@@ -3,10 +3,14 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder};
 use utoipa::{Modify, OpenApi, openapi::security::SecurityScheme};
 
 use crate::controller::{
-    __path_delete_auth_by_user_id, __path_get_auths, __path_get_me, __path_health_check,
-    __path_login, __path_register,
+    __path_accept_invitation, __path_create_invitation, __path_delete_auth_by_user_id,
+    __path_get_auths, __path_get_me, __path_get_user_roles, __path_health_check, __path_login,
+    __path_register,
+};
+use crate::model::{
+    AcceptInvitationRequest, Auth, CreateAuthRequest, CreateInvitationRequest, GetUserRolesRequest,
+    LoginRequest, LoginResponse, UserRole,
 };
-use crate::model::{Auth, CreateAuthRequest, LoginRequest, LoginResponse};
 use common::models::AuthRole;
 
 // =============================================================================================================================
@@ -26,9 +30,12 @@ and internal endpoints for the management of credentials.
         health_check,
         get_auths,
         get_me,
+        get_user_roles,
         delete_auth_by_user_id,
         register,
-        login
+        login,
+        create_invitation,
+        accept_invitation
     ),
     components(
         schemas(
@@ -36,6 +43,10 @@ and internal endpoints for the management of credentials.
             CreateAuthRequest,
             LoginRequest,
             LoginResponse,
+            GetUserRolesRequest,
+            UserRole,
+            CreateInvitationRequest,
+            AcceptInvitationRequest,
             AuthRole,
             ExternalClaims
         )
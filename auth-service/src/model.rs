@@ -1,8 +1,11 @@
 use common::{
     models::AuthRole,
-    utils::utils::{serialize_option_object_id_as_hex_string, trim, trim_lowercase},
+    utils::utils::{
+        deserialize_option_datetime_from_any, serialize_option_datetime_as_rfc3339_string,
+        serialize_option_object_id_as_hex_string, trim, trim_lowercase,
+    },
 };
-use mongodb::bson::oid::ObjectId;
+use mongodb::bson::{DateTime, oid::ObjectId, serde_helpers::serialize_object_id_as_hex_string};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::{Validate, ValidationError};
@@ -29,6 +32,46 @@ pub struct Auth {
     #[serde(rename = "user_id")]
     #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
     pub user_id: ObjectId,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "Xb3kP9qLm2Zt7Wc4Rn8Yd1Hf6Js0Va5E", value_type = Option<String>)]
+    pub invitation_token: Option<String>,
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-30T09:00:00.000Z", value_type = Option<String>)]
+    pub invitation_expires_at: Option<DateTime>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserRole {
+    #[serde(
+        rename = "user_id",
+        serialize_with = "serialize_object_id_as_hex_string"
+    )]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[schema(example = "User", value_type = String)]
+    pub role: AuthRole,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct GetUserRolesRequest {
+    #[validate(custom(function = "validate_user_ids"))]
+    #[schema(example = json!(["63d88106c3f7903ba0f9211a"]), value_type = Option<Vec<String>>)]
+    pub user_ids: Option<Vec<String>>,
+
+    #[schema(example = json!(["Operator", "Admin"]), value_type = Option<Vec<String>>)]
+    pub roles: Option<Vec<AuthRole>>,
 }
 
 // =============================================================================================================================
@@ -113,36 +156,108 @@ pub struct CreateAuthRequest {
     ))]
     #[schema(example = "SecurePass123!", value_type = String)]
     pub confirm_password: String,
+
+    #[serde(default)]
+    #[schema(example = "2025-03", value_type = Option<String>)]
+    pub terms_version: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = false)]
+    pub marketing_consent: Option<bool>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateInvitationRequest {
+    #[serde(rename = "user_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(
+    function = "validate_invitation_passwords",
+    skip_on_field_errors = false
+))]
+pub struct AcceptInvitationRequest {
+    #[serde(deserialize_with = "trim")]
+    #[validate(length(min = 1, message = "token is required"))]
+    #[schema(example = "Xb3kP9qLm2Zt7Wc4Rn8Yd1Hf6Js0Va5E", value_type = String)]
+    pub token: String,
+
+    #[serde(deserialize_with = "trim")]
+    #[validate(length(
+        min = 12,
+        max = 32,
+        message = "password must be between 12 and 32 characters"
+    ))]
+    #[schema(example = "SecurePass123!", value_type = String)]
+    pub password: String,
+
+    #[serde(deserialize_with = "trim")]
+    #[validate(length(
+        min = 12,
+        max = 32,
+        message = "password must be between 12 and 32 characters"
+    ))]
+    #[schema(example = "SecurePass123!", value_type = String)]
+    pub confirm_password: String,
+}
+
+// =============================================================================================================================
+
+fn validate_user_ids(user_ids: &[String]) -> Result<(), ValidationError> {
+    if user_ids.iter().any(|id| ObjectId::parse_str(id).is_err()) {
+        let mut error = ValidationError::new("user_id_invalid");
+        error.message = Some("Every user id must be a valid id.".into());
+        return Err(error);
+    }
+    Ok(())
 }
 
 // =============================================================================================================================
 
 fn validate_passwords(req: &CreateAuthRequest) -> Result<(), ValidationError> {
-    if req.password != req.confirm_password {
+    validate_password_rules(&req.password, &req.confirm_password)
+}
+
+// =============================================================================================================================
+
+fn validate_invitation_passwords(req: &AcceptInvitationRequest) -> Result<(), ValidationError> {
+    validate_password_rules(&req.password, &req.confirm_password)
+}
+
+// =============================================================================================================================
+
+fn validate_password_rules(password: &str, confirm_password: &str) -> Result<(), ValidationError> {
+    if password != confirm_password {
         let mut error = ValidationError::new("password_mismatch");
         error.message = Some("password and confirm_password must match".into());
         return Err(error);
     }
 
-    if !req.password.chars().any(|c| c.is_lowercase()) {
+    if !password.chars().any(|c| c.is_lowercase()) {
         let mut error = ValidationError::new("password_no_lowercase");
         error.message = Some("Password must contain at least one lowercase letter.".into());
         return Err(error);
     }
 
-    if !req.password.chars().any(|c| c.is_uppercase()) {
+    if !password.chars().any(|c| c.is_uppercase()) {
         let mut error = ValidationError::new("password_no_uppercase");
         error.message = Some("Password must contain at least one uppercase letter.".into());
         return Err(error);
     }
 
-    if !req.password.chars().any(|c| c.is_ascii_digit()) {
+    if !password.chars().any(|c| c.is_ascii_digit()) {
         let mut error = ValidationError::new("password_no_digit");
         error.message = Some("Password must contain at least one digit.".into());
         return Err(error);
     }
 
-    if !req.password.chars().any(|c| !c.is_alphanumeric()) {
+    if !password.chars().any(|c| !c.is_alphanumeric()) {
         let mut error = ValidationError::new("password_no_special");
         error.message = Some("Password must contain at least one special character.".into());
         return Err(error);
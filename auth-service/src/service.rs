@@ -4,25 +4,29 @@ use common::{
     models::{AuthRole, TriggerNotificationRequest},
     utils::{
         api_response::{ApiResponse, ObjectIdWrapper},
-        utils::trigger_notification,
+        utils::{INVITATION_URL, trigger_notification},
     },
 };
 use futures::TryStreamExt;
 use mongodb::{
     Collection, Database,
-    bson::{doc, oid::ObjectId},
+    bson::{DateTime, Document, doc, oid::ObjectId, to_bson},
 };
+use rand::{Rng, distr::Alphanumeric};
 use serde_json::json;
 use std::{thread, time::Duration};
 use validator::Validate;
 
 use crate::model::{
-    Auth, CreateAuthRequest, CreateUserInternalResponse, LoginRequest, LoginResponse,
+    AcceptInvitationRequest, Auth, CreateAuthRequest, CreateInvitationRequest,
+    CreateUserInternalResponse, GetUserRolesRequest, LoginRequest, LoginResponse, UserRole,
 };
 
 // =============================================================================================================================
 
 const COLLECTION_NAME: &str = "auth";
+const INVITATION_VALIDITY_DAYS: i64 = 7;
+const MAX_NOTIFICATION_LENGTH: usize = 100;
 
 // =============================================================================================================================
 
@@ -36,6 +40,36 @@ pub async fn get_auths(db: &Database) -> Result<Vec<Auth>, Box<dyn std::error::E
 
 // =============================================================================================================================
 
+pub async fn get_user_roles(
+    db: &Database,
+    payload: GetUserRolesRequest,
+) -> Result<Vec<UserRole>, Box<dyn std::error::Error>> {
+    let mut filter = Document::new();
+
+    if let Some(user_ids) = payload.user_ids {
+        let user_ids = user_ids
+            .iter()
+            .map(ObjectId::parse_str)
+            .collect::<Result<Vec<ObjectId>, _>>()?;
+        filter.insert("user_id", doc! { "$in": user_ids });
+    }
+
+    if let Some(roles) = payload.roles {
+        filter.insert("role", doc! { "$in": to_bson(&roles)? });
+    }
+
+    let collection: Collection<UserRole> = db.collection(COLLECTION_NAME);
+    let cursor = collection
+        .find(filter)
+        .projection(doc! { "_id": 0, "user_id": 1, "role": 1 })
+        .await?;
+    let roles = cursor.try_collect().await?;
+
+    Ok(roles)
+}
+
+// =============================================================================================================================
+
 pub async fn delete_auth_by_user_id(
     db: &Database,
     user_id: String,
@@ -65,6 +99,8 @@ pub async fn register(
         "email": &payload.email,
         "first_name": &payload.first_name,
         "last_name": &payload.last_name,
+        "terms_version": &payload.terms_version,
+        "marketing_consent": &payload.marketing_consent,
     });
 
     let internal_token = encode_internal_jwt()?;
@@ -95,6 +131,8 @@ pub async fn register(
         password: hashed_password,
         role: AuthRole::User,
         user_id: id,
+        invitation_token: None,
+        invitation_expires_at: None,
     };
 
     let result = collection.insert_one(&credential).await?;
@@ -148,6 +186,12 @@ pub async fn login(
         None => return Err("No user with this id exist".into()),
     };
 
+    if credentials.invitation_token.is_some() {
+        return Err(
+            "This account is pending, use the invitation you received to set a password".into(),
+        );
+    }
+
     if let Err(_) | Ok(false) = verify(&payload.password, &credentials.password) {
         thread::sleep(Duration::from_millis(300));
         return Err("Invalid email or password".into());
@@ -159,3 +203,146 @@ pub async fn login(
 }
 
 // =============================================================================================================================
+
+pub async fn create_invitation(
+    db: &Database,
+    payload: CreateInvitationRequest,
+) -> Result<Auth, Box<dyn std::error::Error>> {
+    let collection: Collection<Auth> = db.collection(COLLECTION_NAME);
+
+    let existing_credentials = collection
+        .find_one(doc! { "user_id": payload.user_id })
+        .await?;
+
+    if existing_credentials
+        .as_ref()
+        .is_some_and(|credentials| credentials.invitation_token.is_none())
+    {
+        return Err("This user already has credentials".into());
+    }
+
+    let token: String = rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect();
+    let expires_at = DateTime::from_millis(
+        DateTime::now().timestamp_millis() + INVITATION_VALIDITY_DAYS * 24 * 60 * 60 * 1000,
+    );
+
+    // The id is left out of the replacement, which would otherwise store it as a string next to `_id`.
+    let mut credential = Auth {
+        id: None,
+        password: String::new(),
+        role: AuthRole::User,
+        user_id: payload.user_id,
+        invitation_token: Some(token.clone()),
+        invitation_expires_at: Some(expires_at),
+    };
+
+    let res = collection
+        .replace_one(doc! { "user_id": payload.user_id }, &credential)
+        .upsert(true)
+        .await?;
+
+    credential.id = existing_credentials
+        .and_then(|credentials| credentials.id)
+        .or_else(|| res.upserted_id.and_then(|id| id.as_object_id()));
+
+    let notification_data = TriggerNotificationRequest {
+        message: invitation_message(&INVITATION_URL, &token),
+        user_id: credential.user_id,
+    };
+
+    if let Err(e) = trigger_notification(notification_data).await {
+        collection
+            .delete_one(doc! { "user_id": payload.user_id })
+            .await?;
+        return Err(e);
+    }
+
+    Ok(credential)
+}
+
+// =============================================================================================================================
+
+// Notifications are limited to 100 characters, so the link is only sent when it fits, and the token alone otherwise.
+fn invitation_message(invitation_url: &str, token: &str) -> String {
+    let message = format!("Set your 4WEBD password: {}{}", invitation_url, token);
+    if message.chars().count() <= MAX_NOTIFICATION_LENGTH {
+        return message;
+    }
+    format!("Your 4WEBD invitation code: {}", token)
+}
+
+// =============================================================================================================================
+
+pub async fn accept_invitation(
+    db: &Database,
+    payload: AcceptInvitationRequest,
+) -> Result<LoginResponse, Box<dyn std::error::Error>> {
+    payload.validate()?;
+
+    let collection: Collection<Auth> = db.collection(COLLECTION_NAME);
+
+    let credentials = match collection
+        .find_one(doc! { "invitation_token": &payload.token })
+        .await?
+    {
+        Some(credentials) => credentials,
+        None => return Err("This invitation is invalid or was already used".into()),
+    };
+
+    if credentials
+        .invitation_expires_at
+        .is_none_or(|expires_at| expires_at < DateTime::now())
+    {
+        return Err("This invitation has expired".into());
+    }
+
+    let hashed_password = hash(&payload.password, DEFAULT_COST)?;
+    let update_doc = doc! {
+        "$set": { "password": hashed_password },
+        "$unset": { "invitation_token": "", "invitation_expires_at": "" }
+    };
+
+    let res = collection
+        .update_one(
+            doc! { "user_id": credentials.user_id, "invitation_token": &payload.token },
+            update_doc,
+        )
+        .await?;
+
+    if res.modified_count == 0 {
+        return Err("This invitation is invalid or was already used".into());
+    }
+
+    let token = encode_external_jwt(credentials.user_id.to_hex(), credentials.role)?;
+
+    Ok(LoginResponse { token })
+}
+
+// =============================================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invitation_message_fits_in_a_notification() {
+        let token = "Xb3kP9qLm2Zt7Wc4Rn8Yd1Hf6Js0Va5E";
+        let default_url = "http://localhost/invitation?token=";
+        let production_url = "https://tickets.4webd-events.example.com/account/invitation?token=";
+
+        for invitation_url in [default_url, production_url] {
+            let notification = TriggerNotificationRequest {
+                message: invitation_message(invitation_url, token),
+                user_id: ObjectId::new(),
+            };
+            assert!(notification.validate().is_ok(), "{}", notification.message);
+            assert!(notification.message.ends_with(token));
+        }
+        assert!(invitation_message(default_url, token).contains(default_url));
+        assert!(!invitation_message(production_url, token).contains(production_url));
+    }
+}
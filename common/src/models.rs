@@ -10,7 +10,7 @@ use crate::utils::utils::{
 
 // =============================================================================================================================
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
 pub enum AuthRole {
     User,
     EventCreator,
@@ -18,6 +18,69 @@ pub enum AuthRole {
     Admin,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum OrganisationRole {
+    Owner,
+    Manager,
+    BoxOffice,
+    Viewer,
+}
+
+impl OrganisationRole {
+    pub fn can_manage_organisation(&self) -> bool {
+        matches!(self, OrganisationRole::Owner)
+    }
+
+    pub fn can_manage_events(&self) -> bool {
+        matches!(self, OrganisationRole::Owner | OrganisationRole::Manager)
+    }
+
+    pub fn can_manage_tickets(&self) -> bool {
+        matches!(
+            self,
+            OrganisationRole::Owner | OrganisationRole::Manager | OrganisationRole::BoxOffice
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default, ToSchema)]
+pub enum EventStatus {
+    Draft,
+    Scheduled,
+    #[default]
+    Published,
+    Postponed,
+    Cancelled,
+    Completed,
+}
+
+impl EventStatus {
+    pub fn is_on_sale(&self) -> bool {
+        matches!(self, EventStatus::Published)
+    }
+
+    pub fn is_public(&self) -> bool {
+        !matches!(self, EventStatus::Draft | EventStatus::Scheduled)
+    }
+
+    pub fn can_transition_to(&self, status: EventStatus) -> bool {
+        use EventStatus::*;
+        matches!(
+            (self, status),
+            (Draft, Scheduled)
+                | (Draft, Published)
+                | (Scheduled, Scheduled)
+                | (Scheduled, Draft)
+                | (Scheduled, Published)
+                | (Published, Postponed)
+                | (Published, Cancelled)
+                | (Published, Completed)
+                | (Postponed, Published)
+                | (Postponed, Cancelled)
+        )
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum TriggerNotificationType {
     Email,
@@ -31,7 +94,7 @@ pub enum TriggerNotificationStatus {
     Failed,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub enum PaymentCurrency {
     Eur,
     Usd,
@@ -54,6 +117,14 @@ pub struct TriggerNotificationRequest {
 
 // =============================================================================================================================
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventOrganiserRole {
+    #[schema(example = "Manager", value_type = Option<String>)]
+    pub role: Option<OrganisationRole>,
+}
+
+// =============================================================================================================================
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TriggerNotificationResponse {
     #[serde(
@@ -5,9 +5,16 @@ use utoipa::ToSchema;
 
 // =============================================================================================================================
 
+// Error comes first: an untagged error body would also match Success and lose its error field.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ApiResponse<T> {
+    Error {
+        #[serde(default = "default_false")]
+        success: bool,
+        message: String,
+        error: String,
+    },
     Success {
         #[serde(default = "default_true")]
         success: bool,
@@ -15,12 +22,6 @@ pub enum ApiResponse<T> {
         #[serde(skip_serializing_if = "Option::is_none")]
         data: Option<T>,
     },
-    Error {
-        #[serde(default = "default_false")]
-        success: bool,
-        message: String,
-        error: String,
-    },
 }
 
 // =============================================================================================================================
@@ -51,6 +52,18 @@ impl<T> ApiResponse<T> {
             error: error.into(),
         }
     }
+
+    pub fn into_result(self) -> Result<Option<T>, String> {
+        match self {
+            ApiResponse::Success {
+                success: true,
+                data,
+                ..
+            } => Ok(data),
+            ApiResponse::Success { message, .. } => Err(message),
+            ApiResponse::Error { error, .. } => Err(error),
+        }
+    }
 }
 
 // =============================================================================================================================
@@ -90,3 +103,55 @@ pub struct DocErrorApiResponse {
 }
 
 // =============================================================================================================================
+
+#[derive(Debug)]
+pub struct BadRequestError(pub String);
+
+impl std::fmt::Display for BadRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for BadRequestError {}
+
+// =============================================================================================================================
+
+#[derive(Debug)]
+pub struct ConflictError(pub String);
+
+impl std::fmt::Display for ConflictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ConflictError {}
+
+// =============================================================================================================================
+
+#[derive(Debug)]
+pub struct TooManyRequestsError(pub String);
+
+impl std::fmt::Display for TooManyRequestsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for TooManyRequestsError {}
+
+// =============================================================================================================================
+
+#[derive(Debug)]
+pub struct NotFoundError(pub String);
+
+impl std::fmt::Display for NotFoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for NotFoundError {}
+
+// =============================================================================================================================
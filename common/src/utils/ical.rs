@@ -0,0 +1,161 @@
+use mongodb::bson::DateTime;
+
+use crate::models::EventStatus;
+
+// =============================================================================================================================
+
+const PRODUCT_ID: &str = "-//Ticketing//Events//EN";
+const MAX_LINE_OCTETS: usize = 75;
+
+// =============================================================================================================================
+
+pub struct CalendarEvent {
+    pub event_id: String,
+    pub title: String,
+    pub description: String,
+    pub location: String,
+    pub start: DateTime,
+    pub status: EventStatus,
+    pub geo: Option<(f64, f64)>,
+}
+
+// =============================================================================================================================
+
+// GeoJSON points are stored longitude first, while the GEO property of a calendar starts with the latitude.
+pub fn calendar_geo(coordinates: [f64; 2]) -> (f64, f64) {
+    (coordinates[1], coordinates[0])
+}
+
+// =============================================================================================================================
+
+// Builds an RFC 5545 calendar. The UID only depends on the event, so an event exported on its own
+// and found again in a subscription feed is recognised as the same one by calendar apps.
+pub fn build_calendar(name: &str, events: &[CalendarEvent]) -> String {
+    let stamp = format_datetime(DateTime::now());
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODUCT_ID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@events", event.event_id));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!("DTSTART:{}", format_datetime(event.start)));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.title)));
+        lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+        lines.push(format!("LOCATION:{}", escape_text(&event.location)));
+        if let Some((latitude, longitude)) = event.geo {
+            lines.push(format!("GEO:{};{}", latitude, longitude));
+        }
+        lines.push(format!("STATUS:{}", calendar_status(event.status)));
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+// =============================================================================================================================
+
+fn calendar_status(status: EventStatus) -> &'static str {
+    match status {
+        EventStatus::Cancelled => "CANCELLED",
+        EventStatus::Postponed => "TENTATIVE",
+        _ => "CONFIRMED",
+    }
+}
+
+// =============================================================================================================================
+
+fn format_datetime(date: DateTime) -> String {
+    date.to_chrono().format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+// =============================================================================================================================
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+        .replace('\r', "")
+}
+
+// =============================================================================================================================
+
+// Lines longer than 75 octets are folded on a character boundary, continuation lines start with a space.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut octets = 0;
+
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+
+    folded
+}
+
+// =============================================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_text_escapes_the_special_characters() {
+        assert_eq!(
+            escape_text("Rock; Pop, Jazz\\Blues"),
+            r"Rock\; Pop\, Jazz\\Blues"
+        );
+        assert_eq!(escape_text("Doors\r\nopen\nat 8\r"), r"Doors\nopen\nat 8");
+    }
+
+    #[test]
+    fn fold_line_keeps_a_short_line() {
+        let line = "a".repeat(MAX_LINE_OCTETS);
+
+        assert_eq!(fold_line(&line), format!("{}\r\n", line));
+    }
+
+    #[test]
+    fn fold_line_folds_a_long_line() {
+        let line = format!("{}bc", "a".repeat(MAX_LINE_OCTETS));
+
+        assert_eq!(
+            fold_line(&line),
+            format!("{}\r\n bc\r\n", "a".repeat(MAX_LINE_OCTETS))
+        );
+    }
+
+    #[test]
+    fn fold_line_does_not_split_a_character() {
+        let folded = fold_line(&"é".repeat(MAX_LINE_OCTETS));
+
+        assert!(
+            folded
+                .split("\r\n")
+                .all(|line| line.len() <= MAX_LINE_OCTETS)
+        );
+        assert_eq!(folded.split("\r\n").next(), Some("é".repeat(37).as_str()));
+        assert_eq!(
+            folded.replace("\r\n ", ""),
+            format!("{}\r\n", "é".repeat(75))
+        );
+    }
+}
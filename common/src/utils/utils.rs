@@ -1,5 +1,6 @@
 use mongodb::bson::serde_helpers::serialize_object_id_as_hex_string;
 use mongodb::bson::{DateTime, oid::ObjectId};
+use mongodb::error::{ErrorKind, WriteFailure};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::de::{self, Deserializer, MapAccess, Visitor};
@@ -10,7 +11,9 @@ use std::fmt;
 use validator::ValidationError;
 
 use crate::jwt::internal::encode_internal_jwt;
-use crate::models::{TriggerNotificationRequest, TriggerNotificationResponse};
+use crate::models::{
+    EventOrganiserRole, OrganisationRole, TriggerNotificationRequest, TriggerNotificationResponse,
+};
 
 use super::api_response::ApiResponse::{self, Error, Success};
 
@@ -19,6 +22,63 @@ use super::api_response::ApiResponse::{self, Error, Success};
 pub static LETTERS_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^[A-Za-zÀ-ÖØ-öø-ÿ\s'-]+$").unwrap());
 
+pub static SLUG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
+
+pub static SOFT_DELETE_RETENTION_DAYS: Lazy<i64> = Lazy::new(|| {
+    std::env::var("SOFT_DELETE_RETENTION_DAYS")
+        .ok()
+        .and_then(|days| days.parse::<i64>().ok())
+        .unwrap_or(30)
+});
+
+// Hours before an event at which its ticket holders are reminded of it, as a comma separated list.
+pub static EVENT_REMINDER_OFFSETS_HOURS: Lazy<Vec<i64>> = Lazy::new(|| {
+    let mut offsets: Vec<i64> = std::env::var("EVENT_REMINDER_OFFSETS_HOURS")
+        .unwrap_or_else(|_| "168,24".into())
+        .split(',')
+        .filter_map(|hours| hours.trim().parse::<i64>().ok())
+        .filter(|hours| *hours > 0)
+        .collect();
+    offsets.sort_unstable();
+    offsets.dedup();
+    offsets
+});
+
+pub static EVENT_MODERATION_ENABLED: Lazy<bool> = Lazy::new(|| {
+    std::env::var("EVENT_MODERATION_ENABLED")
+        .map(|enabled| matches!(enabled.trim(), "true" | "1"))
+        .unwrap_or(false)
+});
+
+pub static BROADCASTS_PER_EVENT_PER_HOUR: Lazy<i64> = Lazy::new(|| {
+    std::env::var("BROADCASTS_PER_EVENT_PER_HOUR")
+        .ok()
+        .and_then(|count| count.parse::<i64>().ok())
+        .filter(|count| *count > 0)
+        .unwrap_or(5)
+});
+
+pub static NOTIFICATIONS_PER_RUN: Lazy<i64> = Lazy::new(|| {
+    std::env::var("NOTIFICATIONS_PER_RUN")
+        .ok()
+        .and_then(|count| count.parse::<i64>().ok())
+        .filter(|count| *count > 0)
+        .unwrap_or(100)
+});
+
+pub static CALENDAR_FEED_URL: Lazy<String> = Lazy::new(|| {
+    std::env::var("CALENDAR_FEED_URL")
+        .unwrap_or_else(|_| "http://localhost/api/tickets/calendar-feed/".into())
+});
+
+pub static PURCHASE_FINGERPRINT_SECRET: Lazy<String> = Lazy::new(|| {
+    std::env::var("PURCHASE_FINGERPRINT_SECRET").expect("PURCHASE_FINGERPRINT_SECRET not set")
+});
+
+pub static INVITATION_URL: Lazy<String> = Lazy::new(|| {
+    std::env::var("INVITATION_URL").unwrap_or_else(|_| "http://localhost/invitation?token=".into())
+});
+
 // =============================================================================================================================
 
 pub fn serialize_option_object_id_as_hex_string<S>(
@@ -46,6 +106,16 @@ where
 
 // =============================================================================================================================
 
+pub fn trim_lowercase_option<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = Option::<String>::deserialize(deserializer)?;
+    Ok(s.map(|s| s.trim().to_lowercase()))
+}
+
+// =============================================================================================================================
+
 pub fn trim<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: Deserializer<'de>,
@@ -56,6 +126,16 @@ where
 
 // =============================================================================================================================
 
+pub fn trim_option<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = Option::<String>::deserialize(deserializer)?;
+    Ok(s.map(|s| s.trim().to_string()))
+}
+
+// =============================================================================================================================
+
 pub fn deserialize_object_id<'de, D>(deserializer: D) -> Result<ObjectId, D::Error>
 where
     D: Deserializer<'de>,
@@ -232,6 +312,25 @@ pub fn validate_date_not_in_past(date: &DateTime) -> Result<(), ValidationError>
 
 // =============================================================================================================================
 
+pub fn soft_delete_purge_cutoff() -> DateTime {
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(*SOFT_DELETE_RETENTION_DAYS);
+    DateTime::from_millis(cutoff.timestamp_millis())
+}
+
+// =============================================================================================================================
+
+pub fn is_duplicate_key_error(e: &mongodb::error::Error) -> bool {
+    matches!(
+        e.kind.as_ref(),
+        ErrorKind::Write(WriteFailure::WriteError(write_error)) if write_error.code == 11000
+    ) || matches!(
+        e.kind.as_ref(),
+        ErrorKind::Command(command_error) if command_error.code == 11000
+    )
+}
+
+// =============================================================================================================================
+
 pub async fn trigger_notification(
     notification_data: TriggerNotificationRequest,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -255,3 +354,29 @@ pub async fn trigger_notification(
 }
 
 // =============================================================================================================================
+
+pub async fn get_event_organiser_role(
+    event_id: ObjectId,
+    user_id: &str,
+) -> Result<Option<OrganisationRole>, Box<dyn std::error::Error>> {
+    let internal_token = encode_internal_jwt()?;
+    let client = reqwest::Client::new();
+    let res = client
+        .get(format!(
+            "http://events-service:8080/api/events/{}/organisers/{}",
+            event_id.to_hex(),
+            user_id
+        ))
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .send()
+        .await?
+        .json::<ApiResponse<EventOrganiserRole>>()
+        .await?;
+
+    match res {
+        Success { data, .. } => Ok(data.and_then(|organiser| organiser.role)),
+        Error { error, .. } => Err(error.into()),
+    }
+}
+
+// =============================================================================================================================
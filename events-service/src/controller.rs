@@ -1,18 +1,32 @@
 use actix_web::{
     HttpRequest, HttpResponse, Responder, delete, get, patch, post, put,
-    web::{self, Data, Json, Path},
+    web::{self, Data, Json, Path, Query},
 };
 use common::{
-    jwt::{external::user_has_any_of_these_roles, internal::authenticate_internal_request},
-    models::AuthRole,
-    utils::api_response::{ApiResponse, DocErrorApiResponse, DocSuccessApiResponse},
+    jwt::{
+        external::{get_authenticated_user, user_has_any_of_these_roles},
+        internal::authenticate_internal_request,
+    },
+    models::{AuthRole, EventOrganiserRole, EventStatus},
+    utils::api_response::{ApiResponse, ConflictError, DocErrorApiResponse, DocSuccessApiResponse},
 };
 use mongodb::Database;
 use utoipa::OpenApi;
+use validator::Validate;
 
 use crate::{
     doc::ApiDoc,
-    model::{CreateEventRequest, Event, UpdateEventRequest, UpdateSeatsRequest},
+    model::{
+        AccessCode, AccessCodeRequest, AddOrganisationMemberRequest, Category, CategoryRequest,
+        CreateEventRequest, CreateEventSeriesRequest, Event, EventSalesStatistics,
+        EventSearchResponse, EventSeat, EventSeries, EventSeriesDetails, Organisation,
+        OrganisationRequest, OrganisationSalesStatistics, Pass, PassRequest, PostponeEventRequest,
+        PresaleRequest, PurchaseLimits, RejectEventRequest, SaleWindowRequest,
+        ScheduleEventRequest, SearchEventsQuery, SeatingMapRequest, SeriesDatesRequest,
+        SeriesStatistics, TicketTypeRequest, UpdateCategoryRequest, UpdateEventRequest,
+        UpdateEventSeriesRequest, UpdateOrganisationMemberRequest, UpdateSeatsRequest,
+        UseAccessCodeRequest, Venue, VenueRequest,
+    },
     service,
 };
 
@@ -22,11 +36,72 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     let scope = web::scope("/api/events")
         .service(health_check)
         .service(get_events)
+        .service(get_deleted_events)
+        .service(get_moderation_queue)
+        .service(get_organisations)
+        .service(get_organisation_by_id)
+        .service(create_organisation)
+        .service(update_organisation_by_id)
+        .service(delete_organisation_by_id)
+        .service(add_organisation_member)
+        .service(update_organisation_member)
+        .service(remove_organisation_member)
+        .service(get_organisation_sales_statistics)
+        .service(get_event_series)
+        .service(get_event_series_by_id)
+        .service(create_event_series)
+        .service(add_event_series_dates)
+        .service(update_event_series)
+        .service(delete_event_series)
+        .service(get_event_series_statistics)
+        .service(get_categories)
+        .service(create_category)
+        .service(update_category)
+        .service(delete_category)
+        .service(get_passes)
+        .service(get_pass_by_id)
+        .service(create_pass)
+        .service(delete_pass)
+        .service(get_venues)
+        .service(get_venue_by_id)
+        .service(create_venue)
+        .service(update_venue_by_id)
+        .service(delete_venue_by_id)
+        .service(add_seating_map)
+        .service(update_seating_map)
+        .service(delete_seating_map)
         .service(get_event_by_id)
+        .service(get_event_seats)
+        .service(get_event_calendar)
+        .service(get_event_sales_statistics)
+        .service(get_event_organiser_role)
         .service(create_event)
         .service(update_event_by_id)
         .service(update_event_seats_by_id)
+        .service(add_ticket_type)
+        .service(update_ticket_type)
+        .service(delete_ticket_type)
+        .service(update_sale_window)
+        .service(update_purchase_limits)
+        .service(add_presale)
+        .service(update_presale)
+        .service(delete_presale)
+        .service(get_access_codes)
+        .service(create_access_code)
+        .service(delete_access_code)
+        .service(redeem_access_code)
+        .service(release_access_code)
+        .service(publish_event)
+        .service(schedule_event)
+        .service(unschedule_event)
+        .service(postpone_event)
+        .service(cancel_event)
+        .service(complete_event)
+        .service(approve_event)
+        .service(reject_event)
+        .service(resubmit_event)
         .service(delete_event_by_id)
+        .service(restore_event_by_id)
         .service(web::resource("/doc").route(web::get().to(|| async {
             HttpResponse::Found()
                 .append_header(("Location", "./"))
@@ -66,10 +141,12 @@ async fn health_check() -> impl Responder {
     get,
     path = "/api/events",
     tag = "Public Endpoints",
-    summary = "Retrieve all events",
-    description = "Fetches a list of all events.",
+    summary = "Search events",
+    description = "Searches upcoming events by free text over title and description, date range, location, city, category (including its sub-categories), tags, price range and remaining seats, sorted by date, price, creation date or title. Given lat and lon, only the events within radius kilometres (50 by default) are returned with their distance_km, sorted by distance unless sort_by is set. Each result carries its local_date in the timezone of the event. The first page also returns the facets of the matching events: their counts per category, city and local month. Results are paginated with the next_cursor returned by the previous page. Past events are only included when include_past is true or date_from is set. Internal requests receive every event without pagination.",
+    params(SearchEventsQuery),
     responses(
-        (status = 200, description = "Events were successfully retrieved.", body = DocSuccessApiResponse<Vec<Event>>),
+        (status = 200, description = "Events were successfully retrieved.", body = DocSuccessApiResponse<EventSearchResponse>),
+        (status = 400, description = "Invalid query parameters", body = DocErrorApiResponse),
         (status = 500, description = "An error occurred while trying to get events.", body = DocErrorApiResponse)
     ),
     security(
@@ -77,7 +154,28 @@ async fn health_check() -> impl Responder {
     )
 )]
 #[get("")]
-async fn get_events(db: Data<Database>) -> impl Responder {
+async fn get_events(
+    db: Data<Database>,
+    query: Query<SearchEventsQuery>,
+    req: HttpRequest,
+) -> impl Responder {
+    if authenticate_internal_request(&req).is_err() {
+        return match service::search_events(&db, query.into_inner()).await {
+            Ok(events) => {
+                let response: ApiResponse<EventSearchResponse> =
+                    ApiResponse::success("Events were successfully retrieved.", Some(events));
+                HttpResponse::Ok().json(response)
+            }
+            Err(e) => {
+                let response: ApiResponse<()> = ApiResponse::error(
+                    "An error occured while trying to get events.",
+                    e.to_string(),
+                );
+                HttpResponse::InternalServerError().json(response)
+            }
+        };
+    }
+
     match service::get_events(&db).await {
         Ok(events) => {
             let response: ApiResponse<Vec<Event>> =
@@ -101,7 +199,7 @@ async fn get_events(db: Data<Database>) -> impl Responder {
     path = "/api/events/{id}",
     tag = "Public Endpoints",
     summary = "Retrieve an event by ID",
-    description = "Fetches details of a specific event by its ID.",
+    description = "Fetches details of a specific event by its ID. Draft and scheduled events are only visible to Admin users and the organisers of the event.",
     responses(
         (status = 200, description = "Event was successfully retrieved.", body = DocSuccessApiResponse<Event>),
         (status = 500, description = "Failed to retrieve the event by id", body = DocErrorApiResponse)
@@ -114,9 +212,19 @@ async fn get_events(db: Data<Database>) -> impl Responder {
     )
 )]
 #[get("/{id}")]
-async fn get_event_by_id(db: Data<Database>, id: Path<String>) -> impl Responder {
+async fn get_event_by_id(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
     let id = id.into_inner();
-    match service::get_event_by_id(&db, id).await {
+    let result = match authenticate_internal_request(&req) {
+        Ok(_) => service::get_event_by_id(&db, id).await,
+        Err(_) => {
+            let viewer = get_authenticated_user(&req)
+                .ok()
+                .map(|claims| (claims.user_id, claims.role));
+            service::get_visible_event_by_id(&db, id, viewer).await
+        }
+    };
+
+    match result {
         Ok(event) => {
             let response: ApiResponse<Event> =
                 ApiResponse::success("Event was successfully retrieved.", Some(event));
@@ -132,12 +240,94 @@ async fn get_event_by_id(db: Data<Database>, id: Path<String>) -> impl Responder
 
 // =============================================================================================================================
 
+#[utoipa::path(
+    get,
+    path = "/api/events/{id}/calendar",
+    tag = "Public Endpoints",
+    summary = "Export an event to a calendar",
+    description = "Returns the event as an iCalendar file (RFC 5545) that can be added to any calendar app. Draft and scheduled events are only visible to Admin users and the organisers of the event.",
+    responses(
+        (status = 200, description = "The iCalendar file of the event.", body = String, content_type = "text/calendar"),
+        (status = 500, description = "Failed to export the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/{id}/calendar")]
+async fn get_event_calendar(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let id = id.into_inner();
+    let viewer = get_authenticated_user(&req)
+        .ok()
+        .map(|claims| (claims.user_id, claims.role));
+
+    match service::get_event_calendar(&db, id, viewer).await {
+        Ok(calendar) => HttpResponse::Ok()
+            .content_type("text/calendar; charset=utf-8")
+            .insert_header(("Content-Disposition", "attachment; filename=\"event.ics\""))
+            .body(calendar),
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to export the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/{id}/seats",
+    tag = "Public Endpoints",
+    summary = "Retrieve the seats of an event",
+    description = "Returns the seat inventory generated from the seating map of the event, with the section, row and accessibility of each seat. The seat number is the one used by tickets. Follows the visibility rules of the event.",
+    responses(
+        (status = 200, description = "Seats were successfully retrieved.", body = DocSuccessApiResponse<Vec<EventSeat>>),
+        (status = 500, description = "Failed to retrieve the seats of the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/{id}/seats")]
+async fn get_event_seats(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
+    let viewer = get_authenticated_user(&req)
+        .ok()
+        .map(|claims| (claims.user_id, claims.role));
+
+    match service::get_event_seats(&db, id.into_inner(), viewer).await {
+        Ok(seats) => {
+            let response: ApiResponse<Vec<EventSeat>> =
+                ApiResponse::success("Seats were successfully retrieved.", Some(seats));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the seats of the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
 #[utoipa::path(
     post,
     path = "/api/events",
     tag = "Protected Endpoints",
     summary = "Create a new event",
-    description = "Creates a new event. Access is restricted to Admin or EventCreator roles.",
+    description = "Creates a new event. Access is restricted to Admin or EventCreator roles. When an organisation_id is provided, the event is owned by that organisation and the user must be one of its owners or managers.",
     request_body = CreateEventRequest,
     responses(
         (status = 200, description = "Event was successfully created.", body = DocSuccessApiResponse<Event>),
@@ -158,7 +348,7 @@ async fn create_event(
     };
 
     let event = payload.into_inner();
-    match service::create_event(&db, event, jwt_payload.user_id).await {
+    match service::create_event(&db, event, jwt_payload.user_id, jwt_payload.role).await {
         Ok(event) => {
             let response: ApiResponse<Event> =
                 ApiResponse::success("Event was successfully created.", Some(event));
@@ -175,22 +365,23 @@ async fn create_event(
 // =============================================================================================================================
 
 #[utoipa::path(
-    put,
+    patch,
     path = "/api/events/{id}",
     tag = "Protected Endpoints",
     summary = "Update an event",
-    description = "Updates the details of an existing event. Access is restricted to Admin or EventCreator roles.",
+    description = "Partially updates an existing event, only the given fields are changed. The capacity cannot go below the seats already sold, and the remaining seats follow it. Ticket holders are notified when the date or the location of a public event changes. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
     request_body = UpdateEventRequest,
     responses(
         (status = 200, description = "Event was successfully updated.", body = DocSuccessApiResponse<Event>),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "The event changed while it was being updated.", body = DocErrorApiResponse),
         (status = 500, description = "Failed to update the event", body = DocErrorApiResponse)
     ),
     params(
         ("id" = String, Path, description = "Event ID")
     )
 )]
-#[put("/{id}")]
+#[patch("/{id}")]
 async fn update_event_by_id(
     db: Data<Database>,
     payload: Json<UpdateEventRequest>,
@@ -198,7 +389,7 @@ async fn update_event_by_id(
     req: HttpRequest,
 ) -> impl Responder {
     let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
-    match user_has_any_of_these_roles(&req, required_roles) {
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
         Ok(claims) => claims,
         Err(err_res) => return err_res,
     };
@@ -206,12 +397,18 @@ async fn update_event_by_id(
     let id = id.into_inner();
     let payload = payload.into_inner();
 
-    match service::update_event_by_id(&db, payload, id).await {
+    match service::update_event_by_id(&db, payload, id, jwt_payload.user_id, jwt_payload.role).await
+    {
         Ok(event) => {
             let response: ApiResponse<Event> =
                 ApiResponse::success("Event was successfully updated.", Some(event));
             HttpResponse::Ok().json(response)
         }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the event", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
         Err(e) => {
             let response: ApiResponse<()> =
                 ApiResponse::error("Failed to update the event", e.to_string());
@@ -227,11 +424,12 @@ async fn update_event_by_id(
     path = "/api/events/{id}/update-seats",
     tag = "Internal Endpoints",
     summary = "Update remaining seats for an event",
-    description = "Updates the number of remaining seats for an event. This endpoint is for internal use only.",
+    description = "Atomically updates the number of remaining seats for an event, and of the given ticket type. Taking more seats than remain, taking a seat number beyond the capacity, or releasing seats beyond the capacity or the quantity of the ticket type, is refused with a 409 and leaves the event untouched. This endpoint is for internal use only.",
     request_body = UpdateSeatsRequest,
     responses(
         (status = 200, description = "Remaining seats successfully updated.", body = DocSuccessApiResponse<Event>),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "Not enough seats remain, or too many were released.", body = DocErrorApiResponse),
         (status = 500, description = "Failed to update remaining seats.", body = DocErrorApiResponse)
     ),
     params(
@@ -252,13 +450,21 @@ async fn update_event_seats_by_id(
 
     let event_id = id.into_inner();
     let delta = payload.delta;
+    let ticket_type_id = payload.ticket_type_id;
+    let seat_number = payload.seat_number;
 
-    match service::update_event_seats_by_id(&db, event_id, delta).await {
+    match service::update_event_seats_by_id(&db, event_id, delta, ticket_type_id, seat_number).await
+    {
         Ok(event) => {
             let response: ApiResponse<Event> =
                 ApiResponse::success("Remaining seats successfully updated.", Some(event));
             HttpResponse::Ok().json(response)
         }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update remaining seats.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
         Err(e) => {
             let response: ApiResponse<()> =
                 ApiResponse::error("Failed to update remaining seats.", e.to_string());
@@ -269,25 +475,187 @@ async fn update_event_seats_by_id(
 
 // =============================================================================================================================
 
+#[utoipa::path(
+    post,
+    path = "/api/events/{id}/ticket-types",
+    tag = "Protected Endpoints",
+    summary = "Add a ticket type to an event",
+    description = "Adds a ticket type, such as VIP, standard or early-bird, with its own price, currency, quantity, sale window and seat range. The quantities of the ticket types cannot exceed the capacity of the event. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    request_body = TicketTypeRequest,
+    responses(
+        (status = 200, description = "Ticket type was successfully added.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to add the ticket type.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[post("/{id}/ticket-types")]
+async fn add_ticket_type(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<TicketTypeRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::add_ticket_type(&db, id, payload, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Ticket type was successfully added.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to add the ticket type.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/events/{id}/ticket-types/{ticket_type_id}",
+    tag = "Protected Endpoints",
+    summary = "Update a ticket type of an event",
+    description = "Updates a ticket type. Its quantity cannot be lower than the number of tickets already sold. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    request_body = TicketTypeRequest,
+    responses(
+        (status = 200, description = "Ticket type was successfully updated.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the ticket type.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID"),
+        ("ticket_type_id" = String, Path, description = "Ticket type ID")
+    )
+)]
+#[put("/{id}/ticket-types/{ticket_type_id}")]
+async fn update_ticket_type(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    payload: Json<TicketTypeRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, ticket_type_id) = path.into_inner();
+    let payload = payload.into_inner();
+
+    match service::update_ticket_type(
+        &db,
+        id,
+        ticket_type_id,
+        payload,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Ticket type was successfully updated.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the ticket type.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
 #[utoipa::path(
     delete,
-    path = "/api/events/{id}",
+    path = "/api/events/{id}/ticket-types/{ticket_type_id}",
     tag = "Protected Endpoints",
-    summary = "Delete an event",
-    description = "Deletes an event specified by its ID. Access is restricted to Admin or EventCreator roles.",
+    summary = "Delete a ticket type of an event",
+    description = "Deletes a ticket type that has no sold tickets. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
     responses(
-        (status = 200, description = "Event was successfully deleted.", body = DocSuccessApiResponse<Event>),
+        (status = 200, description = "Ticket type was successfully deleted.", body = DocSuccessApiResponse<Event>),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
-        (status = 500, description = "Failed to delete the event.", body = DocErrorApiResponse)
+        (status = 500, description = "Failed to delete the ticket type.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID"),
+        ("ticket_type_id" = String, Path, description = "Ticket type ID")
+    )
+)]
+#[delete("/{id}/ticket-types/{ticket_type_id}")]
+async fn delete_ticket_type(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, ticket_type_id) = path.into_inner();
+
+    match service::delete_ticket_type(
+        &db,
+        id,
+        ticket_type_id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Ticket type was successfully deleted.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the ticket type.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/events/{id}/sale-window",
+    tag = "Protected Endpoints",
+    summary = "Set the public sale window of an event",
+    description = "Sets when the public sale of a published event opens and closes. Without a start the sale opens as soon as the event is published, and without an end it stays open until the event. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    request_body = SaleWindowRequest,
+    responses(
+        (status = 200, description = "Sale window was successfully updated.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the sale window.", body = DocErrorApiResponse)
     ),
     params(
         ("id" = String, Path, description = "Event ID")
     )
 )]
-#[delete("/{id}")]
-async fn delete_event_by_id(
+#[put("/{id}/sale-window")]
+async fn update_sale_window(
     db: Data<Database>,
     id: Path<String>,
+    payload: Json<SaleWindowRequest>,
     req: HttpRequest,
 ) -> impl Responder {
     let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
@@ -297,15 +665,2639 @@ async fn delete_event_by_id(
     };
 
     let id = id.into_inner();
-    match service::delete_event_by_id(&db, jwt_payload.user_id, jwt_payload.role, id).await {
+    let payload = payload.into_inner();
+
+    match service::update_sale_window(&db, id, payload, jwt_payload.user_id, jwt_payload.role).await
+    {
         Ok(event) => {
             let response: ApiResponse<Event> =
-                ApiResponse::success("Event was successfully deleted.", Some(event));
+                ApiResponse::success("Sale window was successfully updated.", Some(event));
             HttpResponse::Ok().json(response)
         }
         Err(e) => {
             let response: ApiResponse<()> =
-                ApiResponse::error("Failed to delete the event.", e.to_string());
+                ApiResponse::error("Failed to update the sale window.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/events/{id}/purchase-limits",
+    tag = "Protected Endpoints",
+    summary = "Set the purchase limits of an event",
+    description = "Sets how many tickets of the event can be bought per user, per payment card and per household address. A missing limit removes it. The limits of each ticket type are set with the ticket type and apply on top of these. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    request_body = PurchaseLimits,
+    responses(
+        (status = 200, description = "Purchase limits were successfully updated.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the purchase limits.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[put("/{id}/purchase-limits")]
+async fn update_purchase_limits(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<PurchaseLimits>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::update_purchase_limits(&db, id, payload, jwt_payload.user_id, jwt_payload.role)
+        .await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Purchase limits were successfully updated.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the purchase limits.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/{id}/presales",
+    tag = "Protected Endpoints",
+    summary = "Add a presale to an event",
+    description = "Adds a presale window, during which tickets can only be bought with one of its access codes. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    request_body = PresaleRequest,
+    responses(
+        (status = 200, description = "Presale was successfully added.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to add the presale.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[post("/{id}/presales")]
+async fn add_presale(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<PresaleRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::add_presale(&db, id, payload, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Presale was successfully added.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to add the presale.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/events/{id}/presales/{presale_id}",
+    tag = "Protected Endpoints",
+    summary = "Update a presale of an event",
+    description = "Updates the name and the window of a presale. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    request_body = PresaleRequest,
+    responses(
+        (status = 200, description = "Presale was successfully updated.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the presale.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID"),
+        ("presale_id" = String, Path, description = "Presale ID")
+    )
+)]
+#[put("/{id}/presales/{presale_id}")]
+async fn update_presale(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    payload: Json<PresaleRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, presale_id) = path.into_inner();
+    let payload = payload.into_inner();
+
+    match service::update_presale(
+        &db,
+        id,
+        presale_id,
+        payload,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Presale was successfully updated.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the presale.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/{id}/presales/{presale_id}",
+    tag = "Protected Endpoints",
+    summary = "Delete a presale of an event",
+    description = "Deletes a presale along with its access codes. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Presale was successfully deleted.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to delete the presale.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID"),
+        ("presale_id" = String, Path, description = "Presale ID")
+    )
+)]
+#[delete("/{id}/presales/{presale_id}")]
+async fn delete_presale(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, presale_id) = path.into_inner();
+
+    match service::delete_presale(&db, id, presale_id, jwt_payload.user_id, jwt_payload.role).await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Presale was successfully deleted.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the presale.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/{id}/presales/{presale_id}/access-codes",
+    tag = "Protected Endpoints",
+    summary = "List the access codes of a presale",
+    description = "Lists the access codes of a presale with their quota and their uses. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Access codes were successfully retrieved.", body = DocSuccessApiResponse<Vec<AccessCode>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the access codes.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID"),
+        ("presale_id" = String, Path, description = "Presale ID")
+    )
+)]
+#[get("/{id}/presales/{presale_id}/access-codes")]
+async fn get_access_codes(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, presale_id) = path.into_inner();
+
+    match service::get_access_codes(&db, id, presale_id, jwt_payload.user_id, jwt_payload.role)
+        .await
+    {
+        Ok(access_codes) => {
+            let response: ApiResponse<Vec<AccessCode>> = ApiResponse::success(
+                "Access codes were successfully retrieved.",
+                Some(access_codes),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the access codes.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/{id}/presales/{presale_id}/access-codes",
+    tag = "Protected Endpoints",
+    summary = "Create an access code for a presale",
+    description = "Creates an access code unlocking a presale. A code with a max_uses of 1 is single-use, a higher value lets it be shared up to that quota. Codes are case insensitive and unique per event. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    request_body = AccessCodeRequest,
+    responses(
+        (status = 200, description = "Access code was successfully created.", body = DocSuccessApiResponse<AccessCode>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to create the access code.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID"),
+        ("presale_id" = String, Path, description = "Presale ID")
+    )
+)]
+#[post("/{id}/presales/{presale_id}/access-codes")]
+async fn create_access_code(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    payload: Json<AccessCodeRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, presale_id) = path.into_inner();
+    let payload = payload.into_inner();
+
+    match service::create_access_code(
+        &db,
+        id,
+        presale_id,
+        payload,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(access_code) => {
+            let response: ApiResponse<AccessCode> =
+                ApiResponse::success("Access code was successfully created.", Some(access_code));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to create the access code.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/{id}/presales/{presale_id}/access-codes/{access_code_id}",
+    tag = "Protected Endpoints",
+    summary = "Delete an access code of a presale",
+    description = "Deletes an access code, the tickets already bought with it are kept. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Access code was successfully deleted.", body = DocSuccessApiResponse<AccessCode>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to delete the access code.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID"),
+        ("presale_id" = String, Path, description = "Presale ID"),
+        ("access_code_id" = String, Path, description = "Access code ID")
+    )
+)]
+#[delete("/{id}/presales/{presale_id}/access-codes/{access_code_id}")]
+async fn delete_access_code(
+    db: Data<Database>,
+    path: Path<(String, String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, presale_id, access_code_id) = path.into_inner();
+
+    match service::delete_access_code(
+        &db,
+        id,
+        presale_id,
+        access_code_id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(access_code) => {
+            let response: ApiResponse<AccessCode> =
+                ApiResponse::success("Access code was successfully deleted.", Some(access_code));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the access code.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/access-codes/redeem",
+    tag = "Internal Endpoints",
+    summary = "Redeem an access code",
+    description = "Uses an access code of the event once, if its presale is open. A code whose quota is used up is refused with a 409. This endpoint is for internal use only.",
+    request_body = UseAccessCodeRequest,
+    responses(
+        (status = 200, description = "Access code was successfully redeemed.", body = DocSuccessApiResponse<AccessCode>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "The access code has already been used.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to redeem the access code.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/access-codes/redeem")]
+async fn redeem_access_code(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<UseAccessCodeRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::redeem_access_code(&db, id, payload).await {
+        Ok(access_code) => {
+            let response: ApiResponse<AccessCode> =
+                ApiResponse::success("Access code was successfully redeemed.", Some(access_code));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to redeem the access code.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to redeem the access code.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/access-codes/release",
+    tag = "Internal Endpoints",
+    summary = "Release a use of an access code",
+    description = "Gives back a use of an access code when the purchase it unlocked failed. This endpoint is for internal use only.",
+    request_body = UseAccessCodeRequest,
+    responses(
+        (status = 200, description = "Access code was successfully released.", body = DocSuccessApiResponse<AccessCode>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "The access code has no use to release.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to release the access code.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/access-codes/release")]
+async fn release_access_code(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<UseAccessCodeRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::release_access_code(&db, id, payload).await {
+        Ok(access_code) => {
+            let response: ApiResponse<AccessCode> =
+                ApiResponse::success("Access code was successfully released.", Some(access_code));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to release the access code.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to release the access code.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/publish",
+    tag = "Protected Endpoints",
+    summary = "Publish an event",
+    description = "Publishes a draft, scheduled or postponed event, making it visible to everyone and opening its ticket sales. The event date must be in the future. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Event was successfully published.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to publish the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/publish")]
+async fn publish_event(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::change_event_status(
+        &db,
+        id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+        EventStatus::Published,
+        None,
+        None,
+    )
+    .await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event was successfully published.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to publish the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/schedule",
+    tag = "Protected Endpoints",
+    summary = "Schedule the publication of an event",
+    description = "Schedules a draft event to be published automatically at publish_at, or moves the publication date of an already scheduled event. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    request_body = ScheduleEventRequest,
+    responses(
+        (status = 200, description = "Event publication was successfully scheduled.", body = DocSuccessApiResponse<Event>),
+        (status = 400, description = "Invalid request payload", body = DocErrorApiResponse),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to schedule the event publication.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/schedule")]
+async fn schedule_event(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<ScheduleEventRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let payload = payload.into_inner();
+    if let Err(e) = payload.validate() {
+        let response: ApiResponse<()> =
+            ApiResponse::error("Failed to schedule the event publication.", e.to_string());
+        return HttpResponse::BadRequest().json(response);
+    }
+
+    let id = id.into_inner();
+    match service::change_event_status(
+        &db,
+        id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+        EventStatus::Scheduled,
+        Some(payload.publish_at),
+        None,
+    )
+    .await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event publication was successfully scheduled.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to schedule the event publication.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/unschedule",
+    tag = "Protected Endpoints",
+    summary = "Cancel the scheduled publication of an event",
+    description = "Moves a scheduled event back to draft. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Event publication was successfully unscheduled.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to unschedule the event publication.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/unschedule")]
+async fn unschedule_event(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::change_event_status(
+        &db,
+        id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+        EventStatus::Draft,
+        None,
+        None,
+    )
+    .await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> = ApiResponse::success(
+                "Event publication was successfully unscheduled.",
+                Some(event),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to unschedule the event publication.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/postpone",
+    tag = "Protected Endpoints",
+    summary = "Postpone an event",
+    description = "Postpones a published event and suspends its ticket sales. The new date can be given now or later through the event update, before publishing the event again. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    request_body = PostponeEventRequest,
+    responses(
+        (status = 200, description = "Event was successfully postponed.", body = DocSuccessApiResponse<Event>),
+        (status = 400, description = "Invalid request payload", body = DocErrorApiResponse),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to postpone the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/postpone")]
+async fn postpone_event(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<PostponeEventRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let payload = payload.into_inner();
+    if let Err(e) = payload.validate() {
+        let response: ApiResponse<()> =
+            ApiResponse::error("Failed to postpone the event.", e.to_string());
+        return HttpResponse::BadRequest().json(response);
+    }
+
+    let id = id.into_inner();
+    match service::change_event_status(
+        &db,
+        id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+        EventStatus::Postponed,
+        None,
+        payload.date,
+    )
+    .await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event was successfully postponed.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to postpone the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/cancel",
+    tag = "Protected Endpoints",
+    summary = "Cancel an event",
+    description = "Cancels a published or postponed event, stops its ticket sales and starts the background refund of its tickets, whose progress is available at /api/tickets/events/{id}/cancellation. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Event was successfully cancelled.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to cancel the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/cancel")]
+async fn cancel_event(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::cancel_event(&db, id, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event was successfully cancelled.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to cancel the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/complete",
+    tag = "Protected Endpoints",
+    summary = "Complete an event",
+    description = "Marks a published event whose date has passed as completed. Events are also completed automatically once their date has passed. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Event was successfully completed.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to complete the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/complete")]
+async fn complete_event(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::change_event_status(
+        &db,
+        id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+        EventStatus::Completed,
+        None,
+        None,
+    )
+    .await
+    {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event was successfully completed.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to complete the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/{id}",
+    tag = "Protected Endpoints",
+    summary = "Delete an event",
+    description = "Soft deletes an event specified by its ID. The event is hidden from every other endpoint and permanently purged once the retention period is over. A published or postponed event is cancelled first, and the refund of the tickets of a cancelled event is started before it is deleted. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Event was successfully deleted.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to delete the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[delete("/{id}")]
+async fn delete_event_by_id(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::delete_event_by_id(&db, jwt_payload.user_id, jwt_payload.role, id).await {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event was successfully deleted.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/moderation",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the moderation queue",
+    description = "Fetches the events awaiting a review, oldest first: new events of organisers and the pending changes to the key fields of approved events. Access is restricted to Admin and Operator users.",
+    responses(
+        (status = 200, description = "Moderation queue was successfully retrieved.", body = DocSuccessApiResponse<Vec<Event>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred while trying to get the moderation queue.", body = DocErrorApiResponse)
+    )
+)]
+#[get("/moderation")]
+async fn get_moderation_queue(db: Data<Database>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::Operator];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    match service::get_moderation_queue(&db).await {
+        Ok(events) => {
+            let response: ApiResponse<Vec<Event>> =
+                ApiResponse::success("Moderation queue was successfully retrieved.", Some(events));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured while trying to get the moderation queue.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/approve",
+    tag = "Protected Endpoints",
+    summary = "Approve an event",
+    description = "Approves a new event, which can then be published, or applies the pending changes to the key fields of an event. The creator of the event is notified. Access is restricted to Admin and Operator users.",
+    responses(
+        (status = 200, description = "Event was successfully approved.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "The event changed while it was being reviewed.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to approve the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/approve")]
+async fn approve_event(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::Operator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::approve_event(&db, id, jwt_payload.user_id).await {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event was successfully approved.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to approve the event.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to approve the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/reject",
+    tag = "Protected Endpoints",
+    summary = "Reject an event",
+    description = "Rejects a new event, which cannot be published until it is edited and approved, or discards the pending changes to the key fields of an event. The creator of the event is notified with the reason. Access is restricted to Admin and Operator users.",
+    request_body = RejectEventRequest,
+    responses(
+        (status = 200, description = "Event was successfully rejected.", body = DocSuccessApiResponse<Event>),
+        (status = 400, description = "Invalid request payload", body = DocErrorApiResponse),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "The event changed while it was being reviewed.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to reject the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/reject")]
+async fn reject_event(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<RejectEventRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::Operator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let payload = payload.into_inner();
+    if let Err(e) = payload.validate() {
+        let response: ApiResponse<()> =
+            ApiResponse::error("Failed to reject the event.", e.to_string());
+        return HttpResponse::BadRequest().json(response);
+    }
+
+    let id = id.into_inner();
+    match service::reject_event(&db, id, jwt_payload.user_id, payload).await {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event was successfully rejected.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to reject the event.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to reject the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/resubmit",
+    tag = "Protected Endpoints",
+    summary = "Submit a rejected event again",
+    description = "Submits a rejected event for a new review without changing it. Editing its content also submits it again. Access is restricted to Admin users, the creator of the event or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Event was successfully submitted.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "The event changed while it was being submitted.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to submit the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/resubmit")]
+async fn resubmit_event(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::resubmit_event(&db, id, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event was successfully submitted.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to submit the event.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to submit the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/deleted",
+    tag = "Protected Endpoints",
+    summary = "Retrieve deleted events",
+    description = "Fetches the list of soft deleted events that have not been purged yet. Access is restricted to Admin users.",
+    responses(
+        (status = 200, description = "Deleted events were successfully retrieved.", body = DocSuccessApiResponse<Vec<Event>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred while trying to get deleted events.", body = DocErrorApiResponse)
+    )
+)]
+#[get("/deleted")]
+async fn get_deleted_events(db: Data<Database>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    match service::get_deleted_events(&db).await {
+        Ok(events) => {
+            let response: ApiResponse<Vec<Event>> =
+                ApiResponse::success("Deleted events were successfully retrieved.", Some(events));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured while trying to get deleted events.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/{id}/restore",
+    tag = "Protected Endpoints",
+    summary = "Restore a deleted event",
+    description = "Restores a soft deleted event specified by its ID. Access is restricted to Admin users.",
+    responses(
+        (status = 200, description = "Event was successfully restored.", body = DocSuccessApiResponse<Event>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to restore the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{id}/restore")]
+async fn restore_event_by_id(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::restore_event_by_id(&db, id).await {
+        Ok(event) => {
+            let response: ApiResponse<Event> =
+                ApiResponse::success("Event was successfully restored.", Some(event));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to restore the event.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/{id}/organisers/{user_id}",
+    tag = "Internal Endpoints",
+    summary = "Retrieve the organiser role of a user for an event",
+    description = "Returns the role the user holds in the organisation owning the event. The creator of an event without organisation is considered its owner. This endpoint is for internal use only.",
+    responses(
+        (status = 200, description = "Organiser role was successfully retrieved.", body = DocSuccessApiResponse<EventOrganiserRole>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the organiser role.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID"),
+        ("user_id" = String, Path, description = "User ID")
+    )
+)]
+#[get("/{id}/organisers/{user_id}")]
+async fn get_event_organiser_role(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, user_id) = path.into_inner();
+    match service::get_event_organiser_role(&db, id, user_id).await {
+        Ok(role) => {
+            let response: ApiResponse<EventOrganiserRole> = ApiResponse::success(
+                "Organiser role was successfully retrieved.",
+                Some(EventOrganiserRole { role }),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the organiser role.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/organisations",
+    tag = "Protected Endpoints",
+    summary = "Retrieve organisations",
+    description = "Fetches the organisations the user is a member of. Admin users retrieve every organisation.",
+    responses(
+        (status = 200, description = "Organisations were successfully retrieved.", body = DocSuccessApiResponse<Vec<Organisation>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred while trying to get organisations.", body = DocErrorApiResponse)
+    )
+)]
+#[get("/organisations")]
+async fn get_organisations(db: Data<Database>, req: HttpRequest) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    match service::get_organisations(&db, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(organisations) => {
+            let response: ApiResponse<Vec<Organisation>> = ApiResponse::success(
+                "Organisations were successfully retrieved.",
+                Some(organisations),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured while trying to get organisations.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/organisations/{id}",
+    tag = "Protected Endpoints",
+    summary = "Retrieve an organisation by ID",
+    description = "Fetches an organisation and its members. Access is restricted to Admin users and the members of the organisation.",
+    responses(
+        (status = 200, description = "Organisation was successfully retrieved.", body = DocSuccessApiResponse<Organisation>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the organisation by id.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Organisation ID")
+    )
+)]
+#[get("/organisations/{id}")]
+async fn get_organisation_by_id(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::get_organisation_by_id(&db, id, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(organisation) => {
+            let response: ApiResponse<Organisation> = ApiResponse::success(
+                "Organisation was successfully retrieved.",
+                Some(organisation),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the organisation by id.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/organisations",
+    tag = "Protected Endpoints",
+    summary = "Create an organisation",
+    description = "Creates a new organisation whose only member is the user, as its owner. Access is restricted to Admin or EventCreator roles.",
+    request_body = OrganisationRequest,
+    responses(
+        (status = 200, description = "Organisation was successfully created.", body = DocSuccessApiResponse<Organisation>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred during the organisation creation.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/organisations")]
+async fn create_organisation(
+    db: Data<Database>,
+    payload: Json<OrganisationRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let organisation = payload.into_inner();
+    match service::create_organisation(&db, organisation, jwt_payload.user_id).await {
+        Ok(organisation) => {
+            let response: ApiResponse<Organisation> =
+                ApiResponse::success("Organisation was successfully created.", Some(organisation));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured during the organisation creation.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/events/organisations/{id}",
+    tag = "Protected Endpoints",
+    summary = "Update an organisation",
+    description = "Renames an organisation. Access is restricted to Admin users and the owners of the organisation.",
+    request_body = OrganisationRequest,
+    responses(
+        (status = 200, description = "Organisation was successfully updated.", body = DocSuccessApiResponse<Organisation>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the organisation.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Organisation ID")
+    )
+)]
+#[put("/organisations/{id}")]
+async fn update_organisation_by_id(
+    db: Data<Database>,
+    payload: Json<OrganisationRequest>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::update_organisation_by_id(
+        &db,
+        payload,
+        id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(organisation) => {
+            let response: ApiResponse<Organisation> =
+                ApiResponse::success("Organisation was successfully updated.", Some(organisation));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the organisation.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/organisations/{id}",
+    tag = "Protected Endpoints",
+    summary = "Delete an organisation",
+    description = "Deletes an organisation that no longer owns any event. Access is restricted to Admin users and the owners of the organisation.",
+    responses(
+        (status = 200, description = "Organisation was successfully deleted.", body = DocSuccessApiResponse<Organisation>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to delete the organisation.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Organisation ID")
+    )
+)]
+#[delete("/organisations/{id}")]
+async fn delete_organisation_by_id(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::delete_organisation_by_id(&db, id, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(organisation) => {
+            let response: ApiResponse<Organisation> =
+                ApiResponse::success("Organisation was successfully deleted.", Some(organisation));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the organisation.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/organisations/{id}/members",
+    tag = "Protected Endpoints",
+    summary = "Add a member to an organisation",
+    description = "Adds a user to an organisation with the given role. Owners can grant any role, managers can only add BoxOffice and Viewer members.",
+    request_body = AddOrganisationMemberRequest,
+    responses(
+        (status = 200, description = "Member was successfully added.", body = DocSuccessApiResponse<Organisation>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to add the member to the organisation.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Organisation ID")
+    )
+)]
+#[post("/organisations/{id}/members")]
+async fn add_organisation_member(
+    db: Data<Database>,
+    payload: Json<AddOrganisationMemberRequest>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::add_organisation_member(&db, id, payload, jwt_payload.user_id, jwt_payload.role)
+        .await
+    {
+        Ok(organisation) => {
+            let response: ApiResponse<Organisation> =
+                ApiResponse::success("Member was successfully added.", Some(organisation));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "Failed to add the member to the organisation.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/organisations/{id}/members/{user_id}",
+    tag = "Protected Endpoints",
+    summary = "Change the role of an organisation member",
+    description = "Changes the role of a member. Owners can change any role, managers can only move members between BoxOffice and Viewer. The organisation always keeps at least one owner.",
+    request_body = UpdateOrganisationMemberRequest,
+    responses(
+        (status = 200, description = "Member was successfully updated.", body = DocSuccessApiResponse<Organisation>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the organisation member.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Organisation ID"),
+        ("user_id" = String, Path, description = "Member user ID")
+    )
+)]
+#[patch("/organisations/{id}/members/{user_id}")]
+async fn update_organisation_member(
+    db: Data<Database>,
+    payload: Json<UpdateOrganisationMemberRequest>,
+    path: Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, member_id) = path.into_inner();
+    let payload = payload.into_inner();
+
+    match service::update_organisation_member(
+        &db,
+        id,
+        member_id,
+        payload,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(organisation) => {
+            let response: ApiResponse<Organisation> =
+                ApiResponse::success("Member was successfully updated.", Some(organisation));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the organisation member.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/organisations/{id}/members/{user_id}",
+    tag = "Protected Endpoints",
+    summary = "Remove a member from an organisation",
+    description = "Removes a member from an organisation. Members can always leave, owners can remove anyone and managers can only remove BoxOffice and Viewer members. The organisation always keeps at least one owner.",
+    responses(
+        (status = 200, description = "Member was successfully removed.", body = DocSuccessApiResponse<Organisation>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to remove the organisation member.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Organisation ID"),
+        ("user_id" = String, Path, description = "Member user ID")
+    )
+)]
+#[delete("/organisations/{id}/members/{user_id}")]
+async fn remove_organisation_member(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, member_id) = path.into_inner();
+    match service::remove_organisation_member(
+        &db,
+        id,
+        member_id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(organisation) => {
+            let response: ApiResponse<Organisation> =
+                ApiResponse::success("Member was successfully removed.", Some(organisation));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to remove the organisation member.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/series",
+    tag = "Public Endpoints",
+    summary = "List event series",
+    description = "Returns the event series, such as tours, that have at least one public date, sorted by title.",
+    responses(
+        (status = 200, description = "Event series were successfully retrieved.", body = DocSuccessApiResponse<Vec<EventSeries>>),
+        (status = 500, description = "Failed to retrieve the event series.", body = DocErrorApiResponse)
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/series")]
+async fn get_event_series(db: Data<Database>) -> impl Responder {
+    match service::get_event_series(&db).await {
+        Ok(series) => {
+            let response: ApiResponse<Vec<EventSeries>> =
+                ApiResponse::success("Event series were successfully retrieved.", Some(series));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the event series.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/series/{id}",
+    tag = "Public Endpoints",
+    summary = "Retrieve an event series by ID",
+    description = "Returns a series with its dates, sorted chronologically. Only the public dates are listed, except for Admin users and the organisers of the series who see all of them.",
+    responses(
+        (status = 200, description = "Event series was successfully retrieved.", body = DocSuccessApiResponse<EventSeriesDetails>),
+        (status = 500, description = "Failed to retrieve the event series.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Series ID")
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/series/{id}")]
+async fn get_event_series_by_id(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let id = id.into_inner();
+    let viewer = get_authenticated_user(&req)
+        .ok()
+        .map(|claims| (claims.user_id, claims.role));
+
+    match service::get_event_series_by_id(&db, id, viewer).await {
+        Ok(series) => {
+            let response: ApiResponse<EventSeriesDetails> =
+                ApiResponse::success("Event series was successfully retrieved.", Some(series));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the event series.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/series",
+    tag = "Protected Endpoints",
+    summary = "Create an event series",
+    description = "Creates a series, such as a tour, with its shared title, description, price, capacity and place, and creates its dates as Draft events. The dates are given as a list, each one able to override the title, place, capacity and price, or generated from a recurrence rule. Access is restricted to Admin and EventCreator users, and to the owners and managers of the organisation owning the series.",
+    request_body = CreateEventSeriesRequest,
+    responses(
+        (status = 200, description = "Event series was successfully created.", body = DocSuccessApiResponse<EventSeriesDetails>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to create the event series.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/series")]
+async fn create_event_series(
+    db: Data<Database>,
+    payload: Json<CreateEventSeriesRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let payload = payload.into_inner();
+
+    match service::create_event_series(&db, payload, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(series) => {
+            let response: ApiResponse<EventSeriesDetails> =
+                ApiResponse::success("Event series was successfully created.", Some(series));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to create the event series.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/series/{id}/dates",
+    tag = "Protected Endpoints",
+    summary = "Add dates to an event series",
+    description = "Creates more dates of a series as Draft events, from a list of dates or a recurrence rule. If one of them cannot be created, none is. Access is restricted to Admin users, the creator of the series or the owners and managers of the organisation owning it.",
+    request_body = SeriesDatesRequest,
+    responses(
+        (status = 200, description = "Dates were successfully added to the series.", body = DocSuccessApiResponse<EventSeriesDetails>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to add the dates to the series.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Series ID")
+    )
+)]
+#[post("/series/{id}/dates")]
+async fn add_event_series_dates(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<SeriesDatesRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::add_event_series_dates(&db, id, payload, jwt_payload.user_id, jwt_payload.role)
+        .await
+    {
+        Ok(series) => {
+            let response: ApiResponse<EventSeriesDetails> =
+                ApiResponse::success("Dates were successfully added to the series.", Some(series));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to add the dates to the series.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/events/series/{id}",
+    tag = "Protected Endpoints",
+    summary = "Update an event series",
+    description = "Partially updates the shared metadata of a series. A new description is applied to the dates that are not cancelled or completed, and a new price to those of them that kept the price of the series. Access is restricted to Admin users, the creator of the series or the owners and managers of the organisation owning it.",
+    request_body = UpdateEventSeriesRequest,
+    responses(
+        (status = 200, description = "Event series was successfully updated.", body = DocSuccessApiResponse<EventSeries>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the event series.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Series ID")
+    )
+)]
+#[patch("/series/{id}")]
+async fn update_event_series(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<UpdateEventSeriesRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::update_event_series(&db, id, payload, jwt_payload.user_id, jwt_payload.role)
+        .await
+    {
+        Ok(series) => {
+            let response: ApiResponse<EventSeries> =
+                ApiResponse::success("Event series was successfully updated.", Some(series));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the event series.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/series/{id}",
+    tag = "Protected Endpoints",
+    summary = "Delete an event series",
+    description = "Deletes a series that has no dates left. Access is restricted to Admin users, the creator of the series or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Event series was successfully deleted.", body = DocSuccessApiResponse<EventSeries>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to delete the event series.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Series ID")
+    )
+)]
+#[delete("/series/{id}")]
+async fn delete_event_series(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+
+    match service::delete_event_series(&db, id, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(series) => {
+            let response: ApiResponse<EventSeries> =
+                ApiResponse::success("Event series was successfully deleted.", Some(series));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the event series.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/series/{id}/statistics",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the sales statistics of an event series",
+    description = "Aggregates the capacity, the sold and the remaining seats across all the dates of a series, with the sell-through percentage and the figures of each date. Access is restricted to Admin users, the creator of the series or the owners and managers of the organisation owning it.",
+    responses(
+        (status = 200, description = "Series statistics were successfully retrieved.", body = DocSuccessApiResponse<SeriesStatistics>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the series statistics.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Series ID")
+    )
+)]
+#[get("/series/{id}/statistics")]
+async fn get_event_series_statistics(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+
+    match service::get_event_series_statistics(&db, id, jwt_payload.user_id, jwt_payload.role).await
+    {
+        Ok(statistics) => {
+            let response: ApiResponse<SeriesStatistics> = ApiResponse::success(
+                "Series statistics were successfully retrieved.",
+                Some(statistics),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the series statistics.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/{id}/statistics",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the sales statistics of an event",
+    description = "Returns the tickets of an event by status, its sell-through percentage, its revenue by currency and by day, and its refunds and cancellations. Sold tickets are the active ones. Access is restricted to Admin users and the owners of the event.",
+    responses(
+        (status = 200, description = "Sales statistics were successfully retrieved.", body = DocSuccessApiResponse<EventSalesStatistics>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the sales statistics.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Event ID")
+    )
+)]
+#[get("/{id}/statistics")]
+async fn get_event_sales_statistics(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+
+    match service::get_event_sales_statistics(&db, id, jwt_payload.user_id, jwt_payload.role).await
+    {
+        Ok(statistics) => {
+            let response: ApiResponse<EventSalesStatistics> = ApiResponse::success(
+                "Sales statistics were successfully retrieved.",
+                Some(statistics),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the sales statistics.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/organisations/{id}/statistics",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the sales statistics of an organisation",
+    description = "Aggregates the sales statistics of every event of an organisation: tickets by status, sell-through percentage, revenue by currency and by day, refunds and cancellations, with the figures of each event. Access is restricted to Admin users and the owners of the organisation.",
+    responses(
+        (status = 200, description = "Sales statistics were successfully retrieved.", body = DocSuccessApiResponse<OrganisationSalesStatistics>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the sales statistics.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Organisation ID")
+    )
+)]
+#[get("/organisations/{id}/statistics")]
+async fn get_organisation_sales_statistics(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+
+    match service::get_organisation_sales_statistics(&db, id, jwt_payload.user_id, jwt_payload.role)
+        .await
+    {
+        Ok(statistics) => {
+            let response: ApiResponse<OrganisationSalesStatistics> = ApiResponse::success(
+                "Sales statistics were successfully retrieved.",
+                Some(statistics),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the sales statistics.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/categories",
+    tag = "Public Endpoints",
+    summary = "List categories",
+    description = "Returns the category taxonomy, sorted by name. Sub-categories reference their parent with parent_id.",
+    responses(
+        (status = 200, description = "Categories were successfully retrieved.", body = DocSuccessApiResponse<Vec<Category>>),
+        (status = 500, description = "Failed to retrieve the categories.", body = DocErrorApiResponse)
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/categories")]
+async fn get_categories(db: Data<Database>) -> impl Responder {
+    match service::get_categories(&db).await {
+        Ok(categories) => {
+            let response: ApiResponse<Vec<Category>> =
+                ApiResponse::success("Categories were successfully retrieved.", Some(categories));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the categories.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/categories",
+    tag = "Protected Endpoints",
+    summary = "Create a category",
+    description = "Adds a category, or a sub-category when parent_id is given. Its slug is the value used by events and cannot be changed afterwards. Access is restricted to Admin users.",
+    request_body = CategoryRequest,
+    responses(
+        (status = 200, description = "Category was successfully created.", body = DocSuccessApiResponse<Category>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred during the category creation.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/categories")]
+async fn create_category(
+    db: Data<Database>,
+    payload: Json<CategoryRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    match service::create_category(&db, payload.into_inner()).await {
+        Ok(category) => {
+            let response: ApiResponse<Category> =
+                ApiResponse::success("Category was successfully created.", Some(category));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured during the category creation.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/events/categories/{id}",
+    tag = "Protected Endpoints",
+    summary = "Rename a category",
+    description = "Updates the name of a category. Access is restricted to Admin users.",
+    request_body = UpdateCategoryRequest,
+    responses(
+        (status = 200, description = "Category was successfully updated.", body = DocSuccessApiResponse<Category>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the category.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Category ID")
+    )
+)]
+#[put("/categories/{id}")]
+async fn update_category(
+    db: Data<Database>,
+    payload: Json<UpdateCategoryRequest>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    match service::update_category(&db, payload.into_inner(), id.into_inner()).await {
+        Ok(category) => {
+            let response: ApiResponse<Category> =
+                ApiResponse::success("Category was successfully updated.", Some(category));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the category.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/categories/{id}",
+    tag = "Protected Endpoints",
+    summary = "Delete a category",
+    description = "Deletes a category that has no sub-categories and is no longer used by any event or series. Access is restricted to Admin users.",
+    responses(
+        (status = 200, description = "Category was successfully deleted.", body = DocSuccessApiResponse<Category>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to delete the category.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Category ID")
+    )
+)]
+#[delete("/categories/{id}")]
+async fn delete_category(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    match service::delete_category(&db, id.into_inner()).await {
+        Ok(category) => {
+            let response: ApiResponse<Category> =
+                ApiResponse::success("Category was successfully deleted.", Some(category));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the category.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/passes",
+    tag = "Public Endpoints",
+    summary = "List passes",
+    description = "Returns all the passes on offer, newest first, with the events they grant entry to.",
+    responses(
+        (status = 200, description = "Passes were successfully retrieved.", body = DocSuccessApiResponse<Vec<Pass>>),
+        (status = 500, description = "Failed to retrieve the passes.", body = DocErrorApiResponse)
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/passes")]
+async fn get_passes(db: Data<Database>) -> impl Responder {
+    match service::get_passes(&db).await {
+        Ok(passes) => {
+            let response: ApiResponse<Vec<Pass>> =
+                ApiResponse::success("Passes were successfully retrieved.", Some(passes));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the passes.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/passes/{id}",
+    tag = "Public Endpoints",
+    summary = "Retrieve a pass by ID",
+    description = "Fetches a pass with the events it grants entry to, ordered by date.",
+    responses(
+        (status = 200, description = "Pass was successfully retrieved.", body = DocSuccessApiResponse<Pass>),
+        (status = 500, description = "Failed to retrieve the pass.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Pass ID")
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/passes/{id}")]
+async fn get_pass_by_id(db: Data<Database>, id: Path<String>) -> impl Responder {
+    match service::get_pass_by_id(&db, id.into_inner()).await {
+        Ok(pass) => {
+            let response: ApiResponse<Pass> =
+                ApiResponse::success("Pass was successfully retrieved.", Some(pass));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the pass.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/passes",
+    tag = "Protected Endpoints",
+    summary = "Create a pass",
+    description = "Creates a pass granting entry to a set of events for a single price, such as a festival pass or a tour bundle. Each event can be tied to one of its ticket types. Access is restricted to Admin or EventCreator roles, who must be able to manage the sales of every event of the pass.",
+    request_body = PassRequest,
+    responses(
+        (status = 200, description = "Pass was successfully created.", body = DocSuccessApiResponse<Pass>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to create the pass.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/passes")]
+async fn create_pass(
+    db: Data<Database>,
+    payload: Json<PassRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let payload = payload.into_inner();
+    match service::create_pass(&db, payload, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(pass) => {
+            let response: ApiResponse<Pass> =
+                ApiResponse::success("Pass was successfully created.", Some(pass));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to create the pass.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/passes/{id}",
+    tag = "Protected Endpoints",
+    summary = "Delete a pass",
+    description = "Stops the sale of a pass, the passes already bought stay valid. Access is restricted to Admin users and the creator of the pass.",
+    responses(
+        (status = 200, description = "Pass was successfully deleted.", body = DocSuccessApiResponse<Pass>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to delete the pass.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Pass ID")
+    )
+)]
+#[delete("/passes/{id}")]
+async fn delete_pass(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::delete_pass(&db, id, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(pass) => {
+            let response: ApiResponse<Pass> =
+                ApiResponse::success("Pass was successfully deleted.", Some(pass));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the pass.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/venues",
+    tag = "Public Endpoints",
+    summary = "List venues",
+    description = "Returns all the venues, sorted by name, with their seating maps.",
+    responses(
+        (status = 200, description = "Venues were successfully retrieved.", body = DocSuccessApiResponse<Vec<Venue>>),
+        (status = 500, description = "Failed to retrieve the venues.", body = DocErrorApiResponse)
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/venues")]
+async fn get_venues(db: Data<Database>) -> impl Responder {
+    match service::get_venues(&db).await {
+        Ok(venues) => {
+            let response: ApiResponse<Vec<Venue>> =
+                ApiResponse::success("Venues were successfully retrieved.", Some(venues));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the venues.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/events/venues/{id}",
+    tag = "Public Endpoints",
+    summary = "Retrieve a venue by ID",
+    description = "Fetches a venue with its address, coordinates and seating maps.",
+    responses(
+        (status = 200, description = "Venue was successfully retrieved.", body = DocSuccessApiResponse<Venue>),
+        (status = 500, description = "Failed to retrieve the venue.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Venue ID")
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/venues/{id}")]
+async fn get_venue_by_id(db: Data<Database>, id: Path<String>) -> impl Responder {
+    match service::get_venue_by_id(&db, id.into_inner()).await {
+        Ok(venue) => {
+            let response: ApiResponse<Venue> =
+                ApiResponse::success("Venue was successfully retrieved.", Some(venue));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the venue.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/venues",
+    tag = "Protected Endpoints",
+    summary = "Create a venue",
+    description = "Registers a venue with its address and geo coordinates. Seating maps are added afterwards. Access is restricted to Admin or EventCreator roles.",
+    request_body = VenueRequest,
+    responses(
+        (status = 200, description = "Venue was successfully created.", body = DocSuccessApiResponse<Venue>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred during the venue creation.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/venues")]
+async fn create_venue(
+    db: Data<Database>,
+    payload: Json<VenueRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    match service::create_venue(&db, payload.into_inner(), jwt_payload.user_id).await {
+        Ok(venue) => {
+            let response: ApiResponse<Venue> =
+                ApiResponse::success("Venue was successfully created.", Some(venue));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("An error occured during the venue creation.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/events/venues/{id}",
+    tag = "Protected Endpoints",
+    summary = "Update a venue",
+    description = "Updates the name, address and coordinates of a venue. Access is restricted to Admin users and the creator of the venue.",
+    request_body = VenueRequest,
+    responses(
+        (status = 200, description = "Venue was successfully updated.", body = DocSuccessApiResponse<Venue>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the venue.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Venue ID")
+    )
+)]
+#[put("/venues/{id}")]
+async fn update_venue_by_id(
+    db: Data<Database>,
+    payload: Json<VenueRequest>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::update_venue_by_id(&db, payload, id, jwt_payload.user_id, jwt_payload.role).await
+    {
+        Ok(venue) => {
+            let response: ApiResponse<Venue> =
+                ApiResponse::success("Venue was successfully updated.", Some(venue));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the venue.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/venues/{id}",
+    tag = "Protected Endpoints",
+    summary = "Delete a venue",
+    description = "Deletes a venue that is no longer used by any event. Access is restricted to Admin users and the creator of the venue.",
+    responses(
+        (status = 200, description = "Venue was successfully deleted.", body = DocSuccessApiResponse<Venue>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to delete the venue.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Venue ID")
+    )
+)]
+#[delete("/venues/{id}")]
+async fn delete_venue_by_id(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::delete_venue_by_id(&db, id, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(venue) => {
+            let response: ApiResponse<Venue> =
+                ApiResponse::success("Venue was successfully deleted.", Some(venue));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the venue.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/events/venues/{id}/seating-maps",
+    tag = "Protected Endpoints",
+    summary = "Add a seating map to a venue",
+    description = "Adds a seating configuration made of sections, rows and seats, with the accessible seats of each row. Access is restricted to Admin users and the creator of the venue.",
+    request_body = SeatingMapRequest,
+    responses(
+        (status = 200, description = "Seating map was successfully added.", body = DocSuccessApiResponse<Venue>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to add the seating map.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Venue ID")
+    )
+)]
+#[post("/venues/{id}/seating-maps")]
+async fn add_seating_map(
+    db: Data<Database>,
+    id: Path<String>,
+    payload: Json<SeatingMapRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::add_seating_map(&db, id, payload, jwt_payload.user_id, jwt_payload.role).await {
+        Ok(venue) => {
+            let response: ApiResponse<Venue> =
+                ApiResponse::success("Seating map was successfully added.", Some(venue));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to add the seating map.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/events/venues/{id}/seating-maps/{seating_map_id}",
+    tag = "Protected Endpoints",
+    summary = "Update a seating map of a venue",
+    description = "Replaces the name and layout of a seating map. Events already using it keep the seats they were created with. Access is restricted to Admin users and the creator of the venue.",
+    request_body = SeatingMapRequest,
+    responses(
+        (status = 200, description = "Seating map was successfully updated.", body = DocSuccessApiResponse<Venue>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the seating map.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Venue ID"),
+        ("seating_map_id" = String, Path, description = "Seating map ID")
+    )
+)]
+#[put("/venues/{id}/seating-maps/{seating_map_id}")]
+async fn update_seating_map(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    payload: Json<SeatingMapRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, seating_map_id) = path.into_inner();
+    let payload = payload.into_inner();
+
+    match service::update_seating_map(
+        &db,
+        id,
+        seating_map_id,
+        payload,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(venue) => {
+            let response: ApiResponse<Venue> =
+                ApiResponse::success("Seating map was successfully updated.", Some(venue));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the seating map.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/events/venues/{id}/seating-maps/{seating_map_id}",
+    tag = "Protected Endpoints",
+    summary = "Delete a seating map of a venue",
+    description = "Removes a seating map from a venue. Events already using it keep their seats. Access is restricted to Admin users and the creator of the venue.",
+    responses(
+        (status = 200, description = "Seating map was successfully deleted.", body = DocSuccessApiResponse<Venue>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to delete the seating map.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "Venue ID"),
+        ("seating_map_id" = String, Path, description = "Seating map ID")
+    )
+)]
+#[delete("/venues/{id}/seating-maps/{seating_map_id}")]
+async fn delete_seating_map(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let (id, seating_map_id) = path.into_inner();
+
+    match service::delete_seating_map(
+        &db,
+        id,
+        seating_map_id,
+        jwt_payload.user_id,
+        jwt_payload.role,
+    )
+    .await
+    {
+        Ok(venue) => {
+            let response: ApiResponse<Venue> =
+                ApiResponse::success("Seating map was successfully deleted.", Some(venue));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to delete the seating map.", e.to_string());
             HttpResponse::InternalServerError().json(response)
         }
     }
@@ -0,0 +1,46 @@
+use actix_rt::spawn;
+use mongodb::Database;
+use tokio_schedule::{Job, every};
+
+use crate::service::{
+    complete_past_events, publish_scheduled_events, purge_deleted_events, send_event_reminders,
+};
+
+// =============================================================================================================================
+
+pub async fn cron_jobs(db: Database) {
+    let lifecycle_db = db.clone();
+    let every_minute: std::pin::Pin<Box<dyn Future<Output = ()> + Send>> =
+        every(1).minute().perform(move || {
+            let local_db = lifecycle_db.clone();
+            async move {
+                println!("Running cron job: Publish scheduled events");
+                if let Err(e) = publish_scheduled_events(&local_db).await {
+                    eprintln!("Erreur dans le cron job: {:?}", e);
+                }
+                println!("Running cron job: Complete past events");
+                if let Err(e) = complete_past_events(&local_db).await {
+                    eprintln!("Erreur dans le cron job: {:?}", e);
+                }
+                println!("Running cron job: Send event reminders");
+                if let Err(e) = send_event_reminders(&local_db).await {
+                    eprintln!("Erreur dans le cron job: {:?}", e);
+                }
+            }
+        });
+    spawn(every_minute);
+
+    let every_hour: std::pin::Pin<Box<dyn Future<Output = ()> + Send>> =
+        every(1).hour().perform(move || {
+            let local_db = db.clone();
+            async move {
+                println!("Running cron job: Purge deleted events");
+                if let Err(e) = purge_deleted_events(&local_db).await {
+                    eprintln!("Erreur dans le cron job: {:?}", e);
+                }
+            }
+        });
+    spawn(every_hour);
+}
+
+// =============================================================================================================================
@@ -2,11 +2,47 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder};
 use utoipa::{Modify, OpenApi, openapi::security::SecurityScheme};
 
 use crate::controller::{
-    __path_create_event, __path_delete_event_by_id, __path_get_event_by_id, __path_get_events,
-    __path_health_check, __path_update_event_by_id, __path_update_event_seats_by_id,
+    __path_add_event_series_dates, __path_add_organisation_member, __path_add_presale,
+    __path_add_seating_map, __path_add_ticket_type, __path_approve_event, __path_cancel_event,
+    __path_complete_event, __path_create_access_code, __path_create_category, __path_create_event,
+    __path_create_event_series, __path_create_organisation, __path_create_pass,
+    __path_create_venue, __path_delete_access_code, __path_delete_category,
+    __path_delete_event_by_id, __path_delete_event_series, __path_delete_organisation_by_id,
+    __path_delete_pass, __path_delete_presale, __path_delete_seating_map,
+    __path_delete_ticket_type, __path_delete_venue_by_id, __path_get_access_codes,
+    __path_get_categories, __path_get_deleted_events, __path_get_event_by_id,
+    __path_get_event_calendar, __path_get_event_organiser_role, __path_get_event_sales_statistics,
+    __path_get_event_seats, __path_get_event_series, __path_get_event_series_by_id,
+    __path_get_event_series_statistics, __path_get_events, __path_get_moderation_queue,
+    __path_get_organisation_by_id, __path_get_organisation_sales_statistics,
+    __path_get_organisations, __path_get_pass_by_id, __path_get_passes, __path_get_venue_by_id,
+    __path_get_venues, __path_health_check, __path_postpone_event, __path_publish_event,
+    __path_redeem_access_code, __path_reject_event, __path_release_access_code,
+    __path_remove_organisation_member, __path_restore_event_by_id, __path_resubmit_event,
+    __path_schedule_event, __path_unschedule_event, __path_update_category,
+    __path_update_event_by_id, __path_update_event_seats_by_id, __path_update_event_series,
+    __path_update_organisation_by_id, __path_update_organisation_member, __path_update_presale,
+    __path_update_purchase_limits, __path_update_sale_window, __path_update_seating_map,
+    __path_update_ticket_type, __path_update_venue_by_id,
+};
+use crate::model::{
+    AccessCode, AccessCodeRequest, AddOrganisationMemberRequest, Category, CategoryRequest,
+    CreateEventRequest, CreateEventSeriesRequest, CurrencyAmount, DailyRevenue, Event,
+    EventChanges, EventFacets, EventReview, EventSalesStatistics, EventSearchResponse,
+    EventSearchResult, EventSeat, EventSeries, EventSeriesDetails, EventSortField, FacetCount,
+    GeoPoint, GeoPointType, Organisation, OrganisationMember, OrganisationRequest,
+    OrganisationSalesStatistics, Pass, PassEvent, PassRequest, PostponeEventRequest, Presale,
+    PresaleRequest, PurchaseLimits, RecurrenceFrequency, RecurrenceRule, RejectEventRequest,
+    ReviewAction, ReviewStatus, SaleWindowRequest, ScheduleEventRequest, SeatingMap,
+    SeatingMapRequest, SeatingRow, SeatingSection, SeriesDateRequest, SeriesDateStatistics,
+    SeriesDatesRequest, SeriesStatistics, SortOrder, TicketCounts, TicketType, TicketTypeRequest,
+    UpdateCategoryRequest, UpdateEventRequest, UpdateEventSeriesRequest,
+    UpdateOrganisationMemberRequest, UpdateSeatsRequest, UseAccessCodeRequest, Venue, VenueAddress,
+    VenueRequest,
+};
+use common::models::{
+    AuthRole, EventOrganiserRole, EventStatus, OrganisationRole, PaymentCurrency,
 };
-use crate::model::{CreateEventRequest, Event, UpdateEventRequest, UpdateSeatsRequest};
-use common::models::AuthRole;
 
 // =============================================================================================================================
 
@@ -18,24 +54,156 @@ use common::models::AuthRole;
         description = r#"
 The Events Service manages event data including title, description, date, capacity, and location.
 It provides endpoints for retrieving, creating, updating, and deleting events, as well as updating the remaining seats.
+Each event can sell several ticket types, each with its own price, quantity, sale window and seat range.
+Events follow a lifecycle: they are created as drafts, published immediately or at a scheduled date, and can then be postponed, cancelled or completed.
+Events can be owned by organisations, teams of organisers whose members hold an Owner, Manager, BoxOffice or Viewer role.
+Events can take place in registered venues; when they use one of its seating maps, their seat inventory is generated from it.
+The public sale of an event can be limited to a window, and presales let the holders of an access code buy tickets before it opens.
+Events are classified in a managed category taxonomy and tagged freely, and their search returns facet counts per category, city and month.
+Events are located with GeoJSON coordinates and keep their timezone, so they can be searched around a location and their dates shown in local time.
+Events can be grouped in series, such as tours, whose dates share their metadata and are created in bulk from a list of dates or a recurrence rule.
+Owners can follow the sales of their events and organisations: tickets by status, sell-through, revenue by day and currency, refunds and cancellations.
 "#
     ),
     paths(
         health_check,
         get_events,
         get_event_by_id,
+        get_event_calendar,
+        get_event_seats,
         create_event,
         update_event_by_id,
         update_event_seats_by_id,
-        delete_event_by_id
+        add_ticket_type,
+        update_ticket_type,
+        delete_ticket_type,
+        update_sale_window,
+        update_purchase_limits,
+        add_presale,
+        update_presale,
+        delete_presale,
+        get_access_codes,
+        create_access_code,
+        delete_access_code,
+        redeem_access_code,
+        release_access_code,
+        publish_event,
+        schedule_event,
+        unschedule_event,
+        postpone_event,
+        cancel_event,
+        complete_event,
+        get_moderation_queue,
+        approve_event,
+        reject_event,
+        resubmit_event,
+        delete_event_by_id,
+        get_deleted_events,
+        restore_event_by_id,
+        get_event_organiser_role,
+        get_organisations,
+        get_organisation_by_id,
+        create_organisation,
+        update_organisation_by_id,
+        delete_organisation_by_id,
+        add_organisation_member,
+        update_organisation_member,
+        remove_organisation_member,
+        get_event_series,
+        get_event_series_by_id,
+        create_event_series,
+        add_event_series_dates,
+        update_event_series,
+        delete_event_series,
+        get_event_series_statistics,
+        get_event_sales_statistics,
+        get_organisation_sales_statistics,
+        get_categories,
+        create_category,
+        update_category,
+        delete_category,
+        get_passes,
+        get_pass_by_id,
+        create_pass,
+        delete_pass,
+        get_venues,
+        get_venue_by_id,
+        create_venue,
+        update_venue_by_id,
+        delete_venue_by_id,
+        add_seating_map,
+        update_seating_map,
+        delete_seating_map
     ),
     components(
         schemas(
             Event,
+            EventStatus,
+            EventSearchResponse,
+            EventSearchResult,
+            EventFacets,
+            FacetCount,
+            EventSortField,
+            GeoPoint,
+            GeoPointType,
+            SortOrder,
             CreateEventRequest,
             UpdateEventRequest,
+            TicketType,
+            TicketTypeRequest,
+            SaleWindowRequest,
+            PurchaseLimits,
+            Presale,
+            PresaleRequest,
+            AccessCode,
+            AccessCodeRequest,
+            UseAccessCodeRequest,
+            PaymentCurrency,
+            ScheduleEventRequest,
+            PostponeEventRequest,
+            ReviewStatus,
+            ReviewAction,
+            EventChanges,
+            EventReview,
+            RejectEventRequest,
             UpdateSeatsRequest,
-            AuthRole
+            Organisation,
+            OrganisationMember,
+            OrganisationRequest,
+            AddOrganisationMemberRequest,
+            UpdateOrganisationMemberRequest,
+            EventSeries,
+            EventSeriesDetails,
+            CreateEventSeriesRequest,
+            SeriesDatesRequest,
+            SeriesDateRequest,
+            RecurrenceRule,
+            RecurrenceFrequency,
+            UpdateEventSeriesRequest,
+            SeriesStatistics,
+            SeriesDateStatistics,
+            EventSalesStatistics,
+            OrganisationSalesStatistics,
+            TicketCounts,
+            CurrencyAmount,
+            DailyRevenue,
+            Category,
+            CategoryRequest,
+            UpdateCategoryRequest,
+            Pass,
+            PassEvent,
+            PassRequest,
+            Venue,
+            VenueAddress,
+            VenueRequest,
+            SeatingMap,
+            SeatingSection,
+            SeatingRow,
+            SeatingMapRequest,
+            EventSeat,
+            EventOrganiserRole,
+            AuthRole,
+            OrganisationRole
         )
     ),
     security(
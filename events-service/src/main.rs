@@ -1,8 +1,10 @@
 use actix_web::{App, HttpServer, web};
 use controller::config;
-use extractor::deserialize_error_extractor;
+use cron_jobs::cron_jobs;
+use extractor::{deserialize_error_extractor, deserialize_query_error_extractor};
 
 mod controller;
+mod cron_jobs;
 mod db;
 mod doc;
 mod extractor;
@@ -18,11 +20,17 @@ async fn main() -> std::io::Result<()> {
     let db = db::init_db()
         .await
         .expect("❌ Failed to connect to database");
+    let db_for_cron = db.clone();
+
+    actix_rt::spawn(async move {
+        cron_jobs(db_for_cron).await;
+    });
 
     HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(db.clone()))
             .app_data(deserialize_error_extractor())
+            .app_data(deserialize_query_error_extractor())
             .configure(config)
     })
     .bind(("0.0.0.0", 8080))?
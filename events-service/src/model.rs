@@ -1,11 +1,13 @@
+use common::models::{EventStatus, OrganisationRole, PaymentCurrency};
 use common::utils::utils::{
-    deserialize_datetime_from_any, serialize_option_object_id_as_hex_string, trim,
-    validate_date_not_in_past,
+    SLUG_REGEX, deserialize_datetime_from_any, deserialize_option_datetime_from_any,
+    serialize_option_datetime_as_rfc3339_string, serialize_option_object_id_as_hex_string, trim,
+    trim_lowercase, trim_lowercase_option, trim_option, validate_date_not_in_past,
 };
 use mongodb::bson::serde_helpers::serialize_bson_datetime_as_rfc3339_string;
 use mongodb::bson::{DateTime, oid::ObjectId};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::{Validate, ValidationError};
 
 // =============================================================================================================================
@@ -27,6 +29,14 @@ pub struct Event {
     #[schema(example = "A fun and exciting outdoor music festival.", value_type = String)]
     pub description: String,
 
+    #[serde(default)]
+    #[schema(example = "festival", value_type = Option<String>)]
+    pub category: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = json!(["outdoor", "electro"]))]
+    pub tags: Vec<String>,
+
     #[serde(
         deserialize_with = "deserialize_datetime_from_any",
         serialize_with = "serialize_bson_datetime_as_rfc3339_string"
@@ -40,12 +50,48 @@ pub struct Event {
     #[schema(example = "Central Park", value_type = String)]
     pub location: String,
 
+    #[serde(default)]
+    #[schema(example = "New York", value_type = Option<String>)]
+    pub city: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geo_location: Option<GeoPoint>,
+
+    #[serde(default = "default_timezone")]
+    #[schema(example = "Europe/Paris", value_type = String)]
+    pub timezone: String,
+
     #[schema(example = 450)]
     pub remaining_seats: u32,
 
     #[schema(example = 75)]
     pub price: u32,
 
+    #[serde(default)]
+    pub ticket_types: Vec<TicketType>,
+
+    #[serde(default)]
+    pub purchase_limits: PurchaseLimits,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-06-01T09:00:00Z", value_type = Option<String>)]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-08-15T12:00:00Z", value_type = Option<String>)]
+    pub sale_ends_at: Option<DateTime>,
+
+    #[serde(default)]
+    pub presales: Vec<Presale>,
+
     #[serde(
         deserialize_with = "deserialize_datetime_from_any",
         serialize_with = "serialize_bson_datetime_as_rfc3339_string"
@@ -56,11 +102,62 @@ pub struct Event {
     #[serde(rename = "creator_id")]
     #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
     pub creator_id: ObjectId,
+
+    #[serde(default)]
+    #[schema(example = "64a1c2d3e4f5a6b7c8d9e0f1", value_type = String)]
+    pub organisation_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c5", value_type = String)]
+    pub venue_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c6", value_type = String)]
+    pub seating_map_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "6701a2b3c4d5e6f7a8b9c0d1", value_type = String)]
+    pub series_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "Published", value_type = String)]
+    pub status: EventStatus,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-07-01T09:00:00Z", value_type = String)]
+    pub publish_at: Option<DateTime>,
+
+    #[serde(default)]
+    #[schema(example = "Approved", value_type = String)]
+    pub review_status: ReviewStatus,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_changes: Option<EventChanges>,
+
+    #[serde(default)]
+    pub review_history: Vec<EventReview>,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T09:00:00.000Z", value_type = String)]
+    pub deleted_at: Option<DateTime>,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub deleted_by: Option<ObjectId>,
 }
 
 // =============================================================================================================================
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_create_event", skip_on_field_errors = false))]
 pub struct CreateEventRequest {
     #[serde(deserialize_with = "trim")]
     #[schema(example = "Music Festival", value_type = String)]
@@ -80,14 +177,47 @@ pub struct CreateEventRequest {
     ))]
     pub description: String,
 
-    #[serde(deserialize_with = "trim")]
+    #[serde(default, deserialize_with = "trim_lowercase_option")]
+    #[schema(example = "festival", value_type = Option<String>)]
+    pub category: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = json!(["outdoor", "electro"]))]
+    #[validate(custom(function = "validate_tags"))]
+    pub tags: Vec<String>,
+
+    #[serde(default, deserialize_with = "trim")]
     #[schema(example = "Central Park", value_type = String)]
+    #[validate(length(max = 500, message = "Location must be between 2 and 75 characters"))]
+    pub location: String,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "New York", value_type = Option<String>)]
     #[validate(length(
         min = 2,
-        max = 500,
-        message = "Location must be between 2 and 75 characters"
+        max = 100,
+        message = "City must be between 2 and 100 characters"
     ))]
-    pub location: String,
+    pub city: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = 40.7812)]
+    #[validate(range(min = -90.0, max = 90.0, message = "Latitude must be between -90 and 90."))]
+    pub latitude: Option<f64>,
+
+    #[serde(default)]
+    #[schema(example = -73.9665)]
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "Longitude must be between -180 and 180."
+    ))]
+    pub longitude: Option<f64>,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "America/New_York", value_type = Option<String>)]
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: Option<String>,
 
     #[serde(
         serialize_with = "serialize_bson_datetime_as_rfc3339_string",
@@ -97,65 +227,151 @@ pub struct CreateEventRequest {
     #[validate(custom(function = "validate_date_not_in_past"))]
     pub date: DateTime,
 
+    #[serde(default)]
     #[schema(example = 500)]
-    #[validate(range(min = 25, message = "Capacity must be at least 25"))]
     pub capacity: u32,
 
     #[schema(example = 75)]
     #[validate(range(min = 1, message = "Price must be at least one."))]
     pub price: u32,
+
+    #[serde(default)]
+    #[schema(example = "64a1c2d3e4f5a6b7c8d9e0f1", value_type = String)]
+    pub organisation_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c5", value_type = String)]
+    pub venue_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c6", value_type = String)]
+    pub seating_map_id: Option<ObjectId>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-06-01T09:00:00Z", value_type = Option<String>)]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-08-15T12:00:00Z", value_type = Option<String>)]
+    pub sale_ends_at: Option<DateTime>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub purchase_limits: PurchaseLimits,
+}
+
+// =============================================================================================================================
+
+fn validate_create_event(req: &CreateEventRequest) -> Result<(), ValidationError> {
+    if req.venue_id.is_none() && req.location.chars().count() < 2 {
+        let mut err = ValidationError::new("location_required");
+        err.message = Some("Location must be between 2 and 75 characters".into());
+        return Err(err);
+    }
+    if req.seating_map_id.is_some() && req.venue_id.is_none() {
+        let mut err = ValidationError::new("venue_required");
+        err.message = Some("A seating map can only be used with its venue.".into());
+        return Err(err);
+    }
+    validate_coordinates(req.latitude, req.longitude)?;
+    if req.seating_map_id.is_none() && req.capacity < 25 {
+        let mut err = ValidationError::new("capacity_too_low");
+        err.message = Some("Capacity must be at least 25".into());
+        return Err(err);
+    }
+    validate_sale_window(req.sale_starts_at, req.sale_ends_at)?;
+    if req.sale_ends_at.is_some_and(|ends_at| ends_at > req.date) {
+        let mut err = ValidationError::new("sale_ends_after_event");
+        err.message = Some("The sale cannot end after the event.".into());
+        return Err(err);
+    }
+    Ok(())
 }
 
 // =============================================================================================================================
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
 #[validate(schema(function = "validate_update_event", skip_on_field_errors = false))]
 pub struct UpdateEventRequest {
-    #[serde(deserialize_with = "trim")]
-    #[schema(example = "Updated Music Festival", value_type = String)]
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "Updated Music Festival", value_type = Option<String>)]
     #[validate(length(
         min = 2,
         max = 100,
         message = "Title must be between 2 and 100 characters"
     ))]
-    pub title: String,
+    pub title: Option<String>,
 
-    #[serde(deserialize_with = "trim")]
-    #[schema(example = "An updated description of the music festival.", value_type = String)]
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "An updated description of the music festival.", value_type = Option<String>)]
     #[validate(length(
         min = 10,
         max = 500,
         message = "Description must be between 10 and 500 characters"
     ))]
-    pub description: String,
+    pub description: Option<String>,
 
-    #[serde(deserialize_with = "trim")]
-    #[schema(example = "Downtown Arena", value_type = String)]
+    #[serde(default, deserialize_with = "trim_lowercase_option")]
+    #[schema(example = "concert", value_type = Option<String>)]
+    pub category: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = json!(["metal", "open-air"]))]
+    #[validate(custom(function = "validate_tags"))]
+    pub tags: Option<Vec<String>>,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "Downtown Arena", value_type = Option<String>)]
     #[validate(length(
         min = 2,
         max = 500,
         message = "Location must be between 2 and 75 characters"
     ))]
-    pub location: String,
+    pub location: Option<String>,
 
-    #[serde(
-        serialize_with = "serialize_bson_datetime_as_rfc3339_string",
-        deserialize_with = "deserialize_datetime_from_any"
-    )]
-    #[schema(example = "2025-09-01T20:00:00Z", value_type = String)]
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "Paris", value_type = Option<String>)]
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "City must be between 2 and 100 characters"
+    ))]
+    pub city: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = 48.8386)]
+    #[validate(range(min = -90.0, max = 90.0, message = "Latitude must be between -90 and 90."))]
+    pub latitude: Option<f64>,
+
+    #[serde(default)]
+    #[schema(example = 2.3786)]
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "Longitude must be between -180 and 180."
+    ))]
+    pub longitude: Option<f64>,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "Europe/Paris", value_type = Option<String>)]
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-09-01T20:00:00Z", value_type = Option<String>)]
     #[validate(custom(function = "validate_date_not_in_past"))]
-    pub date: DateTime,
+    pub date: Option<DateTime>,
 
+    #[serde(default)]
     #[schema(example = 600)]
     #[validate(range(min = 25, message = "Capacity must be at least 25"))]
-    pub capacity: u32,
-
-    #[schema(example = 550)]
-    pub remaining_seats: u32,
+    pub capacity: Option<u32>,
 
+    #[serde(default)]
     #[schema(example = 85)]
     #[validate(range(min = 1, message = "Price must be at least one."))]
-    pub price: u32,
+    pub price: Option<u32>,
 }
 
 // =============================================================================================================================
@@ -164,17 +380,1825 @@ pub struct UpdateEventRequest {
 pub struct UpdateSeatsRequest {
     #[schema(example = -10)]
     pub delta: i32,
+
+    #[serde(default)]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = 42)]
+    pub seat_number: Option<u32>,
 }
 
 // =============================================================================================================================
 
-fn validate_update_event(req: &UpdateEventRequest) -> Result<(), ValidationError> {
-    if req.remaining_seats > req.capacity {
-        let mut err = ValidationError::new("remaining_seats_exceeds_capacity");
-        err.message = Some("Remaining seats cannot exceed capacity.".into());
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TicketType {
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = String)]
+    pub id: ObjectId,
+
+    #[schema(example = "VIP", value_type = String)]
+    pub name: String,
+
+    #[schema(example = 150)]
+    pub price: u32,
+
+    #[schema(example = "Eur", value_type = String)]
+    pub currency: PaymentCurrency,
+
+    #[schema(example = 50)]
+    pub quantity: u32,
+
+    #[schema(example = 12)]
+    pub remaining: u32,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-06-01T09:00:00Z", value_type = Option<String>)]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-08-15T12:00:00Z", value_type = Option<String>)]
+    pub sale_ends_at: Option<DateTime>,
+
+    #[serde(default)]
+    #[schema(example = 1)]
+    pub seat_from: Option<u32>,
+
+    #[serde(default)]
+    #[schema(example = 50)]
+    pub seat_to: Option<u32>,
+
+    #[serde(default)]
+    pub purchase_limits: PurchaseLimits,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_ticket_type", skip_on_field_errors = false))]
+pub struct TicketTypeRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "VIP", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 50,
+        message = "Name must be between 2 and 50 characters"
+    ))]
+    pub name: String,
+
+    #[schema(example = 150)]
+    #[validate(range(min = 1, message = "Price must be at least one."))]
+    pub price: u32,
+
+    #[schema(example = "Eur", value_type = String)]
+    pub currency: PaymentCurrency,
+
+    #[schema(example = 50)]
+    #[validate(range(min = 1, message = "Quantity must be at least one."))]
+    pub quantity: u32,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-06-01T09:00:00Z", value_type = Option<String>)]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-08-15T12:00:00Z", value_type = Option<String>)]
+    pub sale_ends_at: Option<DateTime>,
+
+    #[serde(default)]
+    #[schema(example = 1)]
+    #[validate(range(min = 1, message = "Seat numbers start at one."))]
+    pub seat_from: Option<u32>,
+
+    #[serde(default)]
+    #[schema(example = 50)]
+    #[validate(range(min = 1, message = "Seat numbers start at one."))]
+    pub seat_to: Option<u32>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub purchase_limits: PurchaseLimits,
+}
+
+// =============================================================================================================================
+
+fn validate_ticket_type(req: &TicketTypeRequest) -> Result<(), ValidationError> {
+    if matches!(
+        (req.sale_starts_at, req.sale_ends_at),
+        (Some(starts_at), Some(ends_at)) if starts_at >= ends_at
+    ) {
+        let mut err = ValidationError::new("sale_window_invalid");
+        err.message = Some("The sale must start before it ends.".into());
+        return Err(err);
+    }
+    if matches!(
+        (req.seat_from, req.seat_to),
+        (Some(seat_from), Some(seat_to)) if seat_from > seat_to
+    ) {
+        let mut err = ValidationError::new("seat_range_invalid");
+        err.message = Some("The first seat cannot be after the last seat.".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+fn validate_sale_window(
+    starts_at: Option<DateTime>,
+    ends_at: Option<DateTime>,
+) -> Result<(), ValidationError> {
+    if matches!((starts_at, ends_at), (Some(starts_at), Some(ends_at)) if starts_at >= ends_at) {
+        let mut err = ValidationError::new("sale_window_invalid");
+        err.message = Some("The sale must start before it ends.".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(
+    function = "validate_sale_window_request",
+    skip_on_field_errors = false
+))]
+pub struct SaleWindowRequest {
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-06-01T09:00:00Z", value_type = Option<String>)]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-08-15T12:00:00Z", value_type = Option<String>)]
+    pub sale_ends_at: Option<DateTime>,
+}
+
+// =============================================================================================================================
+
+fn validate_sale_window_request(req: &SaleWindowRequest) -> Result<(), ValidationError> {
+    validate_sale_window(req.sale_starts_at, req.sale_ends_at)
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct PurchaseLimits {
+    #[serde(default)]
+    #[schema(example = 4)]
+    #[validate(range(min = 1, message = "A purchase limit must be at least one."))]
+    pub max_per_user: Option<u32>,
+
+    #[serde(default)]
+    #[schema(example = 4)]
+    #[validate(range(min = 1, message = "A purchase limit must be at least one."))]
+    pub max_per_card: Option<u32>,
+
+    #[serde(default)]
+    #[schema(example = 6)]
+    #[validate(range(min = 1, message = "A purchase limit must be at least one."))]
+    pub max_per_household: Option<u32>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Presale {
+    #[schema(example = "66f1a2b3c4d5e6f7a8b9c0d1", value_type = String)]
+    pub id: ObjectId,
+
+    #[schema(example = "Fan club presale", value_type = String)]
+    pub name: String,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-05-25T09:00:00Z", value_type = String)]
+    pub starts_at: DateTime,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-06-01T09:00:00Z", value_type = String)]
+    pub ends_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_presale", skip_on_field_errors = false))]
+pub struct PresaleRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "Fan club presale", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 50,
+        message = "Name must be between 2 and 50 characters"
+    ))]
+    pub name: String,
+
+    #[serde(deserialize_with = "deserialize_datetime_from_any")]
+    #[schema(example = "2025-05-25T09:00:00Z", value_type = String)]
+    pub starts_at: DateTime,
+
+    #[serde(deserialize_with = "deserialize_datetime_from_any")]
+    #[schema(example = "2025-06-01T09:00:00Z", value_type = String)]
+    #[validate(custom(function = "validate_date_not_in_past"))]
+    pub ends_at: DateTime,
+}
+
+// =============================================================================================================================
+
+fn validate_presale(req: &PresaleRequest) -> Result<(), ValidationError> {
+    if req.starts_at >= req.ends_at {
+        let mut err = ValidationError::new("presale_window_invalid");
+        err.message = Some("The presale must start before it ends.".into());
         return Err(err);
     }
     Ok(())
 }
 
 // =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AccessCode {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "66f1a2b3c4d5e6f7a8b9c0d2", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "event_id")]
+    #[schema(example = "63f7b1c0a1234567890abcdef", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(rename = "presale_id")]
+    #[schema(example = "66f1a2b3c4d5e6f7a8b9c0d1", value_type = String)]
+    pub presale_id: ObjectId,
+
+    #[schema(example = "FANCLUB2025", value_type = String)]
+    pub code: String,
+
+    #[schema(example = 100)]
+    pub max_uses: u32,
+
+    #[schema(example = 12)]
+    pub uses: u32,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-05-01T12:00:00Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AccessCodeRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "FANCLUB2025", value_type = String)]
+    #[validate(length(
+        min = 4,
+        max = 32,
+        message = "Code must be between 4 and 32 characters"
+    ))]
+    pub code: String,
+
+    #[schema(example = 100)]
+    #[validate(range(min = 1, message = "An access code must have at least one use."))]
+    pub max_uses: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UseAccessCodeRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "FANCLUB2025", value_type = String)]
+    pub code: String,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Pass {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "6731d2e3f4a5b6c7d8e9f0a1", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[schema(example = "Festival 3-day pass", value_type = String)]
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "Entry to the three days of the festival", value_type = Option<String>)]
+    pub description: Option<String>,
+
+    pub events: Vec<PassEvent>,
+
+    #[schema(example = 240)]
+    pub price: u32,
+
+    #[schema(example = "Eur", value_type = String)]
+    pub currency: PaymentCurrency,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-06-01T09:00:00Z", value_type = Option<String>)]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-08-15T12:00:00Z", value_type = Option<String>)]
+    pub sale_ends_at: Option<DateTime>,
+
+    #[serde(rename = "creator_id")]
+    #[schema(example = "67d88106c3f7903ba0f9211a", value_type = String)]
+    pub creator_id: ObjectId,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-05-01T12:00:00Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PassEvent {
+    #[serde(rename = "event_id")]
+    #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(default)]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_pass", skip_on_field_errors = false))]
+pub struct PassRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "Festival 3-day pass", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "Name must be between 2 and 100 characters"
+    ))]
+    pub name: String,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "Entry to the three days of the festival", value_type = Option<String>)]
+    #[validate(length(max = 1000, message = "Description must be at most 1000 characters"))]
+    pub description: Option<String>,
+
+    #[validate(length(
+        min = 2,
+        max = 20,
+        message = "A pass must grant entry to between 2 and 20 events"
+    ))]
+    pub events: Vec<PassEvent>,
+
+    #[schema(example = 240)]
+    #[validate(range(min = 1, message = "Price must be at least one."))]
+    pub price: u32,
+
+    #[schema(example = "Eur", value_type = String)]
+    pub currency: PaymentCurrency,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-06-01T09:00:00Z", value_type = Option<String>)]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-08-15T12:00:00Z", value_type = Option<String>)]
+    pub sale_ends_at: Option<DateTime>,
+}
+
+// =============================================================================================================================
+
+fn validate_pass(req: &PassRequest) -> Result<(), ValidationError> {
+    let mut event_ids: Vec<ObjectId> = req.events.iter().map(|e| e.event_id).collect();
+    event_ids.sort_unstable();
+    event_ids.dedup();
+    if event_ids.len() != req.events.len() {
+        let mut err = ValidationError::new("pass_events_duplicated");
+        err.message = Some("An event can only appear once in a pass.".into());
+        return Err(err);
+    }
+    validate_sale_window(req.sale_starts_at, req.sale_ends_at)
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ScheduleEventRequest {
+    #[serde(deserialize_with = "deserialize_datetime_from_any")]
+    #[schema(example = "2025-07-01T09:00:00Z", value_type = String)]
+    #[validate(custom(function = "validate_date_not_in_past"))]
+    pub publish_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct PostponeEventRequest {
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[schema(example = "2025-10-15T18:00:00Z", value_type = Option<String>)]
+    #[validate(custom(function = "validate_date_not_in_past"))]
+    pub date: Option<DateTime>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default, ToSchema)]
+pub enum ReviewStatus {
+    Pending,
+    #[default]
+    Approved,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum ReviewAction {
+    Submitted,
+    Approved,
+    Rejected,
+}
+
+// =============================================================================================================================
+
+// The fields reviewed by the moderators, the other ones are applied right away.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct EventChanges {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "Updated Music Festival", value_type = Option<String>)]
+    pub title: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "An updated description of the music festival.", value_type = Option<String>)]
+    pub description: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "concert", value_type = Option<String>)]
+    pub category: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = json!(["metal", "open-air"]))]
+    pub tags: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "Downtown Arena", value_type = Option<String>)]
+    pub location: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "Paris", value_type = Option<String>)]
+    pub city: Option<String>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventReview {
+    #[schema(example = "Rejected", value_type = String)]
+    pub action: ReviewAction,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changes: Option<EventChanges>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "The description advertises another event.", value_type = Option<String>)]
+    pub reason: Option<String>,
+
+    #[serde(rename = "user_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T09:00:00.000Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RejectEventRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "The description advertises another event.", value_type = String)]
+    #[validate(length(
+        min = 5,
+        max = 500,
+        message = "Reason must be between 5 and 500 characters"
+    ))]
+    pub reason: String,
+}
+
+// =============================================================================================================================
+
+fn validate_update_event(req: &UpdateEventRequest) -> Result<(), ValidationError> {
+    if req.title.is_none()
+        && req.description.is_none()
+        && req.category.is_none()
+        && req.tags.is_none()
+        && req.location.is_none()
+        && req.city.is_none()
+        && req.latitude.is_none()
+        && req.longitude.is_none()
+        && req.timezone.is_none()
+        && req.date.is_none()
+        && req.capacity.is_none()
+        && req.price.is_none()
+    {
+        let mut err = ValidationError::new("no_field_to_update");
+        err.message = Some("At least one field must be updated.".into());
+        return Err(err);
+    }
+    validate_coordinates(req.latitude, req.longitude)
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum GeoPointType {
+    Point,
+}
+
+// =============================================================================================================================
+
+// A GeoJSON point, stored with its longitude first so that it can be indexed as `2dsphere`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct GeoPoint {
+    #[serde(rename = "type")]
+    #[schema(example = "Point", value_type = String)]
+    pub kind: GeoPointType,
+
+    #[schema(example = json!([2.3786, 48.8386]))]
+    pub coordinates: [f64; 2],
+}
+
+impl GeoPoint {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        GeoPoint {
+            kind: GeoPointType::Point,
+            coordinates: [longitude, latitude],
+        }
+    }
+
+    pub fn from_coordinates(latitude: Option<f64>, longitude: Option<f64>) -> Option<Self> {
+        match (latitude, longitude) {
+            (Some(latitude), Some(longitude)) => Some(GeoPoint::new(latitude, longitude)),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================================================================
+
+fn validate_coordinates(
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+) -> Result<(), ValidationError> {
+    if latitude.is_some() != longitude.is_some() {
+        let mut err = ValidationError::new("coordinates_incomplete");
+        err.message = Some("Latitude and longitude must be given together.".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+fn validate_timezone(timezone: &str) -> Result<(), ValidationError> {
+    if timezone.parse::<chrono_tz::Tz>().is_err() {
+        let mut err = ValidationError::new("timezone_invalid");
+        err.message = Some("Timezone must be an IANA timezone such as Europe/Paris.".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+pub fn default_timezone() -> String {
+    "UTC".to_string()
+}
+
+// =============================================================================================================================
+
+fn validate_tags(tags: &[String]) -> Result<(), ValidationError> {
+    if tags.len() > 10 {
+        let mut err = ValidationError::new("too_many_tags");
+        err.message = Some("An event can have at most 10 tags.".into());
+        return Err(err);
+    }
+    if tags
+        .iter()
+        .any(|tag| !(2..=30).contains(&tag.trim().chars().count()))
+    {
+        let mut err = ValidationError::new("tag_invalid");
+        err.message = Some("Tags must be between 2 and 30 characters".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganisationMember {
+    #[serde(rename = "user_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[schema(example = "Manager", value_type = String)]
+    pub role: OrganisationRole,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2023-06-01T12:00:00Z", value_type = String)]
+    pub added_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Organisation {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "64a1c2d3e4f5a6b7c8d9e0f1", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[schema(example = "Live Nation Paris", value_type = String)]
+    pub name: String,
+
+    pub members: Vec<OrganisationMember>,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2023-06-01T12:00:00Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct OrganisationRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "Live Nation Paris", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "Name must be between 2 and 100 characters"
+    ))]
+    pub name: String,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AddOrganisationMemberRequest {
+    #[serde(rename = "user_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[schema(example = "BoxOffice", value_type = String)]
+    pub role: OrganisationRole,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateOrganisationMemberRequest {
+    #[schema(example = "Viewer", value_type = String)]
+    pub role: OrganisationRole,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Copy, Deserialize, ToSchema)]
+pub enum EventSortField {
+    Date,
+    Price,
+    CreatedAt,
+    Title,
+    Distance,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, ToSchema)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+#[validate(schema(function = "validate_search_events", skip_on_field_errors = false))]
+pub struct SearchEventsQuery {
+    #[validate(length(max = 100, message = "Search query must be at most 100 characters"))]
+    #[param(example = "jazz festival")]
+    pub q: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[param(example = "2025-08-01T00:00:00Z", value_type = Option<String>)]
+    pub date_from: Option<DateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[param(example = "2025-08-31T23:59:59Z", value_type = Option<String>)]
+    pub date_to: Option<DateTime>,
+
+    #[param(example = false)]
+    pub include_past: Option<bool>,
+
+    #[validate(length(max = 100, message = "Location must be at most 100 characters"))]
+    #[param(example = "Paris")]
+    pub location: Option<String>,
+
+    #[param(example = 10)]
+    pub price_min: Option<u32>,
+
+    #[param(example = 100)]
+    pub price_max: Option<u32>,
+
+    #[param(example = true)]
+    pub has_remaining_seats: Option<bool>,
+
+    #[param(example = "6701a2b3c4d5e6f7a8b9c0d1")]
+    pub series_id: Option<String>,
+
+    #[validate(length(max = 50, message = "Category must be at most 50 characters"))]
+    #[param(example = "concert")]
+    pub category: Option<String>,
+
+    #[validate(length(max = 200, message = "Tags must be at most 200 characters"))]
+    #[param(example = "metal,open-air")]
+    pub tags: Option<String>,
+
+    #[validate(length(max = 100, message = "City must be at most 100 characters"))]
+    #[param(example = "Paris")]
+    pub city: Option<String>,
+
+    #[validate(range(min = -90.0, max = 90.0, message = "Latitude must be between -90 and 90."))]
+    #[param(example = 48.8566)]
+    pub lat: Option<f64>,
+
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "Longitude must be between -180 and 180."
+    ))]
+    #[param(example = 2.3522)]
+    pub lon: Option<f64>,
+
+    #[validate(range(
+        min = 0.1,
+        max = 500.0,
+        message = "Radius must be between 0.1 and 500 kilometres."
+    ))]
+    #[param(example = 50)]
+    pub radius: Option<f64>,
+
+    #[param(example = "Date", value_type = Option<String>)]
+    pub sort_by: Option<EventSortField>,
+
+    #[param(example = "Asc", value_type = Option<String>)]
+    pub order: Option<SortOrder>,
+
+    #[param(example = "63f7b1c0a1234567890abcde")]
+    pub cursor: Option<String>,
+
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100."))]
+    #[param(example = 20)]
+    pub limit: Option<i64>,
+}
+
+// =============================================================================================================================
+
+fn validate_search_events(query: &SearchEventsQuery) -> Result<(), ValidationError> {
+    if query.lat.is_some() != query.lon.is_some() {
+        let mut err = ValidationError::new("coordinates_incomplete");
+        err.message = Some("Latitude and longitude must be given together.".into());
+        return Err(err);
+    }
+    if query.lat.is_none() && query.radius.is_some() {
+        let mut err = ValidationError::new("coordinates_required");
+        err.message = Some("A radius can only be used with a latitude and a longitude.".into());
+        return Err(err);
+    }
+    if query.lat.is_none() && matches!(query.sort_by, Some(EventSortField::Distance)) {
+        let mut err = ValidationError::new("coordinates_required");
+        err.message = Some("Events can only be sorted by distance from a location.".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSearchResult {
+    #[serde(flatten)]
+    pub event: Event,
+
+    #[schema(example = "2025-08-15T20:00:00+02:00", value_type = String)]
+    pub local_date: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 12.4)]
+    pub distance_km: Option<f64>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FacetCount {
+    #[schema(example = "concert", value_type = String)]
+    pub value: String,
+
+    #[schema(example = 42)]
+    pub count: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventFacets {
+    pub categories: Vec<FacetCount>,
+    pub cities: Vec<FacetCount>,
+    pub months: Vec<FacetCount>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSearchResponse {
+    pub events: Vec<EventSearchResult>,
+
+    #[schema(example = "63f7b1c0a1234567890abcde", value_type = Option<String>)]
+    pub next_cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<EventFacets>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct VenueAddress {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "8 Boulevard de Bercy", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 200,
+        message = "Street must be between 2 and 200 characters"
+    ))]
+    pub street: String,
+
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "Paris", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "City must be between 2 and 100 characters"
+    ))]
+    pub city: String,
+
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "75012", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 20,
+        message = "Postal code must be between 2 and 20 characters"
+    ))]
+    pub postal_code: String,
+
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "France", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "Country must be between 2 and 100 characters"
+    ))]
+    pub country: String,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SeatingRow {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "A", value_type = String)]
+    #[validate(length(
+        min = 1,
+        max = 20,
+        message = "Row name must be between 1 and 20 characters"
+    ))]
+    pub name: String,
+
+    #[schema(example = 24)]
+    #[validate(range(
+        min = 1,
+        max = 500,
+        message = "A row must have between 1 and 500 seats."
+    ))]
+    pub seats: u32,
+
+    #[serde(default)]
+    #[schema(example = json!([1, 2]))]
+    pub accessible_seats: Vec<u32>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SeatingSection {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "Orchestra", value_type = String)]
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Section name must be between 1 and 50 characters"
+    ))]
+    pub name: String,
+
+    #[validate(
+        length(
+            min = 1,
+            max = 200,
+            message = "A section must have between 1 and 200 rows."
+        ),
+        nested
+    )]
+    pub rows: Vec<SeatingRow>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatingMap {
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c6", value_type = String)]
+    pub id: ObjectId,
+
+    #[schema(example = "Concert - standing pit removed", value_type = String)]
+    pub name: String,
+
+    pub sections: Vec<SeatingSection>,
+}
+
+impl SeatingMap {
+    pub fn capacity(&self) -> u32 {
+        self.sections
+            .iter()
+            .flat_map(|section| &section.rows)
+            .map(|row| row.seats)
+            .sum()
+    }
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_seating_map", skip_on_field_errors = false))]
+pub struct SeatingMapRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "Concert - standing pit removed", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "Name must be between 2 and 100 characters"
+    ))]
+    pub name: String,
+
+    #[validate(
+        length(
+            min = 1,
+            max = 50,
+            message = "A seating map must have between 1 and 50 sections."
+        ),
+        nested
+    )]
+    pub sections: Vec<SeatingSection>,
+}
+
+// =============================================================================================================================
+
+fn validate_seating_map(req: &SeatingMapRequest) -> Result<(), ValidationError> {
+    let rows = req.sections.iter().flat_map(|section| &section.rows);
+    if rows.clone().any(|row| {
+        row.accessible_seats
+            .iter()
+            .any(|seat| *seat == 0 || *seat > row.seats)
+    }) {
+        let mut err = ValidationError::new("accessible_seat_invalid");
+        err.message = Some("Accessible seats must exist in their row.".into());
+        return Err(err);
+    }
+    let capacity: u32 = rows.map(|row| row.seats).sum();
+    if !(25..=100_000).contains(&capacity) {
+        let mut err = ValidationError::new("capacity_invalid");
+        err.message = Some("A seating map must have between 25 and 100000 seats.".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Venue {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c5", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[schema(example = "Accor Arena", value_type = String)]
+    pub name: String,
+
+    pub address: VenueAddress,
+
+    #[schema(example = 48.8386)]
+    pub latitude: f64,
+
+    #[schema(example = 2.3786)]
+    pub longitude: f64,
+
+    #[serde(default = "default_timezone")]
+    #[schema(example = "Europe/Paris", value_type = String)]
+    pub timezone: String,
+
+    pub seating_maps: Vec<SeatingMap>,
+
+    #[serde(rename = "creator_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub creator_id: ObjectId,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2023-06-01T12:00:00Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct VenueRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "Accor Arena", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "Name must be between 2 and 100 characters"
+    ))]
+    pub name: String,
+
+    #[validate(nested)]
+    pub address: VenueAddress,
+
+    #[schema(example = 48.8386)]
+    #[validate(range(min = -90.0, max = 90.0, message = "Latitude must be between -90 and 90."))]
+    pub latitude: f64,
+
+    #[schema(example = 2.3786)]
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "Longitude must be between -180 and 180."
+    ))]
+    pub longitude: f64,
+
+    #[serde(default = "default_timezone", deserialize_with = "trim")]
+    #[schema(example = "Europe/Paris", value_type = String)]
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: String,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSeat {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "66f6a7b8c9d0e1f2a3b4c5d6", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "event_id")]
+    #[schema(example = "63f7b1c0a1234567890abcde", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[schema(example = 137)]
+    pub seat_number: u32,
+
+    #[schema(example = "Orchestra", value_type = String)]
+    pub section: String,
+
+    #[schema(example = "F", value_type = String)]
+    pub row: String,
+
+    #[schema(example = 12)]
+    pub seat: u32,
+
+    #[schema(example = false)]
+    pub accessible: bool,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSeries {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "6701a2b3c4d5e6f7a8b9c0d1", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[schema(example = "World Tour 2025", value_type = String)]
+    pub title: String,
+
+    #[schema(example = "The band plays its new album across Europe.", value_type = String)]
+    pub description: String,
+
+    #[serde(default)]
+    #[schema(example = "concert", value_type = Option<String>)]
+    pub category: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = json!(["rock"]))]
+    pub tags: Vec<String>,
+
+    #[schema(example = "Central Park", value_type = String)]
+    pub location: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geo_location: Option<GeoPoint>,
+
+    #[serde(default)]
+    #[schema(example = "America/New_York", value_type = Option<String>)]
+    pub timezone: Option<String>,
+
+    #[schema(example = 500)]
+    pub capacity: u32,
+
+    #[schema(example = 75)]
+    pub price: u32,
+
+    #[serde(default)]
+    #[schema(example = "64a1c2d3e4f5a6b7c8d9e0f1", value_type = String)]
+    pub organisation_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c5", value_type = String)]
+    pub venue_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c6", value_type = String)]
+    pub seating_map_id: Option<ObjectId>,
+
+    #[serde(rename = "creator_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub creator_id: ObjectId,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-01T12:00:00Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_series_date", skip_on_field_errors = false))]
+pub struct SeriesDateRequest {
+    #[serde(deserialize_with = "deserialize_datetime_from_any")]
+    #[schema(example = "2025-09-12T20:00:00Z", value_type = String)]
+    #[validate(custom(function = "validate_date_not_in_past"))]
+    pub date: DateTime,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "World Tour 2025 - Berlin", value_type = Option<String>)]
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "Title must be between 2 and 100 characters"
+    ))]
+    pub title: Option<String>,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "Mercedes-Benz Arena, Berlin", value_type = Option<String>)]
+    #[validate(length(
+        min = 2,
+        max = 500,
+        message = "Location must be between 2 and 75 characters"
+    ))]
+    pub location: Option<String>,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "Berlin", value_type = Option<String>)]
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "City must be between 2 and 100 characters"
+    ))]
+    pub city: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = 52.5079)]
+    #[validate(range(min = -90.0, max = 90.0, message = "Latitude must be between -90 and 90."))]
+    pub latitude: Option<f64>,
+
+    #[serde(default)]
+    #[schema(example = 13.4434)]
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "Longitude must be between -180 and 180."
+    ))]
+    pub longitude: Option<f64>,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "Europe/Berlin", value_type = Option<String>)]
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c5", value_type = Option<String>)]
+    pub venue_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c6", value_type = Option<String>)]
+    pub seating_map_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = 800)]
+    #[validate(range(min = 25, message = "Capacity must be at least 25"))]
+    pub capacity: Option<u32>,
+
+    #[serde(default)]
+    #[schema(example = 90)]
+    #[validate(range(min = 1, message = "Price must be at least one."))]
+    pub price: Option<u32>,
+}
+
+// =============================================================================================================================
+
+fn validate_series_date(req: &SeriesDateRequest) -> Result<(), ValidationError> {
+    validate_coordinates(req.latitude, req.longitude)
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RecurrenceRule {
+    #[serde(deserialize_with = "deserialize_datetime_from_any")]
+    #[schema(example = "2025-09-05T20:00:00Z", value_type = String)]
+    #[validate(custom(function = "validate_date_not_in_past"))]
+    pub starts_at: DateTime,
+
+    #[schema(example = "Weekly", value_type = String)]
+    pub frequency: RecurrenceFrequency,
+
+    #[serde(default = "default_recurrence_interval")]
+    #[schema(example = 1)]
+    #[validate(range(min = 1, max = 52, message = "Interval must be between 1 and 52."))]
+    pub interval: u32,
+
+    #[schema(example = 10)]
+    #[validate(range(min = 1, max = 100, message = "Count must be between 1 and 100."))]
+    pub count: u32,
+}
+
+fn default_recurrence_interval() -> u32 {
+    1
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_series_dates", skip_on_field_errors = false))]
+pub struct SeriesDatesRequest {
+    #[serde(default)]
+    #[validate(nested)]
+    pub dates: Vec<SeriesDateRequest>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub recurrence: Option<RecurrenceRule>,
+}
+
+// =============================================================================================================================
+
+fn validate_series_dates(req: &SeriesDatesRequest) -> Result<(), ValidationError> {
+    if req.dates.is_empty() == req.recurrence.is_none() {
+        let mut err = ValidationError::new("series_dates_invalid");
+        err.message = Some("Either a list of dates or a recurrence rule must be given.".into());
+        return Err(err);
+    }
+    if req.dates.len() > 100 {
+        let mut err = ValidationError::new("too_many_dates");
+        err.message = Some("At most 100 dates can be created at once.".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(
+    function = "validate_create_event_series",
+    skip_on_field_errors = false
+))]
+pub struct CreateEventSeriesRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "World Tour 2025", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 80,
+        message = "Title must be between 2 and 80 characters"
+    ))]
+    pub title: String,
+
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "The band plays its new album across Europe.", value_type = String)]
+    #[validate(length(
+        min = 10,
+        max = 500,
+        message = "Description must be between 10 and 500 characters"
+    ))]
+    pub description: String,
+
+    #[serde(default, deserialize_with = "trim_lowercase_option")]
+    #[schema(example = "concert", value_type = Option<String>)]
+    pub category: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = json!(["rock"]))]
+    #[validate(custom(function = "validate_tags"))]
+    pub tags: Vec<String>,
+
+    #[serde(default, deserialize_with = "trim")]
+    #[schema(example = "Central Park", value_type = String)]
+    #[validate(length(max = 500, message = "Location must be between 2 and 75 characters"))]
+    pub location: String,
+
+    #[serde(default)]
+    #[schema(example = 40.7812)]
+    #[validate(range(min = -90.0, max = 90.0, message = "Latitude must be between -90 and 90."))]
+    pub latitude: Option<f64>,
+
+    #[serde(default)]
+    #[schema(example = -73.9665)]
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "Longitude must be between -180 and 180."
+    ))]
+    pub longitude: Option<f64>,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "America/New_York", value_type = Option<String>)]
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = 500)]
+    pub capacity: u32,
+
+    #[schema(example = 75)]
+    #[validate(range(min = 1, message = "Price must be at least one."))]
+    pub price: u32,
+
+    #[serde(default)]
+    #[schema(example = "64a1c2d3e4f5a6b7c8d9e0f1", value_type = String)]
+    pub organisation_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c5", value_type = String)]
+    pub venue_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66e5f6a7b8c9d0e1f2a3b4c6", value_type = String)]
+    pub seating_map_id: Option<ObjectId>,
+
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub schedule: SeriesDatesRequest,
+}
+
+// =============================================================================================================================
+
+fn validate_create_event_series(req: &CreateEventSeriesRequest) -> Result<(), ValidationError> {
+    if req.seating_map_id.is_some() && req.venue_id.is_none() {
+        let mut err = ValidationError::new("venue_required");
+        err.message = Some("A seating map can only be used with its venue.".into());
+        return Err(err);
+    }
+    validate_coordinates(req.latitude, req.longitude)
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(
+    function = "validate_update_event_series",
+    skip_on_field_errors = false
+))]
+pub struct UpdateEventSeriesRequest {
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "World Tour 2025", value_type = Option<String>)]
+    #[validate(length(
+        min = 2,
+        max = 80,
+        message = "Title must be between 2 and 80 characters"
+    ))]
+    pub title: Option<String>,
+
+    #[serde(default, deserialize_with = "trim_option")]
+    #[schema(example = "The band plays its new album across Europe.", value_type = Option<String>)]
+    #[validate(length(
+        min = 10,
+        max = 500,
+        message = "Description must be between 10 and 500 characters"
+    ))]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = 80)]
+    #[validate(range(min = 1, message = "Price must be at least one."))]
+    pub price: Option<u32>,
+}
+
+// =============================================================================================================================
+
+fn validate_update_event_series(req: &UpdateEventSeriesRequest) -> Result<(), ValidationError> {
+    if req.title.is_none() && req.description.is_none() && req.price.is_none() {
+        let mut err = ValidationError::new("no_field_to_update");
+        err.message = Some("At least one field must be updated.".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSeriesDetails {
+    pub series: EventSeries,
+    pub events: Vec<Event>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeriesDateStatistics {
+    #[schema(example = "63f7b1c0a1234567890abcde", value_type = String)]
+    pub event_id: String,
+
+    #[schema(example = "World Tour 2025 - 2025-09-12", value_type = String)]
+    pub title: String,
+
+    #[serde(serialize_with = "serialize_bson_datetime_as_rfc3339_string")]
+    #[schema(example = "2025-09-12T20:00:00Z", value_type = String)]
+    pub date: DateTime,
+
+    #[schema(example = "Published", value_type = String)]
+    pub status: EventStatus,
+
+    #[schema(example = 500)]
+    pub capacity: u32,
+
+    #[schema(example = 420)]
+    pub sold: u32,
+
+    #[schema(example = 80)]
+    pub remaining: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeriesStatistics {
+    #[schema(example = "6701a2b3c4d5e6f7a8b9c0d1", value_type = String)]
+    pub series_id: String,
+
+    #[schema(example = 40)]
+    pub dates: u32,
+
+    #[schema(example = 20000)]
+    pub capacity: u32,
+
+    #[schema(example = 16800)]
+    pub sold: u32,
+
+    #[schema(example = 3200)]
+    pub remaining: u32,
+
+    #[schema(example = 84.0)]
+    pub sell_through: f64,
+
+    pub per_date: Vec<SeriesDateStatistics>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Category {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "6712a3b4c5d6e7f8a9b0c1d2", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[schema(example = "Metal", value_type = String)]
+    pub name: String,
+
+    #[schema(example = "metal", value_type = String)]
+    pub slug: String,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "6712a3b4c5d6e7f8a9b0c1d1", value_type = Option<String>)]
+    pub parent_id: Option<ObjectId>,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-01T12:00:00Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CategoryRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "Metal", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 50,
+        message = "Name must be between 2 and 50 characters"
+    ))]
+    pub name: String,
+
+    #[serde(deserialize_with = "trim_lowercase")]
+    #[schema(example = "metal", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 50,
+        message = "Slug must be between 2 and 50 characters"
+    ))]
+    #[validate(regex(
+        path = "*SLUG_REGEX",
+        message = "Slug must only contain lowercase letters, digits and dashes"
+    ))]
+    pub slug: String,
+
+    #[serde(default)]
+    #[schema(example = "6712a3b4c5d6e7f8a9b0c1d1", value_type = Option<String>)]
+    pub parent_id: Option<ObjectId>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateCategoryRequest {
+    #[serde(deserialize_with = "trim")]
+    #[schema(example = "Heavy Metal", value_type = String)]
+    #[validate(length(
+        min = 2,
+        max = 50,
+        message = "Name must be between 2 and 50 characters"
+    ))]
+    pub name: String,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TicketCounts {
+    #[schema(example = 12)]
+    pub pending: u32,
+
+    #[schema(example = 420)]
+    pub active: u32,
+
+    #[schema(example = 9)]
+    pub cancelled: u32,
+
+    #[schema(example = 4)]
+    pub refunded: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CurrencyAmount {
+    #[schema(example = "Eur", value_type = String)]
+    pub currency: PaymentCurrency,
+
+    #[schema(example = 125000)]
+    pub amount: u64,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DailyRevenue {
+    #[schema(example = "2025-03-23")]
+    pub day: String,
+
+    #[schema(example = "Eur", value_type = String)]
+    pub currency: PaymentCurrency,
+
+    #[schema(example = 4500)]
+    pub amount: u64,
+
+    #[schema(example = 3)]
+    pub payments: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TicketHoldersNotificationInternalResponse {
+    pub recipients: usize,
+
+    #[serde(default)]
+    pub failed: Option<usize>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventTicketStatisticsInternalResponse {
+    pub event_id: String,
+
+    #[serde(flatten)]
+    pub tickets: TicketCounts,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventPaymentStatisticsInternalResponse {
+    pub event_id: String,
+    pub revenue: Vec<CurrencyAmount>,
+    pub revenue_by_day: Vec<DailyRevenue>,
+    pub refunded: Vec<CurrencyAmount>,
+    pub refunds: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSalesStatistics {
+    #[schema(example = "63f7b1c0a1234567890abcde", value_type = String)]
+    pub event_id: String,
+
+    #[schema(example = "Summer Concert", value_type = String)]
+    pub title: String,
+
+    #[serde(serialize_with = "serialize_bson_datetime_as_rfc3339_string")]
+    #[schema(example = "2025-08-15T18:00:00Z", value_type = String)]
+    pub date: DateTime,
+
+    #[schema(example = "Published", value_type = String)]
+    pub status: EventStatus,
+
+    #[schema(example = 500)]
+    pub capacity: u32,
+
+    #[schema(example = 420)]
+    pub sold: u32,
+
+    #[schema(example = 84.0)]
+    pub sell_through: f64,
+
+    pub tickets: TicketCounts,
+
+    pub revenue: Vec<CurrencyAmount>,
+
+    pub revenue_by_day: Vec<DailyRevenue>,
+
+    pub refunded: Vec<CurrencyAmount>,
+
+    #[schema(example = 4)]
+    pub refunds: u32,
+
+    #[schema(example = 9)]
+    pub cancellations: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganisationSalesStatistics {
+    #[schema(example = "6701a2b3c4d5e6f7a8b9c0d1", value_type = String)]
+    pub organisation_id: String,
+
+    #[schema(example = 12)]
+    pub events: u32,
+
+    #[schema(example = 6000)]
+    pub capacity: u32,
+
+    #[schema(example = 5040)]
+    pub sold: u32,
+
+    #[schema(example = 84.0)]
+    pub sell_through: f64,
+
+    pub tickets: TicketCounts,
+
+    pub revenue: Vec<CurrencyAmount>,
+
+    pub revenue_by_day: Vec<DailyRevenue>,
+
+    pub refunded: Vec<CurrencyAmount>,
+
+    #[schema(example = 48)]
+    pub refunds: u32,
+
+    #[schema(example = 108)]
+    pub cancellations: u32,
+
+    pub per_event: Vec<EventSalesStatistics>,
+}
+
+// =============================================================================================================================
+
+// The progress of a reminder in the `event_reminders` collection, a claim left `Sending` is retried once stale.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ReminderStatus {
+    Sending,
+    Sent,
+    Failed,
+    Skipped,
+}
+
+// =============================================================================================================================
@@ -1,21 +1,402 @@
-use bson::{DateTime, doc, oid::ObjectId, to_document};
-use common::models::AuthRole;
+use bson::{Bson, DateTime, Document, doc, oid::ObjectId, to_bson, to_document};
+use common::{
+    jwt::internal::encode_internal_jwt,
+    models::{AuthRole, EventStatus, OrganisationRole, TriggerNotificationRequest},
+    utils::{
+        api_response::{ApiResponse, ConflictError},
+        ical::{CalendarEvent, build_calendar, calendar_geo},
+        utils::{
+            EVENT_MODERATION_ENABLED, EVENT_REMINDER_OFFSETS_HOURS, is_duplicate_key_error,
+            soft_delete_purge_cutoff, trigger_notification,
+        },
+    },
+};
 use futures_util::TryStreamExt;
 use mongodb::{Collection, Cursor, Database, options::ReturnDocument};
 use validator::Validate;
 
-use crate::model::{CreateEventRequest, Event, UpdateEventRequest};
+use crate::model::{
+    AccessCode, AccessCodeRequest, AddOrganisationMemberRequest, Category, CategoryRequest,
+    CreateEventRequest, CreateEventSeriesRequest, CurrencyAmount, DailyRevenue, Event,
+    EventChanges, EventFacets, EventPaymentStatisticsInternalResponse, EventReview,
+    EventSalesStatistics, EventSearchResponse, EventSearchResult, EventSeat, EventSeries,
+    EventSeriesDetails, EventSortField, EventTicketStatisticsInternalResponse, FacetCount,
+    GeoPoint, Organisation, OrganisationMember, OrganisationRequest, OrganisationSalesStatistics,
+    Pass, PassRequest, Presale, PresaleRequest, PurchaseLimits, RecurrenceFrequency,
+    RecurrenceRule, RejectEventRequest, ReminderStatus, ReviewAction, ReviewStatus,
+    SaleWindowRequest, SearchEventsQuery, SeatingMap, SeatingMapRequest, SeriesDateRequest,
+    SeriesDateStatistics, SeriesDatesRequest, SeriesStatistics, SortOrder, TicketCounts,
+    TicketHoldersNotificationInternalResponse, TicketType, TicketTypeRequest,
+    UpdateCategoryRequest, UpdateEventRequest, UpdateEventSeriesRequest,
+    UpdateOrganisationMemberRequest, UseAccessCodeRequest, Venue, VenueRequest, default_timezone,
+};
 
 // =============================================================================================================================
 
 const COLLECTION_NAME: &str = "events";
+const ORGANISATIONS_COLLECTION_NAME: &str = "organisations";
+const VENUES_COLLECTION_NAME: &str = "venues";
+const SEATS_COLLECTION_NAME: &str = "event_seats";
+const ACCESS_CODES_COLLECTION_NAME: &str = "access_codes";
+const SERIES_COLLECTION_NAME: &str = "event_series";
+const CATEGORIES_COLLECTION_NAME: &str = "categories";
+const REMINDERS_COLLECTION_NAME: &str = "event_reminders";
+const REMINDER_STALE_AFTER_MINUTES: i64 = 15;
+const PASSES_COLLECTION_NAME: &str = "passes";
+const STATISTICS_BATCH_SIZE: usize = 500;
 
 // =============================================================================================================================
 
 pub async fn get_events(db: &Database) -> Result<Vec<Event>, Box<dyn std::error::Error>> {
     let collection: Collection<Event> = db.collection(COLLECTION_NAME);
 
-    let cursor: Cursor<Event> = collection.find(doc! {}).await?;
+    let cursor: Cursor<Event> = collection.find(doc! { "deleted_at": null }).await?;
+    let events: Vec<Event> = cursor.try_collect().await?;
+
+    Ok(events)
+}
+
+// =============================================================================================================================
+
+pub async fn search_events(
+    db: &Database,
+    query: SearchEventsQuery,
+) -> Result<EventSearchResponse, Box<dyn std::error::Error>> {
+    query.validate()?;
+
+    let limit = query.limit.unwrap_or(20);
+    let order = query.order.unwrap_or(SortOrder::Asc);
+    let near = match (query.lat, query.lon) {
+        (Some(lat), Some(lon)) => Some(to_bson(&GeoPoint::new(lat, lon))?),
+        _ => None,
+    };
+    let default_sort = match near {
+        Some(_) => EventSortField::Distance,
+        None => EventSortField::Date,
+    };
+    let sort_field = match query.sort_by.unwrap_or(default_sort) {
+        EventSortField::Date => "date",
+        EventSortField::Price => "price",
+        EventSortField::CreatedAt => "created_at",
+        EventSortField::Title => "title",
+        EventSortField::Distance => "distance",
+    };
+    let (direction, comparison) = match order {
+        SortOrder::Asc => (1, "$gt"),
+        SortOrder::Desc => (-1, "$lt"),
+    };
+
+    let mut filter = doc! {};
+    let mut conditions: Vec<Document> = vec![
+        doc! { "deleted_at": null },
+        status_filter(&[EventStatus::Published]),
+    ];
+
+    if let Some(q) = query.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+        if near.is_some() {
+            return Err("A text search cannot be combined with a search around a location".into());
+        }
+        filter.insert("$text", doc! { "$search": q });
+    }
+
+    // Dates are stored as RFC 3339 strings, which sort chronologically.
+    let date_from = match query.date_from {
+        Some(date_from) => Some(date_from),
+        None if !query.include_past.unwrap_or(false) => Some(DateTime::now()),
+        None => None,
+    };
+    if let Some(date_from) = date_from {
+        conditions.push(doc! { "date": { "$gte": date_from.try_to_rfc3339_string()? } });
+    }
+    if let Some(date_to) = query.date_to {
+        conditions.push(doc! { "date": { "$lte": date_to.try_to_rfc3339_string()? } });
+    }
+
+    if let Some(location) = query
+        .location
+        .as_deref()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+    {
+        conditions.push(doc! {
+            "location": { "$regex": regex::escape(location), "$options": "i" }
+        });
+    }
+
+    if let Some(price_min) = query.price_min {
+        conditions.push(doc! { "price": { "$gte": price_min } });
+    }
+    if let Some(price_max) = query.price_max {
+        conditions.push(doc! { "price": { "$lte": price_max } });
+    }
+
+    if query.has_remaining_seats.unwrap_or(false) {
+        conditions.push(doc! { "remaining_seats": { "$gt": 0 } });
+    }
+
+    if let Some(series_id) = &query.series_id {
+        let series_id = ObjectId::parse_str(series_id).map_err(|_| "Invalid series id")?;
+        conditions.push(doc! { "series_id": series_id });
+    }
+
+    if let Some(category) = query
+        .category
+        .as_deref()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+    {
+        let slugs = category_slugs(db, &category.to_lowercase()).await?;
+        conditions.push(doc! { "category": { "$in": slugs } });
+    }
+    if let Some(tags) = query.tags.as_deref() {
+        let tags = normalize_tags(tags.split(',').map(str::to_string).collect());
+        if !tags.is_empty() {
+            conditions.push(doc! { "tags": { "$all": tags } });
+        }
+    }
+    if let Some(city) = query
+        .city
+        .as_deref()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+    {
+        conditions.push(doc! {
+            "city": { "$regex": format!("^{}$", regex::escape(city)), "$options": "i" }
+        });
+    }
+
+    // The facets describe every matching event, so they are only computed for the first page.
+    let radius = query.radius.unwrap_or(50.0) * 1000.0;
+    let facets = match query.cursor {
+        Some(_) => None,
+        None => {
+            let first_stage = match &near {
+                Some(near) => geo_near_stage(near, doc! { "$and": &conditions }, Some(radius)),
+                None => {
+                    let mut facet_filter = filter.clone();
+                    facet_filter.insert("$and", &conditions);
+                    doc! { "$match": facet_filter }
+                }
+            };
+            Some(search_facets(db, first_stage).await?)
+        }
+    };
+
+    let documents: Collection<Document> = db.collection(COLLECTION_NAME);
+    let cursor_filter = match &query.cursor {
+        Some(cursor) => {
+            let cursor_id = ObjectId::parse_str(cursor).map_err(|_| "Invalid cursor")?;
+            let cursor_document = match &near {
+                Some(near) => {
+                    documents
+                        .aggregate(vec![geo_near_stage(near, doc! { "_id": cursor_id }, None)])
+                        .await?
+                        .try_next()
+                        .await?
+                }
+                None => documents.find_one(doc! { "_id": cursor_id }).await?,
+            };
+            let cursor_value = cursor_document
+                .and_then(|document| document.get(sort_field).cloned())
+                .ok_or("Invalid cursor")?;
+
+            Some(doc! {
+                "$or": [
+                    { sort_field: { comparison: cursor_value.clone() } },
+                    { sort_field: cursor_value, "_id": { comparison: cursor_id } },
+                ]
+            })
+        }
+        None => None,
+    };
+
+    // The distance only exists once $geoNear computed it, so the cursor is matched after it.
+    let documents: Vec<Document> = match &near {
+        Some(near) => {
+            let mut pipeline = vec![geo_near_stage(
+                near,
+                doc! { "$and": conditions },
+                Some(radius),
+            )];
+            if let Some(cursor_filter) = cursor_filter {
+                pipeline.push(doc! { "$match": cursor_filter });
+            }
+            pipeline.push(doc! { "$sort": { sort_field: direction, "_id": direction } });
+            pipeline.push(doc! { "$limit": limit + 1 });
+
+            documents.aggregate(pipeline).await?.try_collect().await?
+        }
+        None => {
+            conditions.extend(cursor_filter);
+            filter.insert("$and", conditions);
+
+            documents
+                .find(filter)
+                .sort(doc! { sort_field: direction, "_id": direction })
+                .limit(limit + 1)
+                .await?
+                .try_collect()
+                .await?
+        }
+    };
+
+    let mut events = documents
+        .into_iter()
+        .map(|mut document| {
+            let distance_km = document
+                .remove("distance")
+                .and_then(|distance| distance.as_f64())
+                .map(|distance| (distance / 10.0).round() / 100.0);
+            let event: Event = bson::from_document(document)?;
+            let local_date = local_date(&event)?;
+            Ok(EventSearchResult {
+                event,
+                local_date,
+                distance_km,
+            })
+        })
+        .collect::<Result<Vec<EventSearchResult>, Box<dyn std::error::Error>>>()?;
+
+    let next_cursor = if events.len() as i64 > limit {
+        events.truncate(limit as usize);
+        events
+            .last()
+            .and_then(|result| result.event.id)
+            .map(|id| id.to_hex())
+    } else {
+        None
+    };
+
+    Ok(EventSearchResponse {
+        events,
+        next_cursor,
+        facets,
+    })
+}
+
+// =============================================================================================================================
+
+fn geo_near_stage(near: &Bson, query: Document, max_distance: Option<f64>) -> Document {
+    let mut geo_near = doc! {
+        "near": near.clone(),
+        "distanceField": "distance",
+        "spherical": true,
+        "query": query,
+    };
+    if let Some(max_distance) = max_distance {
+        geo_near.insert("maxDistance", max_distance);
+    }
+
+    doc! { "$geoNear": geo_near }
+}
+
+// =============================================================================================================================
+
+async fn search_facets(
+    db: &Database,
+    first_stage: Document,
+) -> Result<EventFacets, Box<dyn std::error::Error>> {
+    let count_by = |field: &str| {
+        vec![
+            doc! { "$match": { field: { "$ne": null } } },
+            doc! { "$group": { "_id": format!("${}", field), "count": { "$sum": 1 } } },
+            doc! { "$sort": { "count": -1, "_id": 1 } },
+            doc! { "$limit": 50 },
+        ]
+    };
+    // The months are those of the local dates, in the timezone of each event.
+    let months = vec![
+        doc! { "$group": {
+            "_id": { "$dateToString": {
+                "format": "%Y-%m",
+                "date": { "$dateFromString": { "dateString": "$date" } },
+                "timezone": { "$ifNull": ["$timezone", "UTC"] },
+            } },
+            "count": { "$sum": 1 },
+        } },
+        doc! { "$sort": { "_id": 1 } },
+    ];
+
+    let pipeline = vec![
+        first_stage,
+        doc! { "$facet": {
+            "categories": count_by("category"),
+            "cities": count_by("city"),
+            "months": months,
+        } },
+    ];
+
+    let collection: Collection<Document> = db.collection(COLLECTION_NAME);
+    let facets = collection
+        .aggregate(pipeline)
+        .await?
+        .try_next()
+        .await?
+        .ok_or("The facets could not be computed")?;
+
+    let counts = |name: &str| -> Result<Vec<FacetCount>, Box<dyn std::error::Error>> {
+        facets
+            .get_array(name)?
+            .iter()
+            .filter_map(Bson::as_document)
+            .map(|count| {
+                Ok(FacetCount {
+                    value: count.get_str("_id")?.to_string(),
+                    count: count.get_i32("count")? as u32,
+                })
+            })
+            .collect()
+    };
+
+    Ok(EventFacets {
+        categories: counts("categories")?,
+        cities: counts("cities")?,
+        months: counts("months")?,
+    })
+}
+
+// =============================================================================================================================
+
+fn local_date(event: &Event) -> Result<String, Box<dyn std::error::Error>> {
+    let timezone: chrono_tz::Tz = event.timezone.parse()?;
+
+    Ok(event.date.to_chrono().with_timezone(&timezone).to_rfc3339())
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_calendar(
+    db: &Database,
+    id: String,
+    viewer: Option<(String, AuthRole)>,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let event = get_visible_event_by_id(db, id, viewer).await?;
+
+    let calendar_event = CalendarEvent {
+        event_id: event.id.map(|id| id.to_hex()).unwrap_or_default(),
+        geo: event
+            .geo_location
+            .as_ref()
+            .map(|point| calendar_geo(point.coordinates)),
+        title: event.title.clone(),
+        description: event.description,
+        location: event.location,
+        start: event.date,
+        status: event.status,
+    };
+
+    Ok(build_calendar(&event.title, &[calendar_event]))
+}
+
+// =============================================================================================================================
+
+pub async fn get_deleted_events(db: &Database) -> Result<Vec<Event>, Box<dyn std::error::Error>> {
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    let cursor: Cursor<Event> = collection
+        .find(doc! { "deleted_at": { "$ne": null } })
+        .sort(doc! { "deleted_at": -1 })
+        .await?;
     let events: Vec<Event> = cursor.try_collect().await?;
 
     Ok(events)
@@ -30,7 +411,10 @@ pub async fn get_event_by_id(
     let id = ObjectId::parse_str(&id)?;
     let collection: Collection<Event> = db.collection(COLLECTION_NAME);
 
-    match collection.find_one(doc! { "_id": id }).await? {
+    match collection
+        .find_one(doc! { "_id": id, "deleted_at": null })
+        .await?
+    {
         Some(event) => Ok(event),
         None => Err("No event was found with this id".into()),
     }
@@ -38,26 +422,149 @@ pub async fn get_event_by_id(
 
 // =============================================================================================================================
 
+pub async fn get_visible_event_by_id(
+    db: &Database,
+    id: String,
+    viewer: Option<(String, AuthRole)>,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let event = get_event_by_id(db, id).await?;
+    if event.status.is_public() {
+        return Ok(event);
+    }
+
+    let can_view = match viewer {
+        Some((user_id, role)) => {
+            let user_id = ObjectId::parse_str(&user_id)?;
+            role == AuthRole::Admin || organiser_role(db, &event, user_id).await?.is_some()
+        }
+        None => false,
+    };
+
+    if can_view {
+        Ok(event)
+    } else {
+        Err("No event was found with this id".into())
+    }
+}
+
+// =============================================================================================================================
+
 pub async fn create_event(
     db: &Database,
     event: CreateEventRequest,
     user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    insert_event(db, event, user_id, role, None).await
+}
+
+// =============================================================================================================================
+
+async fn insert_event(
+    db: &Database,
+    event: CreateEventRequest,
+    user_id: String,
+    role: AuthRole,
+    series_id: Option<ObjectId>,
 ) -> Result<Event, Box<dyn std::error::Error>> {
     event.validate()?;
 
     let creator_id = ObjectId::parse_str(&user_id)?;
 
+    if let Some(organisation_id) = event.organisation_id {
+        let organisation_role = get_organisation_role(db, organisation_id, creator_id).await?;
+        let can_manage_events = organisation_role.is_some_and(|r| r.can_manage_events());
+        if role != AuthRole::Admin && !can_manage_events {
+            return Err(
+                "Only the owners and managers of the organisation can create its events".into(),
+            );
+        }
+    }
+
+    let venue = match event.venue_id {
+        Some(venue_id) => Some(find_venue(db, venue_id).await?),
+        None => None,
+    };
+    let seating_map = match (&venue, event.seating_map_id) {
+        (Some(venue), Some(seating_map_id)) => match venue
+            .seating_maps
+            .iter()
+            .find(|seating_map| seating_map.id == seating_map_id)
+        {
+            Some(seating_map) => Some(seating_map),
+            None => return Err("No seating map was found with this id for the venue".into()),
+        },
+        _ => None,
+    };
+    let location = match &venue {
+        Some(venue) if event.location.is_empty() => {
+            format!("{}, {}", venue.name, venue.address.city)
+        }
+        _ => event.location,
+    };
+    let capacity = seating_map.map_or(event.capacity, |seating_map| seating_map.capacity());
+    if let Some(category) = &event.category {
+        find_category_by_slug(db, category).await?;
+    }
+    let city = event
+        .city
+        .or_else(|| venue.as_ref().map(|venue| venue.address.city.clone()));
+    let geo_location = GeoPoint::from_coordinates(event.latitude, event.longitude).or_else(|| {
+        venue
+            .as_ref()
+            .map(|venue| GeoPoint::new(venue.latitude, venue.longitude))
+    });
+    let timezone = event
+        .timezone
+        .or_else(|| venue.as_ref().map(|venue| venue.timezone.clone()))
+        .unwrap_or_else(default_timezone);
+
+    // Under moderation, the events of organisers wait for a review before they can be published.
+    let (review_status, review_history) = if is_moderated(role) {
+        let review = EventReview {
+            action: ReviewAction::Submitted,
+            changes: None,
+            reason: None,
+            user_id: creator_id,
+            created_at: DateTime::now(),
+        };
+        (ReviewStatus::Pending, vec![review])
+    } else {
+        (ReviewStatus::Approved, vec![])
+    };
+
     let mut event = Event {
         id: None,
         title: event.title,
         description: event.description,
-        location: event.location,
-        capacity: event.capacity,
-        remaining_seats: event.capacity,
+        category: event.category,
+        tags: normalize_tags(event.tags),
+        location,
+        city,
+        geo_location,
+        timezone,
+        capacity,
+        remaining_seats: capacity,
         creator_id,
+        organisation_id: event.organisation_id,
+        venue_id: event.venue_id,
+        seating_map_id: event.seating_map_id,
+        series_id,
+        status: EventStatus::Draft,
+        publish_at: None,
+        review_status,
+        pending_changes: None,
+        review_history,
         created_at: DateTime::now(),
         price: event.price,
+        ticket_types: vec![],
+        sale_starts_at: event.sale_starts_at,
+        sale_ends_at: event.sale_ends_at,
+        purchase_limits: event.purchase_limits,
+        presales: vec![],
         date: event.date,
+        deleted_at: None,
+        deleted_by: None,
     };
 
     let collection: Collection<Event> = db.collection(COLLECTION_NAME);
@@ -65,75 +572,3575 @@ pub async fn create_event(
     let res = collection.insert_one(&event).await?;
     event.id = res.inserted_id.as_object_id();
 
+    if let (Some(event_id), Some(seating_map)) = (event.id, seating_map) {
+        let generated = generate_event_seats(db, event_id, seating_map).await;
+        if let Err(e) = generated {
+            collection.delete_one(doc! { "_id": event_id }).await?;
+            db.collection::<EventSeat>(SEATS_COLLECTION_NAME)
+                .delete_many(doc! { "event_id": event_id })
+                .await?;
+            return Err(format!("The seats of the event could not be generated: {}", e).into());
+        }
+    }
+
     Ok(event)
 }
 
 // =============================================================================================================================
 
+async fn generate_event_seats(
+    db: &Database,
+    event_id: ObjectId,
+    seating_map: &SeatingMap,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut seats = Vec::with_capacity(seating_map.capacity() as usize);
+    for section in &seating_map.sections {
+        for row in &section.rows {
+            for seat in 1..=row.seats {
+                seats.push(EventSeat {
+                    id: None,
+                    event_id,
+                    seat_number: seats.len() as u32 + 1,
+                    section: section.name.clone(),
+                    row: row.name.clone(),
+                    seat,
+                    accessible: row.accessible_seats.contains(&seat),
+                });
+            }
+        }
+    }
+
+    let collection: Collection<EventSeat> = db.collection(SEATS_COLLECTION_NAME);
+    collection.insert_many(seats).await?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_seats(
+    db: &Database,
+    id: String,
+    viewer: Option<(String, AuthRole)>,
+) -> Result<Vec<EventSeat>, Box<dyn std::error::Error>> {
+    let event = get_visible_event_by_id(db, id, viewer).await?;
+    if event.seating_map_id.is_none() {
+        return Err("This event has no seating map".into());
+    }
+
+    let collection: Collection<EventSeat> = db.collection(SEATS_COLLECTION_NAME);
+
+    let cursor: Cursor<EventSeat> = collection
+        .find(doc! { "event_id": event.id })
+        .sort(doc! { "seat_number": 1 })
+        .await?;
+    let seats: Vec<EventSeat> = cursor.try_collect().await?;
+
+    Ok(seats)
+}
+
+// =============================================================================================================================
+
 pub async fn update_event_by_id(
     db: &Database,
     event: UpdateEventRequest,
     id: String,
+    user_id: String,
+    role: AuthRole,
 ) -> Result<Event, Box<dyn std::error::Error>> {
     event.validate()?;
 
     let id = ObjectId::parse_str(&id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
     let collection: Collection<Event> = db.collection(COLLECTION_NAME);
-    let update_doc = to_document(&event)?;
 
-    match collection
-        .find_one_and_update(doc! { "_id": id}, doc! { "$set": update_doc})
-        .return_document(ReturnDocument::After)
+    let existing_event = match collection
+        .find_one(doc! { "_id": id, "deleted_at": null })
         .await?
     {
-        Some(event) => Ok(event),
-        None => Err("No event was found with this id".into()),
+        Some(existing_event) => existing_event,
+        None => return Err("No event was found with this id".into()),
+    };
+    if !can_manage_event(db, &existing_event, user_id, role).await? {
+        return Err("Only the creator of the event or the owners and managers of its organisation can update it".into());
+    }
+    if matches!(
+        existing_event.status,
+        EventStatus::Cancelled | EventStatus::Completed
+    ) {
+        return Err("Cancelled and completed events can no longer be updated".into());
+    }
+    if let (Some(date), Some(publish_at)) = (event.date, existing_event.publish_at) {
+        if publish_at > date {
+            return Err("The event cannot take place before it is published".into());
+        }
+    }
+    if let Some(date) = event.date {
+        let last_sale_end = existing_event
+            .presales
+            .iter()
+            .map(|presale| presale.ends_at)
+            .chain(existing_event.sale_ends_at)
+            .max();
+        if last_sale_end.is_some_and(|ends_at| ends_at > date) {
+            return Err("The event cannot take place before the end of its sales".into());
+        }
     }
-}
 
-// =============================================================================================================================
+    let changes = EventChanges {
+        title: event.title,
+        description: event.description,
+        category: event.category,
+        tags: event.tags.map(normalize_tags),
+        location: event.location,
+        city: event.city,
+    };
+    if let Some(category) = &changes.category {
+        find_category_by_slug(db, category).await?;
+    }
 
-pub async fn update_event_seats_by_id(
-    db: &Database,
-    event_id: String,
-    delta: i32,
-) -> Result<Event, Box<dyn std::error::Error>> {
-    let event_id = ObjectId::parse_str(&event_id)?;
-    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
-    let update_doc = doc! { "$inc": { "remaining_seats": delta } };
+    let mut set_doc = doc! {};
+    let mut update_doc = doc! {};
+    let changed_fields = to_document(&changes)?;
+    let moderated = is_moderated(role) && !changed_fields.is_empty();
+    if moderated && content_was_approved(&existing_event) {
+        // The approved content stays online until a moderator reviews the changes.
+        for (field, value) in changed_fields {
+            set_doc.insert(format!("pending_changes.{}", field), value);
+        }
+    } else {
+        set_doc.extend(changed_fields);
+    }
+    if moderated {
+        set_doc.insert("review_status", to_bson(&ReviewStatus::Pending)?);
+        let review = EventReview {
+            action: ReviewAction::Submitted,
+            changes: Some(changes),
+            reason: None,
+            user_id,
+            created_at: DateTime::now(),
+        };
+        update_doc.insert("$push", doc! { "review_history": to_bson(&review)? });
+    }
+    if let Some(geo_location) = GeoPoint::from_coordinates(event.latitude, event.longitude) {
+        set_doc.insert("geo_location", to_bson(&geo_location)?);
+    }
+    if let Some(timezone) = &event.timezone {
+        set_doc.insert("timezone", timezone);
+    }
+    if let Some(date) = event.date {
+        set_doc.insert("date", date.try_to_rfc3339_string()?);
+    }
+    if let Some(price) = event.price {
+        set_doc.insert("price", price);
+    }
 
-    match collection
-        .find_one_and_update(doc! { "_id": event_id }, update_doc)
+    // The counters are part of the filter, so the update fails if seats were sold since they were checked.
+    let mut filter = doc! {
+        "_id": id,
+        "deleted_at": null,
+        "status": to_bson(&existing_event.status)?,
+        "capacity": existing_event.capacity,
+    };
+    if moderated {
+        filter.extend(review_history_filter(&existing_event));
+    }
+    let mut added_seats = 0;
+    if let Some(capacity) = event.capacity.filter(|c| *c != existing_event.capacity) {
+        check_new_capacity(&existing_event, capacity).await?;
+
+        // Tickets take their seat before reserving it on the event, so a seat taken since the check
+        // above changes the remaining seats, and a seat taken after the update is refused by the new capacity.
+        let delta = capacity as i64 - existing_event.capacity as i64;
+        if delta < 0 {
+            filter.insert("remaining_seats", existing_event.remaining_seats);
+        }
+        added_seats = capacity.saturating_sub(existing_event.capacity);
+        set_doc.insert("capacity", capacity);
+        update_doc.insert("$inc", doc! { "remaining_seats": delta });
+    }
+    update_doc.insert("$set", set_doc);
+
+    let updated_event = match collection
+        .find_one_and_update(filter, update_doc)
         .return_document(ReturnDocument::After)
         .await?
     {
-        Some(event) => Ok(event),
-        None => Err("No event found with the provided id.".into()),
+        Some(event) => event,
+        None => {
+            return Err(Box::new(ConflictError(
+                "The event changed while it was being updated, try again".to_string(),
+            )));
+        }
+    };
+
+    notify_event_changes(&existing_event, &updated_event).await;
+
+    // The added seats go to the users waiting for the event first, as the seats freed by a cancellation do.
+    if let (true, Some(event_id)) = (
+        added_seats > 0 && updated_event.status.is_on_sale(),
+        updated_event.id,
+    ) {
+        if let Err(e) = offer_waitlist_seats_request(event_id, added_seats).await {
+            eprintln!(
+                "Failed to offer the seats added to event {} to its waitlist: {}",
+                event_id.to_hex(),
+                e
+            );
+        }
     }
+
+    Ok(updated_event)
 }
 
 // =============================================================================================================================
 
-pub async fn delete_event_by_id(
+async fn check_new_capacity(
+    event: &Event,
+    capacity: u32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if event.seating_map_id.is_some() {
+        return Err("The capacity of an event is set by its seating map".into());
+    }
+    let ticket_types_quantity: u32 = event.ticket_types.iter().map(|t| t.quantity).sum();
+    if capacity < ticket_types_quantity {
+        return Err("The capacity cannot be lower than the quantity of its ticket types".into());
+    }
+    let sold_seats = event.capacity.saturating_sub(event.remaining_seats);
+    if capacity < sold_seats {
+        return Err(format!(
+            "The capacity cannot be lower than the {} seats already sold",
+            sold_seats
+        )
+        .into());
+    }
+
+    // Tickets can only have been sold once the event was public.
+    if let (true, Some(event_id)) = (event.status.is_public(), event.id) {
+        let highest_taken_seat = get_highest_taken_seat_request(event_id).await?;
+        if highest_taken_seat.is_some_and(|seat| seat > capacity) {
+            return Err(format!(
+                "The seat {} is already sold, the capacity cannot be lower",
+                highest_taken_seat.unwrap_or_default()
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+async fn notify_event_changes(previous: &Event, event: &Event) {
+    let (Some(event_id), true) = (event.id, event.status.is_public()) else {
+        return;
+    };
+    let title: String = event.title.chars().take(40).collect();
+
+    let mut messages = vec![];
+    if event.date != previous.date {
+        messages.push(format!(
+            "{} has been moved to {}.",
+            title,
+            event.date.to_chrono().format("%Y-%m-%d %H:%M UTC")
+        ));
+    }
+    if event.location != previous.location {
+        let location: String = event.location.chars().take(40).collect();
+        messages.push(format!("{} moves to {}.", title, location));
+    }
+
+    for message in messages {
+        if let Err(e) = notify_ticket_holders_request(event_id, &message, false, None).await {
+            eprintln!(
+                "Failed to notify the ticket holders of event {}: {}",
+                event_id.to_hex(),
+                e
+            );
+        }
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn change_event_status(
     db: &Database,
-    creator_id: String,
-    role: AuthRole,
     id: String,
+    user_id: String,
+    role: AuthRole,
+    status: EventStatus,
+    publish_at: Option<DateTime>,
+    date: Option<DateTime>,
 ) -> Result<Event, Box<dyn std::error::Error>> {
     let id = ObjectId::parse_str(&id)?;
-    let creator_id = ObjectId::parse_str(&creator_id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
     let collection: Collection<Event> = db.collection(COLLECTION_NAME);
-    let filter = match role {
-        AuthRole::Admin => {
-            doc! { "_id" : id }
+
+    let existing_event = match collection
+        .find_one(doc! { "_id": id, "deleted_at": null })
+        .await?
+    {
+        Some(existing_event) => existing_event,
+        None => return Err("No event was found with this id".into()),
+    };
+    if !can_manage_event(db, &existing_event, user_id, role).await? {
+        return Err("Only the creator of the event or the owners and managers of its organisation can change its status".into());
+    }
+    if !existing_event.status.can_transition_to(status) {
+        return Err(format!(
+            "An event cannot go from {:?} to {:?}",
+            existing_event.status, status
+        )
+        .into());
+    }
+
+    if matches!(status, EventStatus::Published | EventStatus::Scheduled)
+        && !content_was_approved(&existing_event)
+    {
+        return Err("The event must be approved by a moderator before it is published".into());
+    }
+
+    let event_date = date.unwrap_or(existing_event.date);
+    match status {
+        EventStatus::Published | EventStatus::Scheduled if event_date < DateTime::now() => {
+            return Err("The event date is in the past, it must be postponed to a new date".into());
         }
-        _ => doc! { "_id": id, "creator_id": creator_id},
+        EventStatus::Scheduled if publish_at.is_some_and(|publish_at| publish_at > event_date) => {
+            return Err("The event must be published before it takes place".into());
+        }
+        EventStatus::Completed if event_date > DateTime::now() => {
+            return Err("An event cannot be completed before it takes place".into());
+        }
+        _ => {}
+    }
+
+    let mut set_doc = doc! {
+        "status": to_bson(&status)?,
+        "publish_at": match publish_at {
+            Some(publish_at) => to_bson(&publish_at.try_to_rfc3339_string()?)?,
+            None => Bson::Null,
+        },
     };
+    if let Some(date) = date {
+        set_doc.insert("date", date.try_to_rfc3339_string()?);
+    }
 
-    match collection.find_one_and_delete(filter).await? {
+    // Filtering on the current status keeps concurrent transitions from overwriting each other.
+    let filter = doc! {
+        "$and": [
+            { "_id": id, "deleted_at": null },
+            status_filter(&[existing_event.status]),
+        ]
+    };
+
+    match collection
+        .find_one_and_update(filter, doc! { "$set": set_doc })
+        .return_document(ReturnDocument::After)
+        .await?
+    {
         Some(event) => Ok(event),
-        None => Err("No event was found with this id or the user who's trying to delete the event is not the creator".into()),
+        None => Err("The event status was changed concurrently, please retry".into()),
     }
 }
 
 // =============================================================================================================================
+
+pub async fn cancel_event(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let event =
+        change_event_status(db, id, user_id, role, EventStatus::Cancelled, None, None).await?;
+    let event_id = event.id.ok_or("The event has no id")?;
+
+    if let Err(e) = start_event_cancellation_request(event_id).await {
+        return Err(format!(
+            "The event was cancelled but the refund of its tickets could not be started, it can be retried from the Tickets Service: {}",
+            e
+        )
+        .into());
+    }
+
+    Ok(event)
+}
+
+// =============================================================================================================================
+
+pub async fn get_moderation_queue(db: &Database) -> Result<Vec<Event>, Box<dyn std::error::Error>> {
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    let mut filter = doc! { "deleted_at": null };
+    filter.extend(review_status_filter(&[ReviewStatus::Pending]));
+    let cursor: Cursor<Event> = collection.find(filter).sort(doc! { "_id": 1 }).await?;
+    let events: Vec<Event> = cursor.try_collect().await?;
+
+    Ok(events)
+}
+
+// =============================================================================================================================
+
+pub async fn approve_event(
+    db: &Database,
+    id: String,
+    reviewer_id: String,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let reviewer_id = ObjectId::parse_str(&reviewer_id)?;
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    let existing_event = match collection
+        .find_one(doc! { "_id": id, "deleted_at": null })
+        .await?
+    {
+        Some(existing_event) => existing_event,
+        None => return Err("No event was found with this id".into()),
+    };
+    if existing_event.review_status == ReviewStatus::Approved {
+        return Err("The event has no content awaiting a review".into());
+    }
+
+    let mut set_doc = match &existing_event.pending_changes {
+        Some(changes) => to_document(changes)?,
+        None => doc! {},
+    };
+    set_doc.insert("review_status", to_bson(&ReviewStatus::Approved)?);
+    let review = EventReview {
+        action: ReviewAction::Approved,
+        changes: existing_event.pending_changes.clone(),
+        reason: None,
+        user_id: reviewer_id,
+        created_at: DateTime::now(),
+    };
+
+    let mut filter = doc! {
+        "_id": id,
+        "deleted_at": null,
+        "review_status": to_bson(&existing_event.review_status)?,
+    };
+    filter.extend(review_history_filter(&existing_event));
+    let updated_event = match collection
+        .find_one_and_update(
+            filter,
+            doc! {
+                "$set": set_doc,
+                "$unset": { "pending_changes": "" },
+                "$push": { "review_history": to_bson(&review)? },
+            },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => event,
+        None => {
+            return Err(Box::new(ConflictError(
+                "The event changed while it was being reviewed, try again".to_string(),
+            )));
+        }
+    };
+
+    notify_event_changes(&existing_event, &updated_event).await;
+
+    let title: String = updated_event.title.chars().take(40).collect();
+    let message = match existing_event.pending_changes {
+        Some(_) => format!("The changes to {} were approved.", title),
+        None => format!("{} was approved and can now be published.", title),
+    };
+    notify_event_creator(&updated_event, message).await;
+
+    Ok(updated_event)
+}
+
+// =============================================================================================================================
+
+pub async fn reject_event(
+    db: &Database,
+    id: String,
+    reviewer_id: String,
+    rejection: RejectEventRequest,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    rejection.validate()?;
+
+    let id = ObjectId::parse_str(&id)?;
+    let reviewer_id = ObjectId::parse_str(&reviewer_id)?;
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    let existing_event = match collection
+        .find_one(doc! { "_id": id, "deleted_at": null })
+        .await?
+    {
+        Some(existing_event) => existing_event,
+        None => return Err("No event was found with this id".into()),
+    };
+    if existing_event.review_status != ReviewStatus::Pending {
+        return Err("The event has no content awaiting a review".into());
+    }
+
+    // Rejected changes are discarded, the approved content of the event stays online.
+    let review_status = match existing_event.pending_changes {
+        Some(_) => ReviewStatus::Approved,
+        None => ReviewStatus::Rejected,
+    };
+    let review = EventReview {
+        action: ReviewAction::Rejected,
+        changes: existing_event.pending_changes.clone(),
+        reason: Some(rejection.reason.clone()),
+        user_id: reviewer_id,
+        created_at: DateTime::now(),
+    };
+
+    let mut filter = doc! {
+        "_id": id,
+        "deleted_at": null,
+        "review_status": to_bson(&ReviewStatus::Pending)?,
+    };
+    filter.extend(review_history_filter(&existing_event));
+    let updated_event = match collection
+        .find_one_and_update(
+            filter,
+            doc! {
+                "$set": { "review_status": to_bson(&review_status)? },
+                "$unset": { "pending_changes": "" },
+                "$push": { "review_history": to_bson(&review)? },
+            },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => event,
+        None => {
+            return Err(Box::new(ConflictError(
+                "The event changed while it was being reviewed, try again".to_string(),
+            )));
+        }
+    };
+
+    let title: String = updated_event.title.chars().take(30).collect();
+    let message = match existing_event.pending_changes {
+        Some(_) => format!(
+            "The changes to {} were rejected: {}",
+            title, rejection.reason
+        ),
+        None => format!("{} was rejected: {}", title, rejection.reason),
+    };
+    notify_event_creator(&updated_event, message.chars().take(100).collect()).await;
+
+    Ok(updated_event)
+}
+
+// =============================================================================================================================
+
+// Editing the content of a rejected event submits it again, this submits it as it is, for instance once the
+// organisers have answered the reason of the rejection elsewhere.
+pub async fn resubmit_event(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    let existing_event = match collection
+        .find_one(doc! { "_id": id, "deleted_at": null })
+        .await?
+    {
+        Some(existing_event) => existing_event,
+        None => return Err("No event was found with this id".into()),
+    };
+    if !can_manage_event(db, &existing_event, user_id, role).await? {
+        return Err("Only the creator of the event or the owners and managers of its organisation can submit it".into());
+    }
+    if existing_event.review_status != ReviewStatus::Rejected {
+        return Err("Only a rejected event can be submitted again".into());
+    }
+
+    let review = EventReview {
+        action: ReviewAction::Submitted,
+        changes: None,
+        reason: None,
+        user_id,
+        created_at: DateTime::now(),
+    };
+
+    let mut filter = doc! {
+        "_id": id,
+        "deleted_at": null,
+        "review_status": to_bson(&ReviewStatus::Rejected)?,
+    };
+    filter.extend(review_history_filter(&existing_event));
+    match collection
+        .find_one_and_update(
+            filter,
+            doc! {
+                "$set": { "review_status": to_bson(&ReviewStatus::Pending)? },
+                "$push": { "review_history": to_bson(&review)? },
+            },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err(Box::new(ConflictError(
+            "The event changed while it was being submitted, try again".to_string(),
+        ))),
+    }
+}
+
+// =============================================================================================================================
+
+async fn notify_event_creator(event: &Event, message: String) {
+    let notification_data = TriggerNotificationRequest {
+        message,
+        user_id: event.creator_id,
+    };
+    if let Err(e) = trigger_notification(notification_data).await {
+        eprintln!(
+            "Failed to notify the creator of event {}: {}",
+            event.id.map(|id| id.to_hex()).unwrap_or_default(),
+            e
+        );
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn publish_scheduled_events(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    let filter = doc! {
+        "status": to_bson(&EventStatus::Scheduled)?,
+        "publish_at": { "$lte": DateTime::now().try_to_rfc3339_string()? },
+        "deleted_at": null,
+    };
+    let update_doc = doc! {
+        "$set": { "status": to_bson(&EventStatus::Published)?, "publish_at": null }
+    };
+
+    collection.update_many(filter, update_doc).await?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn complete_past_events(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    let filter = doc! {
+        "$and": [
+            {
+                "date": { "$lt": DateTime::now().try_to_rfc3339_string()? },
+                "deleted_at": null,
+            },
+            status_filter(&[EventStatus::Published]),
+        ]
+    };
+    let update_doc = doc! { "$set": { "status": to_bson(&EventStatus::Completed)? } };
+
+    collection.update_many(filter, update_doc).await?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn send_event_reminders(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(&furthest_offset) = EVENT_REMINDER_OFFSETS_HOURS.last() else {
+        return Ok(());
+    };
+
+    let now = DateTime::now();
+    let horizon = DateTime::from_millis(now.timestamp_millis() + furthest_offset * 60 * 60 * 1000);
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    let filter = doc! {
+        "$and": [
+            {
+                "date": {
+                    "$gt": now.try_to_rfc3339_string()?,
+                    "$lte": horizon.try_to_rfc3339_string()?
+                },
+                "deleted_at": null,
+            },
+            status_filter(&[EventStatus::Published]),
+        ]
+    };
+    let events: Vec<Event> = collection.find(filter).await?.try_collect().await?;
+
+    let reminders: Collection<Document> = db.collection(REMINDERS_COLLECTION_NAME);
+    for event in events {
+        let Some(event_id) = event.id else {
+            continue;
+        };
+        let time_left = event.date.timestamp_millis() - now.timestamp_millis();
+        let due_offsets: Vec<i64> = EVENT_REMINDER_OFFSETS_HOURS
+            .iter()
+            .copied()
+            .filter(|hours| time_left <= hours * 60 * 60 * 1000)
+            .collect();
+
+        // Only the closest due offset is sent, the earlier ones are recorded as skipped: an event created or moved
+        // late does not get them all at once. The event date is part of the key, so the reminders are sent again
+        // when the event is moved.
+        let event_date = event.date.try_to_rfc3339_string()?;
+        for (index, hours) in due_offsets.iter().enumerate() {
+            let key =
+                doc! { "event_id": event_id, "offset_hours": hours, "event_date": &event_date };
+            if index > 0 {
+                reminders
+                    .update_one(
+                        key,
+                        doc! { "$setOnInsert": { "status": to_bson(&ReminderStatus::Skipped)? } },
+                    )
+                    .upsert(true)
+                    .await?;
+                continue;
+            }
+
+            if !claim_reminder(&reminders, key.clone()).await? {
+                continue;
+            }
+
+            // The reminder is sent to the holders before the response, and sending it again only reaches the ones
+            // it missed, so it is only marked as sent once every holder was notified.
+            let message = reminder_message(&event, *hours)?;
+            let delivery_key = format!("reminder:{}:{}:{}", event_id.to_hex(), hours, event_date);
+            let failure =
+                match notify_ticket_holders_request(event_id, &message, true, Some(&delivery_key))
+                    .await
+                {
+                    Ok(notification) => match notification.failed {
+                        Some(0) => None,
+                        failed => Some(format!(
+                            "{} of the {} notifications failed",
+                            failed.unwrap_or(notification.recipients),
+                            notification.recipients
+                        )),
+                    },
+                    Err(e) => Some(e.to_string()),
+                };
+            let outcome = match failure {
+                None => doc! {
+                    "status": to_bson(&ReminderStatus::Sent)?,
+                    "sent_at": DateTime::now().try_to_rfc3339_string()?
+                },
+                Some(error) => {
+                    eprintln!(
+                        "Failed to remind the ticket holders of event {}: {}",
+                        event_id.to_hex(),
+                        error
+                    );
+                    doc! {
+                        "status": to_bson(&ReminderStatus::Failed)?,
+                        "failed_at": DateTime::now().try_to_rfc3339_string()?
+                    }
+                }
+            };
+            reminders.update_one(key, doc! { "$set": outcome }).await?;
+        }
+    }
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+// A reminder is claimed before it is sent, so a restart never sends it twice. The claim is a conditional upsert:
+// a new reminder is inserted, a failed or stale one is taken back, and any other one makes the insert collide
+// with it. Reminders recorded before their status was kept have been sent.
+async fn claim_reminder(
+    reminders: &Collection<Document>,
+    key: Document,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let now = DateTime::now();
+    let stale_before =
+        DateTime::from_millis(now.timestamp_millis() - REMINDER_STALE_AFTER_MINUTES * 60 * 1000);
+
+    let mut filter = key;
+    filter.insert(
+        "$or",
+        vec![
+            doc! { "status": to_bson(&ReminderStatus::Failed)? },
+            doc! {
+                "status": to_bson(&ReminderStatus::Sending)?,
+                "claimed_at": { "$lt": stale_before.try_to_rfc3339_string()? }
+            },
+        ],
+    );
+    let update = doc! {
+        "$set": {
+            "status": to_bson(&ReminderStatus::Sending)?,
+            "claimed_at": now.try_to_rfc3339_string()?
+        }
+    };
+
+    match reminders.update_one(filter, update).upsert(true).await {
+        Ok(res) => Ok(res.upserted_id.is_some() || res.modified_count == 1),
+        Err(e) if is_duplicate_key_error(&e) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// =============================================================================================================================
+
+fn reminder_message(event: &Event, hours: i64) -> Result<String, Box<dyn std::error::Error>> {
+    let title: String = event.title.chars().take(40).collect();
+    let timezone: chrono_tz::Tz = event.timezone.parse()?;
+    let date = event.date.to_chrono().with_timezone(&timezone);
+
+    let delay = match (hours % 24, hours / 24) {
+        (0, 1) => "tomorrow".to_string(),
+        (0, days) => format!("in {} days", days),
+        (_, _) if hours == 1 => "in 1 hour".to_string(),
+        (_, _) => format!("in {} hours", hours),
+    };
+
+    Ok(format!(
+        "Reminder: {} starts {}, on {}.",
+        title,
+        delay,
+        date.format("%Y-%m-%d %H:%M %Z")
+    ))
+}
+
+// =============================================================================================================================
+
+// Events created before the lifecycle was introduced have no status and are considered published.
+fn status_filter(statuses: &[EventStatus]) -> Document {
+    let mut values: Vec<Bson> = statuses
+        .iter()
+        .filter_map(|status| to_bson(status).ok())
+        .collect();
+    if statuses.contains(&EventStatus::Published) {
+        values.push(Bson::Null);
+    }
+
+    doc! { "status": { "$in": values } }
+}
+
+// =============================================================================================================================
+
+fn review_status_filter(statuses: &[ReviewStatus]) -> Document {
+    let mut values: Vec<Bson> = statuses
+        .iter()
+        .filter_map(|status| to_bson(status).ok())
+        .collect();
+    if statuses.contains(&ReviewStatus::Approved) {
+        values.push(Bson::Null);
+    }
+
+    doc! { "review_status": { "$in": values } }
+}
+
+// =============================================================================================================================
+
+// The review history only grows, so its length tells whether the event was reviewed or edited in the meantime.
+fn review_history_filter(event: &Event) -> Document {
+    let reviews_count = event.review_history.len();
+    let mut filter = doc! {
+        format!("review_history.{}", reviews_count): { "$exists": false },
+    };
+    if reviews_count > 0 {
+        filter.insert(
+            format!("review_history.{}", reviews_count - 1),
+            doc! { "$exists": true },
+        );
+    }
+    filter
+}
+
+// =============================================================================================================================
+
+fn is_moderated(role: AuthRole) -> bool {
+    *EVENT_MODERATION_ENABLED && role != AuthRole::Admin
+}
+
+// =============================================================================================================================
+
+// Whether the event has content that a moderator approved, even if newer changes await a review.
+fn content_was_approved(event: &Event) -> bool {
+    event.review_status == ReviewStatus::Approved || event.pending_changes.is_some()
+}
+
+// =============================================================================================================================
+
+pub async fn update_event_seats_by_id(
+    db: &Database,
+    event_id: String,
+    delta: i32,
+    ticket_type_id: Option<ObjectId>,
+    seat_number: Option<u32>,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    // The bounds are part of the filter, so concurrent updates can never take more seats than remain
+    // or release more than the capacity.
+    let mut filter = doc! { "_id": event_id, "deleted_at": null };
+    let mut bounds = vec![];
+    if delta < 0 {
+        filter.insert("remaining_seats", doc! { "$gte": -delta });
+    } else {
+        bounds.push(doc! {
+            "$lte": [{ "$add": ["$remaining_seats", delta] }, "$capacity"]
+        });
+    }
+    if let Some(seat_number) = seat_number {
+        filter.insert("capacity", doc! { "$gte": seat_number });
+    }
+
+    let mut update_doc = doc! { "$inc": { "remaining_seats": delta } };
+    if let Some(ticket_type_id) = ticket_type_id {
+        if delta < 0 {
+            filter.insert(
+                "ticket_types",
+                doc! { "$elemMatch": { "id": ticket_type_id, "remaining": { "$gte": -delta } } },
+            );
+        } else {
+            filter.insert("ticket_types.id", ticket_type_id);
+            bounds.push(doc! { "$anyElementTrue": [{ "$map": {
+                "input": "$ticket_types",
+                "as": "type",
+                "in": { "$and": [
+                    { "$eq": ["$$type.id", ticket_type_id] },
+                    { "$lte": [{ "$add": ["$$type.remaining", delta] }, "$$type.quantity"] },
+                ] },
+            } }] });
+        }
+        update_doc = doc! {
+            "$inc": { "remaining_seats": delta, "ticket_types.$[type].remaining": delta }
+        };
+    }
+    if !bounds.is_empty() {
+        filter.insert("$expr", doc! { "$and": bounds });
+    }
+
+    let mut action = collection
+        .find_one_and_update(filter, update_doc)
+        .return_document(ReturnDocument::After);
+    if let Some(ticket_type_id) = ticket_type_id {
+        action = action.array_filters(vec![doc! { "type.id": ticket_type_id }]);
+    }
+
+    if let Some(event) = action.await? {
+        return Ok(event);
+    }
+
+    let mut existing_filter = doc! { "_id": event_id, "deleted_at": null };
+    if let Some(ticket_type_id) = ticket_type_id {
+        existing_filter.insert("ticket_types.id", ticket_type_id);
+    }
+    let existing_event = match collection.find_one(existing_filter).await? {
+        Some(existing_event) => existing_event,
+        None => return Err("No event found with the provided id.".into()),
+    };
+    if seat_number.is_some_and(|seat| seat > existing_event.capacity) {
+        return Err(Box::new(ConflictError(
+            "This seat doesn't exist anymore.".to_string(),
+        )));
+    }
+
+    if delta < 0 {
+        Err(Box::new(ConflictError(
+            "Not enough seats remain for this event.".to_string(),
+        )))
+    } else {
+        Err(Box::new(ConflictError(
+            "The released seats would exceed the capacity of this event.".to_string(),
+        )))
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn add_ticket_type(
+    db: &Database,
+    event_id: String,
+    ticket_type: TicketTypeRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    ticket_type.validate()?;
+
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let existing_event = find_event_to_manage(db, event_id, user_id, role).await?;
+    validate_ticket_type_for_event(&existing_event, &ticket_type, None)?;
+
+    let ticket_type = TicketType {
+        id: ObjectId::new(),
+        name: ticket_type.name,
+        price: ticket_type.price,
+        currency: ticket_type.currency,
+        quantity: ticket_type.quantity,
+        remaining: ticket_type.quantity,
+        sale_starts_at: ticket_type.sale_starts_at,
+        sale_ends_at: ticket_type.sale_ends_at,
+        seat_from: ticket_type.seat_from,
+        seat_to: ticket_type.seat_to,
+        purchase_limits: ticket_type.purchase_limits,
+    };
+
+    // The ticket types must not have changed since the capacity was checked.
+    let ticket_types_count = existing_event.ticket_types.len();
+    let mut filter = doc! {
+        "_id": event_id,
+        "deleted_at": null,
+        format!("ticket_types.{}", ticket_types_count): { "$exists": false },
+    };
+    if ticket_types_count > 0 {
+        filter.insert(
+            format!("ticket_types.{}", ticket_types_count - 1),
+            doc! { "$exists": true },
+        );
+    }
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    match collection
+        .find_one_and_update(
+            filter,
+            doc! { "$push": { "ticket_types": to_bson(&ticket_type)? } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err("The ticket types of the event were changed concurrently, please retry".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn update_ticket_type(
+    db: &Database,
+    event_id: String,
+    ticket_type_id: String,
+    ticket_type: TicketTypeRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    ticket_type.validate()?;
+
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let ticket_type_id = ObjectId::parse_str(&ticket_type_id)?;
+    let existing_event = find_event_to_manage(db, event_id, user_id, role).await?;
+
+    let existing_ticket_type = existing_event
+        .ticket_types
+        .iter()
+        .find(|t| t.id == ticket_type_id)
+        .ok_or("No ticket type was found with this id")?;
+    let sold = existing_ticket_type.quantity - existing_ticket_type.remaining;
+    if ticket_type.quantity < sold {
+        return Err(format!(
+            "The quantity cannot be lower than the {} tickets already sold",
+            sold
+        )
+        .into());
+    }
+    validate_ticket_type_for_event(&existing_event, &ticket_type, Some(ticket_type_id))?;
+
+    let updated_ticket_type = TicketType {
+        id: ticket_type_id,
+        name: ticket_type.name,
+        price: ticket_type.price,
+        currency: ticket_type.currency,
+        quantity: ticket_type.quantity,
+        remaining: ticket_type.quantity - sold,
+        sale_starts_at: ticket_type.sale_starts_at,
+        sale_ends_at: ticket_type.sale_ends_at,
+        seat_from: ticket_type.seat_from,
+        seat_to: ticket_type.seat_to,
+        purchase_limits: ticket_type.purchase_limits,
+    };
+
+    // Matching on the remaining tickets makes sure no ticket was sold in the meantime.
+    let filter = doc! {
+        "_id": event_id,
+        "deleted_at": null,
+        "ticket_types": {
+            "$elemMatch": { "id": ticket_type_id, "remaining": existing_ticket_type.remaining }
+        },
+    };
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    match collection
+        .find_one_and_update(
+            filter,
+            doc! { "$set": { "ticket_types.$[type]": to_bson(&updated_ticket_type)? } },
+        )
+        .array_filters(vec![doc! { "type.id": ticket_type_id }])
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err("The ticket type was changed concurrently, please retry".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn delete_ticket_type(
+    db: &Database,
+    event_id: String,
+    ticket_type_id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let ticket_type_id = ObjectId::parse_str(&ticket_type_id)?;
+    let existing_event = find_event_to_manage(db, event_id, user_id, role).await?;
+
+    let existing_ticket_type = existing_event
+        .ticket_types
+        .iter()
+        .find(|t| t.id == ticket_type_id)
+        .ok_or("No ticket type was found with this id")?;
+    if existing_ticket_type.remaining < existing_ticket_type.quantity {
+        return Err("A ticket type with sold tickets cannot be deleted".into());
+    }
+
+    let unsold_ticket_type = doc! {
+        "id": ticket_type_id,
+        "remaining": existing_ticket_type.quantity
+    };
+    let filter = doc! {
+        "_id": event_id,
+        "deleted_at": null,
+        "ticket_types": { "$elemMatch": unsold_ticket_type.clone() },
+    };
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    match collection
+        .find_one_and_update(
+            filter,
+            doc! { "$pull": { "ticket_types": unsold_ticket_type } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err("A ticket type with sold tickets cannot be deleted".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn update_sale_window(
+    db: &Database,
+    event_id: String,
+    sale_window: SaleWindowRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    sale_window.validate()?;
+
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let existing_event = find_event_to_manage(db, event_id, user_id, role).await?;
+    if sale_window
+        .sale_ends_at
+        .is_some_and(|ends_at| ends_at > existing_event.date)
+    {
+        return Err("The sale cannot end after the event".into());
+    }
+
+    let sale_starts_at = match sale_window.sale_starts_at {
+        Some(starts_at) => Bson::String(starts_at.try_to_rfc3339_string()?),
+        None => Bson::Null,
+    };
+    let sale_ends_at = match sale_window.sale_ends_at {
+        Some(ends_at) => Bson::String(ends_at.try_to_rfc3339_string()?),
+        None => Bson::Null,
+    };
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    match collection
+        .find_one_and_update(
+            doc! { "_id": event_id, "deleted_at": null },
+            doc! { "$set": { "sale_starts_at": sale_starts_at, "sale_ends_at": sale_ends_at } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err("No event was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn update_purchase_limits(
+    db: &Database,
+    event_id: String,
+    purchase_limits: PurchaseLimits,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    purchase_limits.validate()?;
+
+    let event_id = ObjectId::parse_str(&event_id)?;
+    find_event_to_manage(db, event_id, user_id, role).await?;
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    match collection
+        .find_one_and_update(
+            doc! { "_id": event_id, "deleted_at": null },
+            doc! { "$set": { "purchase_limits": to_bson(&purchase_limits)? } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err("No event was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn add_presale(
+    db: &Database,
+    event_id: String,
+    presale: PresaleRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    presale.validate()?;
+
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let existing_event = find_event_to_manage(db, event_id, user_id, role).await?;
+    if presale.ends_at > existing_event.date {
+        return Err("The presale cannot end after the event".into());
+    }
+
+    let presale = Presale {
+        id: ObjectId::new(),
+        name: presale.name,
+        starts_at: presale.starts_at,
+        ends_at: presale.ends_at,
+    };
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    match collection
+        .find_one_and_update(
+            doc! { "_id": event_id, "deleted_at": null },
+            doc! { "$push": { "presales": to_bson(&presale)? } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err("No event was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn update_presale(
+    db: &Database,
+    event_id: String,
+    presale_id: String,
+    presale: PresaleRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    presale.validate()?;
+
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let presale_id = ObjectId::parse_str(&presale_id)?;
+    let existing_event = find_event_to_manage(db, event_id, user_id, role).await?;
+    if presale.ends_at > existing_event.date {
+        return Err("The presale cannot end after the event".into());
+    }
+
+    let updated_presale = Presale {
+        id: presale_id,
+        name: presale.name,
+        starts_at: presale.starts_at,
+        ends_at: presale.ends_at,
+    };
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    match collection
+        .find_one_and_update(
+            doc! { "_id": event_id, "deleted_at": null, "presales.id": presale_id },
+            doc! { "$set": { "presales.$": to_bson(&updated_presale)? } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err("No presale was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn delete_presale(
+    db: &Database,
+    event_id: String,
+    presale_id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let presale_id = ObjectId::parse_str(&presale_id)?;
+    find_event_to_manage(db, event_id, user_id, role).await?;
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    let event = match collection
+        .find_one_and_update(
+            doc! { "_id": event_id, "deleted_at": null, "presales.id": presale_id },
+            doc! { "$pull": { "presales": { "id": presale_id } } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => event,
+        None => return Err("No presale was found with this id".into()),
+    };
+
+    db.collection::<AccessCode>(ACCESS_CODES_COLLECTION_NAME)
+        .delete_many(doc! { "event_id": event_id, "presale_id": presale_id })
+        .await?;
+
+    Ok(event)
+}
+
+// =============================================================================================================================
+
+pub async fn get_access_codes(
+    db: &Database,
+    event_id: String,
+    presale_id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Vec<AccessCode>, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let presale_id = ObjectId::parse_str(&presale_id)?;
+    find_event_to_manage(db, event_id, user_id, role).await?;
+
+    let collection: Collection<AccessCode> = db.collection(ACCESS_CODES_COLLECTION_NAME);
+    let cursor: Cursor<AccessCode> = collection
+        .find(doc! { "event_id": event_id, "presale_id": presale_id })
+        .sort(doc! { "code": 1 })
+        .await?;
+    let access_codes: Vec<AccessCode> = cursor.try_collect().await?;
+
+    Ok(access_codes)
+}
+
+// =============================================================================================================================
+
+pub async fn create_access_code(
+    db: &Database,
+    event_id: String,
+    presale_id: String,
+    access_code: AccessCodeRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<AccessCode, Box<dyn std::error::Error>> {
+    access_code.validate()?;
+
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let presale_id = ObjectId::parse_str(&presale_id)?;
+    let existing_event = find_event_to_manage(db, event_id, user_id, role).await?;
+    if !existing_event.presales.iter().any(|p| p.id == presale_id) {
+        return Err("No presale was found with this id".into());
+    }
+
+    // Codes are case insensitive, and unique per event whatever their presale.
+    let code = access_code.code.to_uppercase();
+    let collection: Collection<AccessCode> = db.collection(ACCESS_CODES_COLLECTION_NAME);
+    if collection
+        .find_one(doc! { "event_id": event_id, "code": &code })
+        .await?
+        .is_some()
+    {
+        return Err("This access code already exists for this event".into());
+    }
+
+    let mut access_code = AccessCode {
+        id: None,
+        event_id,
+        presale_id,
+        code,
+        max_uses: access_code.max_uses,
+        uses: 0,
+        created_at: DateTime::now(),
+    };
+
+    let res = collection.insert_one(&access_code).await?;
+    access_code.id = res.inserted_id.as_object_id();
+
+    Ok(access_code)
+}
+
+// =============================================================================================================================
+
+pub async fn delete_access_code(
+    db: &Database,
+    event_id: String,
+    presale_id: String,
+    access_code_id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<AccessCode, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let presale_id = ObjectId::parse_str(&presale_id)?;
+    let access_code_id = ObjectId::parse_str(&access_code_id)?;
+    find_event_to_manage(db, event_id, user_id, role).await?;
+
+    let collection: Collection<AccessCode> = db.collection(ACCESS_CODES_COLLECTION_NAME);
+    match collection
+        .find_one_and_delete(doc! {
+            "_id": access_code_id,
+            "event_id": event_id,
+            "presale_id": presale_id,
+        })
+        .await?
+    {
+        Some(access_code) => Ok(access_code),
+        None => Err("No access code was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn redeem_access_code(
+    db: &Database,
+    event_id: String,
+    access_code: UseAccessCodeRequest,
+) -> Result<AccessCode, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let code = access_code.code.to_uppercase();
+
+    let collection: Collection<AccessCode> = db.collection(ACCESS_CODES_COLLECTION_NAME);
+    let existing_access_code = match collection
+        .find_one(doc! { "event_id": event_id, "code": &code })
+        .await?
+    {
+        Some(access_code) => access_code,
+        None => return Err("This access code is not valid for this event".into()),
+    };
+
+    let event = get_event_by_id(db, event_id.to_hex()).await?;
+    let presale = event
+        .presales
+        .iter()
+        .find(|p| p.id == existing_access_code.presale_id)
+        .ok_or("This access code is not valid for this event")?;
+    let now = DateTime::now();
+    if now < presale.starts_at {
+        return Err(format!(
+            "The presale of this access code opens at {}",
+            presale.starts_at.try_to_rfc3339_string()?
+        )
+        .into());
+    }
+    if now > presale.ends_at {
+        return Err("The presale of this access code is over".into());
+    }
+
+    // The quota is part of the filter, so concurrent purchases cannot use the code more than allowed.
+    match collection
+        .find_one_and_update(
+            doc! {
+                "_id": existing_access_code.id,
+                "$expr": { "$lt": ["$uses", "$max_uses"] },
+            },
+            doc! { "$inc": { "uses": 1 } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(access_code) => Ok(access_code),
+        None => Err(Box::new(ConflictError(
+            "This access code has already been used.".to_string(),
+        ))),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn release_access_code(
+    db: &Database,
+    event_id: String,
+    access_code: UseAccessCodeRequest,
+) -> Result<AccessCode, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let code = access_code.code.to_uppercase();
+
+    let collection: Collection<AccessCode> = db.collection(ACCESS_CODES_COLLECTION_NAME);
+    match collection
+        .find_one_and_update(
+            doc! { "event_id": event_id, "code": code, "uses": { "$gt": 0 } },
+            doc! { "$inc": { "uses": -1 } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(access_code) => Ok(access_code),
+        None => Err(Box::new(ConflictError(
+            "This access code has no use to release.".to_string(),
+        ))),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn get_passes(db: &Database) -> Result<Vec<Pass>, Box<dyn std::error::Error>> {
+    let collection: Collection<Pass> = db.collection(PASSES_COLLECTION_NAME);
+
+    let cursor: Cursor<Pass> = collection.find(doc! {}).sort(doc! { "_id": -1 }).await?;
+    let passes: Vec<Pass> = cursor.try_collect().await?;
+
+    Ok(passes)
+}
+
+// =============================================================================================================================
+
+pub async fn get_pass_by_id(db: &Database, id: String) -> Result<Pass, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let collection: Collection<Pass> = db.collection(PASSES_COLLECTION_NAME);
+
+    match collection.find_one(doc! { "_id": id }).await? {
+        Some(pass) => Ok(pass),
+        None => Err("No pass was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn create_pass(
+    db: &Database,
+    pass: PassRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Pass, Box<dyn std::error::Error>> {
+    pass.validate()?;
+
+    // The pass can only bundle events that its creator is allowed to sell.
+    let mut events = vec![];
+    for pass_event in &pass.events {
+        let event = find_event_to_manage(db, pass_event.event_id, user_id.clone(), role).await?;
+        if let Some(ticket_type_id) = pass_event.ticket_type_id {
+            if !event.ticket_types.iter().any(|t| t.id == ticket_type_id) {
+                return Err(format!(
+                    "No ticket type was found with this id for the event {}",
+                    event.title
+                )
+                .into());
+            }
+        }
+        if pass
+            .sale_ends_at
+            .is_some_and(|ends_at| ends_at > event.date)
+        {
+            return Err("The sale of a pass cannot end after one of its events".into());
+        }
+        events.push((event.date, pass_event.clone()));
+    }
+    events.sort_by_key(|(date, _)| *date);
+
+    let mut pass = Pass {
+        id: None,
+        name: pass.name,
+        description: pass.description,
+        events: events
+            .into_iter()
+            .map(|(_, pass_event)| pass_event)
+            .collect(),
+        price: pass.price,
+        currency: pass.currency,
+        sale_starts_at: pass.sale_starts_at,
+        sale_ends_at: pass.sale_ends_at,
+        creator_id: ObjectId::parse_str(&user_id)?,
+        created_at: DateTime::now(),
+    };
+
+    let collection: Collection<Pass> = db.collection(PASSES_COLLECTION_NAME);
+    let res = collection.insert_one(&pass).await?;
+    pass.id = res.inserted_id.as_object_id();
+
+    Ok(pass)
+}
+
+// =============================================================================================================================
+
+pub async fn delete_pass(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Pass, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    // The passes already sold keep their admissions, only the sale stops.
+    let mut filter = doc! { "_id": id };
+    if role != AuthRole::Admin {
+        filter.insert("creator_id", user_id);
+    }
+
+    let collection: Collection<Pass> = db.collection(PASSES_COLLECTION_NAME);
+    match collection.find_one_and_delete(filter).await? {
+        Some(pass) => Ok(pass),
+        None => Err("No pass was found with this id or you are not its creator".into()),
+    }
+}
+
+// =============================================================================================================================
+
+async fn find_event_to_manage(
+    db: &Database,
+    event_id: ObjectId,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let user_id = ObjectId::parse_str(&user_id)?;
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    let event = match collection
+        .find_one(doc! { "_id": event_id, "deleted_at": null })
+        .await?
+    {
+        Some(event) => event,
+        None => return Err("No event was found with this id".into()),
+    };
+    if !can_manage_event(db, &event, user_id, role).await? {
+        return Err("Only the creator of the event or the owners and managers of its organisation can manage its sales".into());
+    }
+    if matches!(
+        event.status,
+        EventStatus::Cancelled | EventStatus::Completed
+    ) {
+        return Err("Cancelled and completed events can no longer be updated".into());
+    }
+
+    Ok(event)
+}
+
+// =============================================================================================================================
+
+fn validate_ticket_type_for_event(
+    event: &Event,
+    ticket_type: &TicketTypeRequest,
+    replaced_ticket_type_id: Option<ObjectId>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let seat_from = ticket_type.seat_from.unwrap_or(1);
+    let seat_to = ticket_type.seat_to.unwrap_or(event.capacity);
+    if seat_to > event.capacity {
+        return Err("The seat range cannot exceed the capacity of the event".into());
+    }
+    if seat_from > seat_to || seat_to - seat_from + 1 < ticket_type.quantity {
+        return Err("The seat range is too small for the quantity of tickets".into());
+    }
+
+    let other_quantity: u32 = event
+        .ticket_types
+        .iter()
+        .filter(|t| Some(t.id) != replaced_ticket_type_id)
+        .map(|t| t.quantity)
+        .sum();
+    if other_quantity + ticket_type.quantity > event.capacity {
+        return Err("The ticket types cannot exceed the capacity of the event".into());
+    }
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn delete_event_by_id(
+    db: &Database,
+    creator_id: String,
+    role: AuthRole,
+    id: String,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let creator_id = ObjectId::parse_str(&creator_id)?;
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    let existing_event = match collection
+        .find_one(doc! { "_id": id, "deleted_at": null })
+        .await?
+    {
+        Some(existing_event) => existing_event,
+        None => return Err("No event was found with this id".into()),
+    };
+    if !can_manage_event(db, &existing_event, creator_id, role).await? {
+        return Err("Only the creator of the event or the owners and managers of its organisation can delete it".into());
+    }
+
+    // The tickets of an event on sale are refunded as if it was cancelled. The refund is started before the event is
+    // deleted, while the Tickets Service can still retrieve it, and a failure keeps the event so it can be deleted again.
+    match existing_event.status {
+        EventStatus::Published | EventStatus::Postponed => {
+            cancel_event(db, id.to_hex(), creator_id.to_hex(), role).await?;
+        }
+        EventStatus::Cancelled => start_event_cancellation_request(id).await?,
+        _ => {}
+    }
+
+    let filter = doc! { "_id": id, "deleted_at": null };
+    let update_doc = doc! {
+        "$set": {
+            "deleted_at": DateTime::now(),
+            "deleted_by": creator_id
+        }
+    };
+
+    match collection
+        .find_one_and_update(filter, update_doc)
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err("No event was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn restore_event_by_id(
+    db: &Database,
+    id: String,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    let update_doc = doc! {
+        "$set": {
+            "deleted_at": null,
+            "deleted_by": null
+        }
+    };
+
+    match collection
+        .find_one_and_update(
+            doc! { "_id": id, "deleted_at": { "$ne": null } },
+            update_doc,
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(event) => Ok(event),
+        None => Err("No deleted event was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn purge_deleted_events(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    let filter = doc! { "deleted_at": { "$lt": soft_delete_purge_cutoff() } };
+
+    let event_ids = collection.distinct("_id", filter).await?;
+    if event_ids.is_empty() {
+        return Ok(());
+    }
+
+    db.collection::<EventSeat>(SEATS_COLLECTION_NAME)
+        .delete_many(doc! { "event_id": { "$in": &event_ids } })
+        .await?;
+    db.collection::<AccessCode>(ACCESS_CODES_COLLECTION_NAME)
+        .delete_many(doc! { "event_id": { "$in": &event_ids } })
+        .await?;
+    collection
+        .delete_many(doc! { "_id": { "$in": event_ids } })
+        .await?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_organiser_role(
+    db: &Database,
+    event_id: String,
+    user_id: String,
+) -> Result<Option<OrganisationRole>, Box<dyn std::error::Error>> {
+    let event = get_event_by_id(db, event_id).await?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    organiser_role(db, &event, user_id).await
+}
+
+// =============================================================================================================================
+
+async fn organiser_role(
+    db: &Database,
+    event: &Event,
+    user_id: ObjectId,
+) -> Result<Option<OrganisationRole>, Box<dyn std::error::Error>> {
+    match event.organisation_id {
+        Some(organisation_id) => get_organisation_role(db, organisation_id, user_id).await,
+        None if event.creator_id == user_id => Ok(Some(OrganisationRole::Owner)),
+        None => Ok(None),
+    }
+}
+
+// =============================================================================================================================
+
+async fn can_manage_event(
+    db: &Database,
+    event: &Event,
+    user_id: ObjectId,
+    role: AuthRole,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    if role == AuthRole::Admin {
+        return Ok(true);
+    }
+
+    let organiser_role = organiser_role(db, event, user_id).await?;
+    Ok(organiser_role.is_some_and(|r| r.can_manage_events()))
+}
+
+// =============================================================================================================================
+
+async fn get_organisation_role(
+    db: &Database,
+    organisation_id: ObjectId,
+    user_id: ObjectId,
+) -> Result<Option<OrganisationRole>, Box<dyn std::error::Error>> {
+    let collection: Collection<Organisation> = db.collection(ORGANISATIONS_COLLECTION_NAME);
+
+    let role = collection
+        .find_one(doc! { "_id": organisation_id })
+        .await?
+        .and_then(|organisation| member_role(&organisation, user_id));
+
+    Ok(role)
+}
+
+// =============================================================================================================================
+
+fn member_role(organisation: &Organisation, user_id: ObjectId) -> Option<OrganisationRole> {
+    organisation
+        .members
+        .iter()
+        .find(|member| member.user_id == user_id)
+        .map(|member| member.role)
+}
+
+// =============================================================================================================================
+
+async fn find_organisation(
+    db: &Database,
+    id: ObjectId,
+) -> Result<Organisation, Box<dyn std::error::Error>> {
+    let collection: Collection<Organisation> = db.collection(ORGANISATIONS_COLLECTION_NAME);
+
+    match collection.find_one(doc! { "_id": id }).await? {
+        Some(organisation) => Ok(organisation),
+        None => Err("No organisation was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn get_organisations(
+    db: &Database,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Vec<Organisation>, Box<dyn std::error::Error>> {
+    let user_id = ObjectId::parse_str(&user_id)?;
+    let collection: Collection<Organisation> = db.collection(ORGANISATIONS_COLLECTION_NAME);
+    let filter = match role {
+        AuthRole::Admin => doc! {},
+        _ => doc! { "members.user_id": user_id },
+    };
+
+    let cursor: Cursor<Organisation> = collection.find(filter).sort(doc! { "name": 1 }).await?;
+    let organisations: Vec<Organisation> = cursor.try_collect().await?;
+
+    Ok(organisations)
+}
+
+// =============================================================================================================================
+
+pub async fn get_organisation_by_id(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Organisation, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let organisation = find_organisation(db, id).await?;
+    if role != AuthRole::Admin && member_role(&organisation, user_id).is_none() {
+        return Err("Only the members of the organisation can access it".into());
+    }
+
+    Ok(organisation)
+}
+
+// =============================================================================================================================
+
+pub async fn create_organisation(
+    db: &Database,
+    organisation: OrganisationRequest,
+    user_id: String,
+) -> Result<Organisation, Box<dyn std::error::Error>> {
+    organisation.validate()?;
+
+    let user_id = ObjectId::parse_str(&user_id)?;
+    let now = DateTime::now();
+
+    let mut organisation = Organisation {
+        id: None,
+        name: organisation.name,
+        members: vec![OrganisationMember {
+            user_id,
+            role: OrganisationRole::Owner,
+            added_at: now,
+        }],
+        created_at: now,
+    };
+
+    let collection: Collection<Organisation> = db.collection(ORGANISATIONS_COLLECTION_NAME);
+
+    let res = collection.insert_one(&organisation).await?;
+    organisation.id = res.inserted_id.as_object_id();
+
+    Ok(organisation)
+}
+
+// =============================================================================================================================
+
+pub async fn update_organisation_by_id(
+    db: &Database,
+    organisation: OrganisationRequest,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Organisation, Box<dyn std::error::Error>> {
+    organisation.validate()?;
+
+    let id = ObjectId::parse_str(&id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let existing_organisation = find_organisation(db, id).await?;
+    let member_role = member_role(&existing_organisation, user_id);
+    if role != AuthRole::Admin && !member_role.is_some_and(|r| r.can_manage_organisation()) {
+        return Err("Only the owners of the organisation can update it".into());
+    }
+
+    let collection: Collection<Organisation> = db.collection(ORGANISATIONS_COLLECTION_NAME);
+
+    match collection
+        .find_one_and_update(
+            doc! { "_id": id },
+            doc! { "$set": { "name": organisation.name } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(organisation) => Ok(organisation),
+        None => Err("No organisation was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn delete_organisation_by_id(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Organisation, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let existing_organisation = find_organisation(db, id).await?;
+    let member_role = member_role(&existing_organisation, user_id);
+    if role != AuthRole::Admin && !member_role.is_some_and(|r| r.can_manage_organisation()) {
+        return Err("Only the owners of the organisation can delete it".into());
+    }
+
+    let events: Collection<Event> = db.collection(COLLECTION_NAME);
+    if events
+        .count_documents(doc! { "organisation_id": id, "deleted_at": null })
+        .await?
+        > 0
+    {
+        return Err("The organisation still owns events, delete them first".into());
+    }
+
+    let collection: Collection<Organisation> = db.collection(ORGANISATIONS_COLLECTION_NAME);
+
+    match collection.find_one_and_delete(doc! { "_id": id }).await? {
+        Some(organisation) => Ok(organisation),
+        None => Err("No organisation was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+fn can_assign_role(
+    role: AuthRole,
+    actor_role: Option<OrganisationRole>,
+    target_role: OrganisationRole,
+) -> bool {
+    match (role, actor_role) {
+        (AuthRole::Admin, _) | (_, Some(OrganisationRole::Owner)) => true,
+        (_, Some(OrganisationRole::Manager)) => matches!(
+            target_role,
+            OrganisationRole::BoxOffice | OrganisationRole::Viewer
+        ),
+        _ => false,
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn add_organisation_member(
+    db: &Database,
+    id: String,
+    member: AddOrganisationMemberRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Organisation, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let existing_organisation = find_organisation(db, id).await?;
+    let actor_role = member_role(&existing_organisation, user_id);
+    if !can_assign_role(role, actor_role, member.role) {
+        return Err(
+            "You are not allowed to add a member with this role to the organisation".into(),
+        );
+    }
+
+    let new_member = OrganisationMember {
+        user_id: member.user_id,
+        role: member.role,
+        added_at: DateTime::now(),
+    };
+
+    let collection: Collection<Organisation> = db.collection(ORGANISATIONS_COLLECTION_NAME);
+
+    match collection
+        .find_one_and_update(
+            doc! { "_id": id, "members.user_id": { "$ne": member.user_id } },
+            doc! { "$push": { "members": to_bson(&new_member)? } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(organisation) => Ok(organisation),
+        None => Err("This user is already a member of the organisation".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn update_organisation_member(
+    db: &Database,
+    id: String,
+    member_id: String,
+    member: UpdateOrganisationMemberRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Organisation, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let member_id = ObjectId::parse_str(&member_id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let existing_organisation = find_organisation(db, id).await?;
+    let actor_role = member_role(&existing_organisation, user_id);
+    let current_role = match member_role(&existing_organisation, member_id) {
+        Some(current_role) => current_role,
+        None => return Err("This user is not a member of the organisation".into()),
+    };
+    if !can_assign_role(role, actor_role, current_role)
+        || !can_assign_role(role, actor_role, member.role)
+    {
+        return Err("You are not allowed to give this role to this member".into());
+    }
+
+    let mut filter = doc! { "_id": id, "members.user_id": member_id };
+    if current_role == OrganisationRole::Owner && member.role != OrganisationRole::Owner {
+        filter.insert(
+            "members",
+            doc! { "$elemMatch": { "role": "Owner", "user_id": { "$ne": member_id } } },
+        );
+    }
+
+    let collection: Collection<Organisation> = db.collection(ORGANISATIONS_COLLECTION_NAME);
+
+    match collection
+        .find_one_and_update(
+            filter,
+            doc! { "$set": { "members.$[member].role": to_bson(&member.role)? } },
+        )
+        .array_filters(vec![doc! { "member.user_id": member_id }])
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(organisation) => Ok(organisation),
+        None => Err("The organisation must keep at least one owner".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn remove_organisation_member(
+    db: &Database,
+    id: String,
+    member_id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Organisation, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let member_id = ObjectId::parse_str(&member_id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let existing_organisation = find_organisation(db, id).await?;
+    let actor_role = member_role(&existing_organisation, user_id);
+    let current_role = match member_role(&existing_organisation, member_id) {
+        Some(current_role) => current_role,
+        None => return Err("This user is not a member of the organisation".into()),
+    };
+    if member_id != user_id && !can_assign_role(role, actor_role, current_role) {
+        return Err("You are not allowed to remove this member from the organisation".into());
+    }
+
+    let mut filter = doc! { "_id": id, "members.user_id": member_id };
+    if current_role == OrganisationRole::Owner {
+        filter.insert(
+            "members",
+            doc! { "$elemMatch": { "role": "Owner", "user_id": { "$ne": member_id } } },
+        );
+    }
+
+    let collection: Collection<Organisation> = db.collection(ORGANISATIONS_COLLECTION_NAME);
+
+    match collection
+        .find_one_and_update(
+            filter,
+            doc! { "$pull": { "members": { "user_id": member_id } } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(organisation) => Ok(organisation),
+        None => Err("The organisation must keep at least one owner".into()),
+    }
+}
+
+// =============================================================================================================================
+
+async fn find_venue(db: &Database, id: ObjectId) -> Result<Venue, Box<dyn std::error::Error>> {
+    let collection: Collection<Venue> = db.collection(VENUES_COLLECTION_NAME);
+
+    match collection.find_one(doc! { "_id": id }).await? {
+        Some(venue) => Ok(venue),
+        None => Err("No venue was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+async fn find_venue_to_manage(
+    db: &Database,
+    id: &str,
+    user_id: &str,
+    role: AuthRole,
+) -> Result<Venue, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(id)?;
+    let user_id = ObjectId::parse_str(user_id)?;
+
+    let venue = find_venue(db, id).await?;
+    if role != AuthRole::Admin && venue.creator_id != user_id {
+        return Err("Only the creator of the venue can manage it".into());
+    }
+
+    Ok(venue)
+}
+
+// =============================================================================================================================
+
+pub async fn get_venues(db: &Database) -> Result<Vec<Venue>, Box<dyn std::error::Error>> {
+    let collection: Collection<Venue> = db.collection(VENUES_COLLECTION_NAME);
+
+    let cursor: Cursor<Venue> = collection.find(doc! {}).sort(doc! { "name": 1 }).await?;
+    let venues: Vec<Venue> = cursor.try_collect().await?;
+
+    Ok(venues)
+}
+
+// =============================================================================================================================
+
+pub async fn get_venue_by_id(
+    db: &Database,
+    id: String,
+) -> Result<Venue, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+
+    find_venue(db, id).await
+}
+
+// =============================================================================================================================
+
+pub async fn create_venue(
+    db: &Database,
+    venue: VenueRequest,
+    user_id: String,
+) -> Result<Venue, Box<dyn std::error::Error>> {
+    venue.validate()?;
+
+    let mut venue = Venue {
+        id: None,
+        name: venue.name,
+        address: venue.address,
+        latitude: venue.latitude,
+        longitude: venue.longitude,
+        timezone: venue.timezone,
+        seating_maps: vec![],
+        creator_id: ObjectId::parse_str(&user_id)?,
+        created_at: DateTime::now(),
+    };
+
+    let collection: Collection<Venue> = db.collection(VENUES_COLLECTION_NAME);
+
+    let res = collection.insert_one(&venue).await?;
+    venue.id = res.inserted_id.as_object_id();
+
+    Ok(venue)
+}
+
+// =============================================================================================================================
+
+pub async fn update_venue_by_id(
+    db: &Database,
+    venue: VenueRequest,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Venue, Box<dyn std::error::Error>> {
+    venue.validate()?;
+
+    let existing_venue = find_venue_to_manage(db, &id, &user_id, role).await?;
+    let collection: Collection<Venue> = db.collection(VENUES_COLLECTION_NAME);
+
+    let updated_venue = match collection
+        .find_one_and_update(
+            doc! { "_id": existing_venue.id },
+            doc! { "$set": {
+                "name": venue.name,
+                "address": to_bson(&venue.address)?,
+                "latitude": venue.latitude,
+                "longitude": venue.longitude,
+                "timezone": &venue.timezone,
+            } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(venue) => venue,
+        None => return Err("No venue was found with this id".into()),
+    };
+
+    // The events of the venue that have not taken place yet move with it.
+    let mut filter = status_filter(&[
+        EventStatus::Draft,
+        EventStatus::Scheduled,
+        EventStatus::Published,
+        EventStatus::Postponed,
+    ]);
+    filter.insert("venue_id", existing_venue.id);
+    filter.insert("deleted_at", Bson::Null);
+
+    let events: Collection<Event> = db.collection(COLLECTION_NAME);
+    events
+        .update_many(
+            filter,
+            doc! { "$set": {
+                "city": venue.address.city,
+                "geo_location": to_bson(&GeoPoint::new(venue.latitude, venue.longitude))?,
+                "timezone": venue.timezone,
+            } },
+        )
+        .await?;
+
+    Ok(updated_venue)
+}
+
+// =============================================================================================================================
+
+pub async fn delete_venue_by_id(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Venue, Box<dyn std::error::Error>> {
+    let existing_venue = find_venue_to_manage(db, &id, &user_id, role).await?;
+
+    let events: Collection<Event> = db.collection(COLLECTION_NAME);
+    if events
+        .count_documents(doc! { "venue_id": existing_venue.id, "deleted_at": null })
+        .await?
+        > 0
+    {
+        return Err("The venue is still used by events, delete them first".into());
+    }
+
+    let collection: Collection<Venue> = db.collection(VENUES_COLLECTION_NAME);
+
+    match collection
+        .find_one_and_delete(doc! { "_id": existing_venue.id })
+        .await?
+    {
+        Some(venue) => Ok(venue),
+        None => Err("No venue was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn add_seating_map(
+    db: &Database,
+    id: String,
+    seating_map: SeatingMapRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Venue, Box<dyn std::error::Error>> {
+    seating_map.validate()?;
+
+    let existing_venue = find_venue_to_manage(db, &id, &user_id, role).await?;
+    let seating_map = SeatingMap {
+        id: ObjectId::new(),
+        name: seating_map.name,
+        sections: seating_map.sections,
+    };
+
+    let collection: Collection<Venue> = db.collection(VENUES_COLLECTION_NAME);
+
+    match collection
+        .find_one_and_update(
+            doc! { "_id": existing_venue.id },
+            doc! { "$push": { "seating_maps": to_bson(&seating_map)? } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(venue) => Ok(venue),
+        None => Err("No venue was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn update_seating_map(
+    db: &Database,
+    id: String,
+    seating_map_id: String,
+    seating_map: SeatingMapRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Venue, Box<dyn std::error::Error>> {
+    seating_map.validate()?;
+
+    let seating_map_id = ObjectId::parse_str(&seating_map_id)?;
+    let existing_venue = find_venue_to_manage(db, &id, &user_id, role).await?;
+
+    // Events keep the seats generated when they were created, so they are not affected.
+    let collection: Collection<Venue> = db.collection(VENUES_COLLECTION_NAME);
+
+    match collection
+        .find_one_and_update(
+            doc! { "_id": existing_venue.id, "seating_maps.id": seating_map_id },
+            doc! { "$set": {
+                "seating_maps.$.name": seating_map.name,
+                "seating_maps.$.sections": to_bson(&seating_map.sections)?,
+            } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(venue) => Ok(venue),
+        None => Err("No seating map was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn delete_seating_map(
+    db: &Database,
+    id: String,
+    seating_map_id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Venue, Box<dyn std::error::Error>> {
+    let seating_map_id = ObjectId::parse_str(&seating_map_id)?;
+    let existing_venue = find_venue_to_manage(db, &id, &user_id, role).await?;
+
+    let collection: Collection<Venue> = db.collection(VENUES_COLLECTION_NAME);
+
+    match collection
+        .find_one_and_update(
+            doc! { "_id": existing_venue.id, "seating_maps.id": seating_map_id },
+            doc! { "$pull": { "seating_maps": { "id": seating_map_id } } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(venue) => Ok(venue),
+        None => Err("No seating map was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+const PUBLIC_EVENT_STATUSES: [EventStatus; 4] = [
+    EventStatus::Published,
+    EventStatus::Postponed,
+    EventStatus::Cancelled,
+    EventStatus::Completed,
+];
+
+// =============================================================================================================================
+
+async fn find_series(
+    db: &Database,
+    id: ObjectId,
+) -> Result<EventSeries, Box<dyn std::error::Error>> {
+    let collection: Collection<EventSeries> = db.collection(SERIES_COLLECTION_NAME);
+
+    match collection.find_one(doc! { "_id": id }).await? {
+        Some(series) => Ok(series),
+        None => Err("No series was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+async fn series_organiser_role(
+    db: &Database,
+    series: &EventSeries,
+    user_id: ObjectId,
+) -> Result<Option<OrganisationRole>, Box<dyn std::error::Error>> {
+    match series.organisation_id {
+        Some(organisation_id) => get_organisation_role(db, organisation_id, user_id).await,
+        None if series.creator_id == user_id => Ok(Some(OrganisationRole::Owner)),
+        None => Ok(None),
+    }
+}
+
+// =============================================================================================================================
+
+async fn find_series_to_manage(
+    db: &Database,
+    id: &str,
+    user_id: &str,
+    role: AuthRole,
+) -> Result<EventSeries, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(id)?;
+    let user_id = ObjectId::parse_str(user_id)?;
+
+    let series = find_series(db, id).await?;
+    if role != AuthRole::Admin
+        && !series_organiser_role(db, &series, user_id)
+            .await?
+            .is_some_and(|r| r.can_manage_events())
+    {
+        return Err("Only the creator of the series or the owners and managers of its organisation can manage it".into());
+    }
+
+    Ok(series)
+}
+
+// =============================================================================================================================
+
+async fn get_series_events(
+    db: &Database,
+    series_id: ObjectId,
+    public_only: bool,
+) -> Result<Vec<Event>, Box<dyn std::error::Error>> {
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+
+    let mut filter = doc! { "series_id": series_id, "deleted_at": null };
+    if public_only {
+        filter.extend(status_filter(&PUBLIC_EVENT_STATUSES));
+    }
+
+    let cursor: Cursor<Event> = collection.find(filter).sort(doc! { "date": 1 }).await?;
+    let events: Vec<Event> = cursor.try_collect().await?;
+
+    Ok(events)
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_series(
+    db: &Database,
+) -> Result<Vec<EventSeries>, Box<dyn std::error::Error>> {
+    // Only the series with public dates are listed, so drafts stay private.
+    let mut filter = doc! { "series_id": { "$ne": null }, "deleted_at": null };
+    filter.extend(status_filter(&PUBLIC_EVENT_STATUSES));
+    let series_ids = db
+        .collection::<Event>(COLLECTION_NAME)
+        .distinct("series_id", filter)
+        .await?;
+
+    let collection: Collection<EventSeries> = db.collection(SERIES_COLLECTION_NAME);
+    let cursor: Cursor<EventSeries> = collection
+        .find(doc! { "_id": { "$in": series_ids } })
+        .sort(doc! { "title": 1 })
+        .await?;
+    let series: Vec<EventSeries> = cursor.try_collect().await?;
+
+    Ok(series)
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_series_by_id(
+    db: &Database,
+    id: String,
+    viewer: Option<(String, AuthRole)>,
+) -> Result<EventSeriesDetails, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let series = find_series(db, id).await?;
+
+    let is_organiser = match viewer {
+        Some((user_id, role)) => {
+            let user_id = ObjectId::parse_str(&user_id)?;
+            role == AuthRole::Admin || series_organiser_role(db, &series, user_id).await?.is_some()
+        }
+        None => false,
+    };
+
+    let events = get_series_events(db, id, !is_organiser).await?;
+    if !is_organiser && events.is_empty() {
+        return Err("No series was found with this id".into());
+    }
+
+    Ok(EventSeriesDetails { series, events })
+}
+
+// =============================================================================================================================
+
+pub async fn create_event_series(
+    db: &Database,
+    series: CreateEventSeriesRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<EventSeriesDetails, Box<dyn std::error::Error>> {
+    series.validate()?;
+
+    if let Some(category) = &series.category {
+        find_category_by_slug(db, category).await?;
+    }
+    let creator_id = ObjectId::parse_str(&user_id)?;
+    if let Some(organisation_id) = series.organisation_id {
+        let organisation_role = get_organisation_role(db, organisation_id, creator_id).await?;
+        let can_manage_events = organisation_role.is_some_and(|r| r.can_manage_events());
+        if role != AuthRole::Admin && !can_manage_events {
+            return Err(
+                "Only the owners and managers of the organisation can create its series".into(),
+            );
+        }
+    }
+
+    let mut event_series = EventSeries {
+        id: None,
+        title: series.title,
+        description: series.description,
+        category: series.category,
+        tags: normalize_tags(series.tags),
+        location: series.location,
+        geo_location: GeoPoint::from_coordinates(series.latitude, series.longitude),
+        timezone: series.timezone,
+        capacity: series.capacity,
+        price: series.price,
+        organisation_id: series.organisation_id,
+        venue_id: series.venue_id,
+        seating_map_id: series.seating_map_id,
+        creator_id,
+        created_at: DateTime::now(),
+    };
+
+    let collection: Collection<EventSeries> = db.collection(SERIES_COLLECTION_NAME);
+    let res = collection.insert_one(&event_series).await?;
+    event_series.id = res.inserted_id.as_object_id();
+
+    match create_series_events(db, &event_series, series.schedule, user_id, role).await {
+        Ok(events) => Ok(EventSeriesDetails {
+            series: event_series,
+            events,
+        }),
+        Err(e) => {
+            collection
+                .delete_one(doc! { "_id": event_series.id })
+                .await?;
+            Err(e)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn add_event_series_dates(
+    db: &Database,
+    id: String,
+    schedule: SeriesDatesRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<EventSeriesDetails, Box<dyn std::error::Error>> {
+    schedule.validate()?;
+
+    let series = find_series_to_manage(db, &id, &user_id, role).await?;
+    let series_id = series.id.ok_or("The series has no id")?;
+    create_series_events(db, &series, schedule, user_id, role).await?;
+
+    let events = get_series_events(db, series_id, false).await?;
+    Ok(EventSeriesDetails { series, events })
+}
+
+// =============================================================================================================================
+
+async fn create_series_events(
+    db: &Database,
+    series: &EventSeries,
+    schedule: SeriesDatesRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<Vec<Event>, Box<dyn std::error::Error>> {
+    let series_id = series.id.ok_or("The series has no id")?;
+    let dates = match schedule.recurrence {
+        Some(recurrence) => recurrence_dates(&recurrence, series.timezone.as_deref())?
+            .into_iter()
+            .map(|date| SeriesDateRequest {
+                date,
+                title: None,
+                location: None,
+                city: None,
+                latitude: None,
+                longitude: None,
+                timezone: None,
+                venue_id: None,
+                seating_map_id: None,
+                capacity: None,
+                price: None,
+            })
+            .collect(),
+        None => schedule.dates,
+    };
+
+    // A date with its own location or venue does not take the venue, coordinates and timezone of the series.
+    let requests: Vec<CreateEventRequest> = dates
+        .into_iter()
+        .map(|date| {
+            let own_place = date.location.is_some() || date.venue_id.is_some();
+            let (venue_id, seating_map_id) = if own_place {
+                (date.venue_id, date.seating_map_id)
+            } else {
+                (series.venue_id, series.seating_map_id)
+            };
+            let (latitude, longitude) = match (&series.geo_location, own_place) {
+                (Some(geo_location), false) if date.latitude.is_none() => (
+                    Some(geo_location.coordinates[1]),
+                    Some(geo_location.coordinates[0]),
+                ),
+                _ => (date.latitude, date.longitude),
+            };
+            let timezone = if own_place {
+                date.timezone
+            } else {
+                date.timezone.or_else(|| series.timezone.clone())
+            };
+            CreateEventRequest {
+                title: date.title.unwrap_or(format!(
+                    "{} - {}",
+                    series.title,
+                    date.date.to_chrono().format("%Y-%m-%d")
+                )),
+                description: series.description.clone(),
+                category: series.category.clone(),
+                tags: series.tags.clone(),
+                location: date.location.unwrap_or_else(|| {
+                    if own_place {
+                        String::new()
+                    } else {
+                        series.location.clone()
+                    }
+                }),
+                city: date.city,
+                latitude,
+                longitude,
+                timezone,
+                date: date.date,
+                capacity: date.capacity.unwrap_or(series.capacity),
+                price: date.price.unwrap_or(series.price),
+                organisation_id: series.organisation_id,
+                venue_id,
+                seating_map_id,
+                sale_starts_at: None,
+                sale_ends_at: None,
+                purchase_limits: PurchaseLimits::default(),
+            }
+        })
+        .collect();
+
+    let mut titles: Vec<&str> = requests.iter().map(|r| r.title.as_str()).collect();
+    titles.sort_unstable();
+    if titles.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err("Several dates would share the same title, give them their own title".into());
+    }
+
+    // The dates are created one by one, and removed again if one of them fails.
+    let mut events: Vec<Event> = vec![];
+    for request in requests {
+        let date = request.date;
+        match insert_event(db, request, user_id.clone(), role, Some(series_id)).await {
+            Ok(event) => events.push(event),
+            Err(e) => {
+                let event_ids: Vec<ObjectId> = events.iter().filter_map(|event| event.id).collect();
+                db.collection::<EventSeat>(SEATS_COLLECTION_NAME)
+                    .delete_many(doc! { "event_id": { "$in": &event_ids } })
+                    .await?;
+                db.collection::<Event>(COLLECTION_NAME)
+                    .delete_many(doc! { "_id": { "$in": &event_ids } })
+                    .await?;
+                return Err(format!(
+                    "The date {} could not be created: {}",
+                    date.try_to_rfc3339_string()?,
+                    e
+                )
+                .into());
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+// =============================================================================================================================
+
+// The dates follow the timezone of the series, so they keep their local time across daylight saving changes.
+fn recurrence_dates(
+    rule: &RecurrenceRule,
+    timezone: Option<&str>,
+) -> Result<Vec<DateTime>, Box<dyn std::error::Error>> {
+    let timezone: chrono_tz::Tz = timezone.unwrap_or("UTC").parse()?;
+    let starts_at = rule.starts_at.to_chrono().with_timezone(&timezone);
+
+    (0..rule.count)
+        .map(|occurrence| {
+            let step = occurrence * rule.interval;
+            let date = match rule.frequency {
+                RecurrenceFrequency::Daily => {
+                    starts_at.checked_add_days(chrono::Days::new(step as u64))
+                }
+                RecurrenceFrequency::Weekly => {
+                    starts_at.checked_add_days(chrono::Days::new(step as u64 * 7))
+                }
+                RecurrenceFrequency::Monthly => {
+                    starts_at.checked_add_months(chrono::Months::new(step))
+                }
+            };
+            match date {
+                Some(date) => Ok(DateTime::from_chrono(date.with_timezone(&chrono::Utc))),
+                None => Err(
+                    "The recurrence falls outside the supported dates or on a skipped local time"
+                        .into(),
+                ),
+            }
+        })
+        .collect()
+}
+
+// =============================================================================================================================
+
+pub async fn update_event_series(
+    db: &Database,
+    id: String,
+    series: UpdateEventSeriesRequest,
+    user_id: String,
+    role: AuthRole,
+) -> Result<EventSeries, Box<dyn std::error::Error>> {
+    series.validate()?;
+
+    let existing_series = find_series_to_manage(db, &id, &user_id, role).await?;
+
+    let mut set_doc = doc! {};
+    if let Some(title) = &series.title {
+        set_doc.insert("title", title);
+    }
+    if let Some(description) = &series.description {
+        set_doc.insert("description", description);
+    }
+    if let Some(price) = series.price {
+        set_doc.insert("price", price);
+    }
+
+    let collection: Collection<EventSeries> = db.collection(SERIES_COLLECTION_NAME);
+    let updated_series = match collection
+        .find_one_and_update(doc! { "_id": existing_series.id }, doc! { "$set": set_doc })
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(series) => series,
+        None => return Err("No series was found with this id".into()),
+    };
+
+    // The shared metadata is applied to the dates still to come, except for the prices they override.
+    let events: Collection<Event> = db.collection(COLLECTION_NAME);
+    let mut filter = doc! { "series_id": existing_series.id, "deleted_at": null };
+    filter.extend(status_filter(&[
+        EventStatus::Draft,
+        EventStatus::Scheduled,
+        EventStatus::Published,
+        EventStatus::Postponed,
+    ]));
+    if let Some(description) = &series.description {
+        if is_moderated(role) {
+            submit_series_description(&events, &filter, description, &user_id).await?;
+        } else {
+            events
+                .update_many(
+                    filter.clone(),
+                    doc! { "$set": { "description": description } },
+                )
+                .await?;
+        }
+    }
+    if let Some(price) = series.price {
+        filter.insert("price", existing_series.price);
+        events
+            .update_many(filter, doc! { "$set": { "price": price } })
+            .await?;
+    }
+
+    Ok(updated_series)
+}
+
+// =============================================================================================================================
+
+async fn submit_series_description(
+    events: &Collection<Event>,
+    filter: &Document,
+    description: &str,
+    user_id: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let changes = EventChanges {
+        description: Some(description.to_string()),
+        ..Default::default()
+    };
+    let review = EventReview {
+        action: ReviewAction::Submitted,
+        changes: Some(changes),
+        reason: None,
+        user_id: ObjectId::parse_str(user_id)?,
+        created_at: DateTime::now(),
+    };
+    let pending = to_bson(&ReviewStatus::Pending)?;
+
+    // Approved dates keep their description online until the change is reviewed.
+    let mut approved_filter = filter.clone();
+    approved_filter.insert(
+        "$or",
+        vec![
+            review_status_filter(&[ReviewStatus::Approved]),
+            doc! { "pending_changes": { "$ne": null } },
+        ],
+    );
+    events
+        .update_many(
+            approved_filter,
+            doc! {
+                "$set": { "pending_changes.description": description, "review_status": pending.clone() },
+                "$push": { "review_history": to_bson(&review)? },
+            },
+        )
+        .await?;
+
+    let mut unapproved_filter = filter.clone();
+    unapproved_filter.insert("pending_changes", Bson::Null);
+    unapproved_filter.extend(review_status_filter(&[
+        ReviewStatus::Pending,
+        ReviewStatus::Rejected,
+    ]));
+    events
+        .update_many(
+            unapproved_filter,
+            doc! {
+                "$set": { "description": description, "review_status": pending },
+                "$push": { "review_history": to_bson(&review)? },
+            },
+        )
+        .await?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn delete_event_series(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<EventSeries, Box<dyn std::error::Error>> {
+    let existing_series = find_series_to_manage(db, &id, &user_id, role).await?;
+
+    let events: Collection<Event> = db.collection(COLLECTION_NAME);
+    if events
+        .count_documents(doc! { "series_id": existing_series.id, "deleted_at": null })
+        .await?
+        > 0
+    {
+        return Err("The series still has dates, delete them first".into());
+    }
+
+    let collection: Collection<EventSeries> = db.collection(SERIES_COLLECTION_NAME);
+    match collection
+        .find_one_and_delete(doc! { "_id": existing_series.id })
+        .await?
+    {
+        Some(series) => Ok(series),
+        None => Err("No series was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_series_statistics(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<SeriesStatistics, Box<dyn std::error::Error>> {
+    let series = find_series_to_manage(db, &id, &user_id, role).await?;
+    let series_id = series.id.ok_or("The series has no id")?;
+    let events = get_series_events(db, series_id, false).await?;
+
+    let per_date: Vec<SeriesDateStatistics> = events
+        .into_iter()
+        .map(|event| SeriesDateStatistics {
+            event_id: event.id.map(|id| id.to_hex()).unwrap_or_default(),
+            title: event.title,
+            date: event.date,
+            status: event.status,
+            capacity: event.capacity,
+            sold: event.capacity.saturating_sub(event.remaining_seats),
+            remaining: event.remaining_seats,
+        })
+        .collect();
+
+    let capacity: u32 = per_date.iter().map(|date| date.capacity).sum();
+    let sold: u32 = per_date.iter().map(|date| date.sold).sum();
+    let remaining: u32 = per_date.iter().map(|date| date.remaining).sum();
+
+    Ok(SeriesStatistics {
+        series_id: series_id.to_hex(),
+        dates: per_date.len() as u32,
+        capacity,
+        sold,
+        remaining,
+        sell_through: sell_through(sold, capacity),
+        per_date,
+    })
+}
+
+// =============================================================================================================================
+
+// Percentage of the capacity that was sold, rounded to two decimals.
+fn sell_through(sold: u32, capacity: u32) -> f64 {
+    match capacity {
+        0 => 0.0,
+        _ => (sold as f64 * 10000.0 / capacity as f64).round() / 100.0,
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_sales_statistics(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<EventSalesStatistics, Box<dyn std::error::Error>> {
+    let event = get_event_by_id(db, id).await?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    if role != AuthRole::Admin
+        && organiser_role(db, &event, user_id).await? != Some(OrganisationRole::Owner)
+    {
+        return Err("Only the owners of the event can access its sales statistics".into());
+    }
+
+    sales_statistics(&[event])
+        .await?
+        .pop()
+        .ok_or_else(|| "The sales statistics of the event could not be computed".into())
+}
+
+// =============================================================================================================================
+
+pub async fn get_organisation_sales_statistics(
+    db: &Database,
+    id: String,
+    user_id: String,
+    role: AuthRole,
+) -> Result<OrganisationSalesStatistics, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let organisation = find_organisation(db, id).await?;
+    if role != AuthRole::Admin
+        && member_role(&organisation, user_id) != Some(OrganisationRole::Owner)
+    {
+        return Err("Only the owners of the organisation can access its sales statistics".into());
+    }
+
+    let collection: Collection<Event> = db.collection(COLLECTION_NAME);
+    let events: Vec<Event> = collection
+        .find(doc! { "organisation_id": id, "deleted_at": null })
+        .sort(doc! { "date": 1 })
+        .await?
+        .try_collect()
+        .await?;
+    let per_event = sales_statistics(&events).await?;
+
+    let mut tickets = TicketCounts::default();
+    let mut revenue = vec![];
+    let mut revenue_by_day: Vec<DailyRevenue> = vec![];
+    let mut refunded = vec![];
+    for event in &per_event {
+        tickets.pending += event.tickets.pending;
+        tickets.active += event.tickets.active;
+        tickets.cancelled += event.tickets.cancelled;
+        tickets.refunded += event.tickets.refunded;
+        add_amounts(&mut revenue, &event.revenue);
+        add_amounts(&mut refunded, &event.refunded);
+
+        for day in &event.revenue_by_day {
+            match revenue_by_day
+                .iter_mut()
+                .find(|total| total.day == day.day && total.currency == day.currency)
+            {
+                Some(total) => {
+                    total.amount += day.amount;
+                    total.payments += day.payments;
+                }
+                None => revenue_by_day.push(day.clone()),
+            }
+        }
+    }
+    revenue_by_day.sort_by(|a, b| a.day.cmp(&b.day));
+
+    let capacity: u32 = per_event.iter().map(|event| event.capacity).sum();
+    let sold: u32 = per_event.iter().map(|event| event.sold).sum();
+
+    Ok(OrganisationSalesStatistics {
+        organisation_id: id.to_hex(),
+        events: per_event.len() as u32,
+        capacity,
+        sold,
+        sell_through: sell_through(sold, capacity),
+        tickets,
+        revenue,
+        revenue_by_day,
+        refunded,
+        refunds: per_event.iter().map(|event| event.refunds).sum(),
+        cancellations: per_event.iter().map(|event| event.cancellations).sum(),
+        per_event,
+    })
+}
+
+// =============================================================================================================================
+
+// The tickets and the payments are aggregated by their own services, a batch of events at a time.
+async fn sales_statistics(
+    events: &[Event],
+) -> Result<Vec<EventSalesStatistics>, Box<dyn std::error::Error>> {
+    let mut statistics = vec![];
+
+    for batch in events.chunks(STATISTICS_BATCH_SIZE) {
+        let event_ids: Vec<String> = batch
+            .iter()
+            .filter_map(|event| event.id)
+            .map(|id| id.to_hex())
+            .collect();
+        if event_ids.is_empty() {
+            continue;
+        }
+        let tickets = ticket_statistics_request(&event_ids).await?;
+        let payments = payment_statistics_request(&event_ids).await?;
+
+        for event in batch {
+            let event_id = event.id.map(|id| id.to_hex()).unwrap_or_default();
+            let tickets = tickets
+                .iter()
+                .find(|tickets| tickets.event_id == event_id)
+                .map(|tickets| tickets.tickets.clone())
+                .unwrap_or_default();
+            let payments = payments
+                .iter()
+                .find(|payments| payments.event_id == event_id);
+
+            statistics.push(EventSalesStatistics {
+                event_id,
+                title: event.title.clone(),
+                date: event.date,
+                status: event.status,
+                capacity: event.capacity,
+                sold: tickets.active,
+                sell_through: sell_through(tickets.active, event.capacity),
+                cancellations: tickets.cancelled,
+                tickets,
+                revenue: payments.map(|p| p.revenue.clone()).unwrap_or_default(),
+                revenue_by_day: payments
+                    .map(|p| p.revenue_by_day.clone())
+                    .unwrap_or_default(),
+                refunded: payments.map(|p| p.refunded.clone()).unwrap_or_default(),
+                refunds: payments.map(|p| p.refunds).unwrap_or_default(),
+            });
+        }
+    }
+
+    Ok(statistics)
+}
+
+// =============================================================================================================================
+
+fn add_amounts(totals: &mut Vec<CurrencyAmount>, amounts: &[CurrencyAmount]) {
+    for amount in amounts {
+        match totals
+            .iter_mut()
+            .find(|total| total.currency == amount.currency)
+        {
+            Some(total) => total.amount += amount.amount,
+            None => totals.push(amount.clone()),
+        }
+    }
+}
+
+// =============================================================================================================================
+
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = vec![];
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if !tag.is_empty() && !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+
+    normalized
+}
+
+// =============================================================================================================================
+
+async fn find_category_by_slug(
+    db: &Database,
+    slug: &str,
+) -> Result<Category, Box<dyn std::error::Error>> {
+    let collection: Collection<Category> = db.collection(CATEGORIES_COLLECTION_NAME);
+
+    match collection.find_one(doc! { "slug": slug }).await? {
+        Some(category) => Ok(category),
+        None => Err(format!("No category was found with the slug {}", slug).into()),
+    }
+}
+
+// =============================================================================================================================
+
+// A category also matches the events of its sub-categories.
+async fn category_slugs(
+    db: &Database,
+    slug: &str,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let category = find_category_by_slug(db, slug).await?;
+
+    let collection: Collection<Category> = db.collection(CATEGORIES_COLLECTION_NAME);
+    let children: Vec<Category> = collection
+        .find(doc! { "parent_id": category.id })
+        .await?
+        .try_collect()
+        .await?;
+
+    Ok(std::iter::once(category.slug)
+        .chain(children.into_iter().map(|child| child.slug))
+        .collect())
+}
+
+// =============================================================================================================================
+
+pub async fn get_categories(db: &Database) -> Result<Vec<Category>, Box<dyn std::error::Error>> {
+    let collection: Collection<Category> = db.collection(CATEGORIES_COLLECTION_NAME);
+
+    let cursor: Cursor<Category> = collection.find(doc! {}).sort(doc! { "name": 1 }).await?;
+    let categories: Vec<Category> = cursor.try_collect().await?;
+
+    Ok(categories)
+}
+
+// =============================================================================================================================
+
+pub async fn create_category(
+    db: &Database,
+    category: CategoryRequest,
+) -> Result<Category, Box<dyn std::error::Error>> {
+    category.validate()?;
+
+    let collection: Collection<Category> = db.collection(CATEGORIES_COLLECTION_NAME);
+
+    if collection
+        .find_one(doc! { "slug": &category.slug })
+        .await?
+        .is_some()
+    {
+        return Err("A category already uses this slug".into());
+    }
+    // The taxonomy has two levels: categories and their sub-categories.
+    if let Some(parent_id) = category.parent_id {
+        match collection.find_one(doc! { "_id": parent_id }).await? {
+            Some(parent) if parent.parent_id.is_some() => {
+                return Err("A sub-category cannot have sub-categories of its own".into());
+            }
+            Some(_) => {}
+            None => return Err("No parent category was found with this id".into()),
+        }
+    }
+
+    let mut category = Category {
+        id: None,
+        name: category.name,
+        slug: category.slug,
+        parent_id: category.parent_id,
+        created_at: DateTime::now(),
+    };
+
+    let res = collection.insert_one(&category).await?;
+    category.id = res.inserted_id.as_object_id();
+
+    Ok(category)
+}
+
+// =============================================================================================================================
+
+pub async fn update_category(
+    db: &Database,
+    category: UpdateCategoryRequest,
+    id: String,
+) -> Result<Category, Box<dyn std::error::Error>> {
+    category.validate()?;
+
+    let id = ObjectId::parse_str(&id)?;
+    let collection: Collection<Category> = db.collection(CATEGORIES_COLLECTION_NAME);
+
+    match collection
+        .find_one_and_update(
+            doc! { "_id": id },
+            doc! { "$set": { "name": category.name } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(category) => Ok(category),
+        None => Err("No category was found with this id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn delete_category(
+    db: &Database,
+    id: String,
+) -> Result<Category, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let collection: Collection<Category> = db.collection(CATEGORIES_COLLECTION_NAME);
+
+    let category = match collection.find_one(doc! { "_id": id }).await? {
+        Some(category) => category,
+        None => return Err("No category was found with this id".into()),
+    };
+    if collection.count_documents(doc! { "parent_id": id }).await? > 0 {
+        return Err("The category still has sub-categories, delete them first".into());
+    }
+    let events: Collection<Event> = db.collection(COLLECTION_NAME);
+    let series: Collection<EventSeries> = db.collection(SERIES_COLLECTION_NAME);
+    if events
+        .count_documents(doc! { "category": &category.slug })
+        .await?
+        > 0
+        || series
+            .count_documents(doc! { "category": &category.slug })
+            .await?
+            > 0
+    {
+        return Err(
+            "The category is still used by events, move them to another category first".into(),
+        );
+    }
+
+    collection.delete_one(doc! { "_id": id }).await?;
+
+    Ok(category)
+}
+
+// =============================================================================================================================
+
+async fn get_highest_taken_seat_request(
+    event_id: ObjectId,
+) -> Result<Option<u32>, Box<dyn std::error::Error>> {
+    let res = reqwest::Client::new()
+        .get(format!(
+            "http://tickets-service:8080/api/tickets/events/{}/seats",
+            event_id.to_hex()
+        ))
+        .send()
+        .await?
+        .json::<ApiResponse<serde_json::Value>>()
+        .await?;
+
+    let availability = res.into_result()?.unwrap_or_default();
+
+    let highest_taken_seat = ["held", "sold"]
+        .iter()
+        .filter_map(|state| availability[state].as_array())
+        .flatten()
+        .filter_map(|seat| seat.as_u64())
+        .max();
+
+    Ok(highest_taken_seat.map(|seat| seat as u32))
+}
+
+// =============================================================================================================================
+
+async fn notify_ticket_holders_request(
+    event_id: ObjectId,
+    message: &str,
+    active_only: bool,
+    delivery_key: Option<&str>,
+) -> Result<TicketHoldersNotificationInternalResponse, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .post(format!(
+            "http://tickets-service:8080/api/tickets/events/{}/holders/notifications",
+            event_id.to_hex()
+        ))
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&serde_json::json!({
+            "message": message,
+            "active_only": active_only,
+            "delivery_key": delivery_key
+        }))
+        .send()
+        .await?
+        .json::<ApiResponse<TicketHoldersNotificationInternalResponse>>()
+        .await?;
+
+    res.into_result()?
+        .ok_or_else(|| "The ticket holders could not be notified.".into())
+}
+
+// =============================================================================================================================
+
+async fn ticket_statistics_request(
+    event_ids: &[String],
+) -> Result<Vec<EventTicketStatisticsInternalResponse>, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .post("http://tickets-service:8080/api/tickets/statistics")
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&serde_json::json!({ "event_ids": event_ids }))
+        .send()
+        .await?
+        .json::<ApiResponse<Vec<EventTicketStatisticsInternalResponse>>>()
+        .await?;
+
+    Ok(res.into_result()?.unwrap_or_default())
+}
+
+// =============================================================================================================================
+
+async fn payment_statistics_request(
+    event_ids: &[String],
+) -> Result<Vec<EventPaymentStatisticsInternalResponse>, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .post("http://payments-service:8080/api/payments/statistics")
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&serde_json::json!({ "event_ids": event_ids }))
+        .send()
+        .await?
+        .json::<ApiResponse<Vec<EventPaymentStatisticsInternalResponse>>>()
+        .await?;
+
+    Ok(res.into_result()?.unwrap_or_default())
+}
+
+// =============================================================================================================================
+
+async fn offer_waitlist_seats_request(
+    event_id: ObjectId,
+    seats: u32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .post(format!(
+            "http://tickets-service:8080/api/tickets/events/{}/waitlist/offers",
+            event_id.to_hex()
+        ))
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&serde_json::json!({ "seats": seats }))
+        .send()
+        .await?
+        .json::<ApiResponse<serde_json::Value>>()
+        .await?;
+
+    res.into_result()?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+async fn start_event_cancellation_request(
+    event_id: ObjectId,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .post(format!(
+            "http://tickets-service:8080/api/tickets/events/{}/cancellation",
+            event_id.to_hex()
+        ))
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .send()
+        .await?
+        .json::<ApiResponse<serde_json::Value>>()
+        .await?;
+
+    res.into_result()?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(title: &str, date: &str, timezone: &str) -> Event {
+        serde_json::from_value(serde_json::json!({
+            "title": title,
+            "description": "A fun and exciting outdoor music festival.",
+            "date": date,
+            "capacity": 500,
+            "location": "Central Park",
+            "timezone": timezone,
+            "remaining_seats": 450,
+            "price": 75,
+            "created_at": "2025-06-01T12:00:00Z",
+            "creator_id": ObjectId::new(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn reminder_message_tells_the_delay_in_days_or_hours() {
+        let event = event("Music Festival", "2025-08-15T18:00:00Z", "Europe/Paris");
+
+        for (hours, delay) in [
+            (24, "tomorrow"),
+            (72, "in 3 days"),
+            (36, "in 36 hours"),
+            (1, "in 1 hour"),
+        ] {
+            assert_eq!(
+                reminder_message(&event, hours).unwrap(),
+                format!("Reminder: Music Festival starts {delay}, on 2025-08-15 20:00 CEST.")
+            );
+        }
+    }
+
+    #[test]
+    fn reminder_message_shortens_the_title() {
+        let event = event(&"é".repeat(60), "2025-12-15T18:00:00Z", "America/New_York");
+
+        assert_eq!(
+            reminder_message(&event, 2).unwrap(),
+            format!(
+                "Reminder: {} starts in 2 hours, on 2025-12-15 13:00 EST.",
+                "é".repeat(40)
+            )
+        );
+    }
+
+    #[test]
+    fn reminder_message_rejects_an_unknown_timezone() {
+        let event = event("Music Festival", "2025-08-15T18:00:00Z", "Mars/Olympus");
+
+        assert!(reminder_message(&event, 24).is_err());
+    }
+
+    #[test]
+    fn sell_through_is_a_percentage_rounded_to_two_decimals() {
+        assert_eq!(sell_through(450, 500), 90.0);
+        assert_eq!(sell_through(1, 3), 33.33);
+        assert_eq!(sell_through(2, 3), 66.67);
+        assert_eq!(sell_through(500, 500), 100.0);
+        assert_eq!(sell_through(0, 500), 0.0);
+    }
+
+    #[test]
+    fn sell_through_of_an_event_without_capacity_is_zero() {
+        assert_eq!(sell_through(0, 0), 0.0);
+        assert_eq!(sell_through(3, 0), 0.0);
+    }
+
+    fn review(action: ReviewAction) -> EventReview {
+        EventReview {
+            action,
+            changes: None,
+            reason: None,
+            user_id: ObjectId::new(),
+            created_at: DateTime::now(),
+        }
+    }
+
+    #[test]
+    fn review_history_filter_of_an_event_never_reviewed() {
+        let event = event("Music Festival", "2025-08-15T18:00:00Z", "Europe/Paris");
+
+        assert_eq!(
+            review_history_filter(&event),
+            doc! { "review_history.0": { "$exists": false } }
+        );
+    }
+
+    #[test]
+    fn review_history_filter_pins_the_length_of_the_history() {
+        let mut event = event("Music Festival", "2025-08-15T18:00:00Z", "Europe/Paris");
+        event.review_history = vec![
+            review(ReviewAction::Submitted),
+            review(ReviewAction::Rejected),
+        ];
+
+        assert_eq!(
+            review_history_filter(&event),
+            doc! {
+                "review_history.2": { "$exists": false },
+                "review_history.1": { "$exists": true },
+            }
+        );
+    }
+}
@@ -7,6 +7,11 @@ db.getCollection("auth").createIndex(
   { unique: true }
 );
 db.getCollection("auth").createIndex({ roles: 1 });
+db.getCollection("auth").createIndex({ role: 1 });
+db.getCollection("auth").createIndex(
+  { invitation_token: 1 },
+  { unique: true, sparse: true }
+);
 
 // 2. USERS DATABASE
 db = db.getSiblingDB("users-service");
@@ -14,11 +19,21 @@ db.users.createIndex(
   { email: 1 },
   { unique: true }
 );
+db.users.createIndex({ deleted_at: 1 });
+db.users.createIndex({ last_name: 1, first_name: 1 });
+db.users.createIndex({ first_name: 1 });
+db.terms.createIndex({ version: 1 }, { unique: true });
+db.terms.createIndex({ published_at: -1 });
+db.terms_acceptances.createIndex({ user_id: 1, accepted_at: -1 });
+db.marketing_consents.createIndex({ user_id: 1, recorded_at: -1 });
 
 // 3. NOTIFICATION DATABASE
 db = db.getSiblingDB("notifications-service");
 db.notifications.createIndex({ user_id: 1 });
 db.notifications.createIndex({ status: 1 });
+db.notifications.createIndex({ broadcast_id: 1, status: 1 });
+db.broadcasts.createIndex({ event_id: 1, created_at: -1 });
+db.broadcast_counters.createIndex({ event_id: 1 });
 
 // 4. TICKETS DATABASE
 db = db.getSiblingDB("tickets-service");
@@ -26,9 +41,39 @@ db.tickets.createIndex({ user_id: 1 });
 db.tickets.createIndex({ event_id: 1 });
 db.tickets.createIndex(
   { event_id: 1, seat_number: 1 },
-  { unique: true }
+  {
+    unique: true,
+    partialFilterExpression: {
+      status: { $in: ["Pending", "Active"] },
+      deleted_at: { $type: "null" },
+      seat_number: { $gt: 0 },
+    },
+  }
 );
 db.tickets.createIndex({ user_id: 1, event_id: 1 });
+db.tickets.createIndex({ deleted_at: 1 });
+db.tickets.createIndex({ event_id: 1, status: 1 });
+db.tickets.createIndex({ "admissions.event_id": 1, status: 1 });
+db.event_cancellations.createIndex({ event_id: 1 }, { unique: true });
+db.event_cancellations.createIndex({ status: 1, started_at: 1 });
+db.waitlist_entries.createIndex({ event_id: 1, status: 1, _id: 1 });
+db.pending_handovers.createIndex({ ticket_id: 1, event_id: 1 }, { unique: true });
+db.notification_deliveries.createIndex(
+  { delivery_key: 1, user_id: 1 },
+  { unique: true }
+);
+db.waitlist_entries.createIndex(
+  { event_id: 1, user_id: 1 },
+  {
+    unique: true,
+    partialFilterExpression: { status: { $in: ["Waiting", "Offered"] } },
+  }
+);
+db.waitlist_entries.createIndex({ status: 1, offer_expires_at: 1 });
+db.calendar_feeds.createIndex({ user_id: 1 }, { unique: true });
+db.calendar_feeds.createIndex({ token: 1 }, { unique: true });
+db.purchase_counters.createIndex({ event_id: 1 });
+db.purchase_limit_rejections.createIndex({ event_id: 1, created_at: -1 });
 
 // 5. EVENTS DATABASE
 db = db.getSiblingDB("events-service");
@@ -38,6 +83,39 @@ db.events.createIndex({ capacity: 1 });
 db.events.createIndex({ remaining_seats: 1 });
 db.events.createIndex({ creator_id: 1 });
 db.events.createIndex({ created_at: 1 });
+db.events.createIndex({ deleted_at: 1 });
+db.events.createIndex({ organisation_id: 1 });
+db.events.createIndex(
+  { title: "text", description: "text" },
+  { weights: { title: 5, description: 1 }, name: "events_text_search" }
+);
+db.events.createIndex({ deleted_at: 1, date: 1, _id: 1 });
+db.events.createIndex({ deleted_at: 1, price: 1, _id: 1 });
+db.events.createIndex({ deleted_at: 1, created_at: 1, _id: 1 });
+db.events.createIndex({ deleted_at: 1, title: 1, _id: 1 });
+db.events.createIndex({ deleted_at: 1, location: 1, date: 1 });
+db.events.createIndex({ status: 1, date: 1 });
+db.events.createIndex({ status: 1, publish_at: 1 });
+db.events.createIndex({ venue_id: 1 });
+db.events.createIndex({ series_id: 1, date: 1 });
+db.events.createIndex({ geo_location: "2dsphere", status: 1, date: 1 });
+db.events.createIndex({ category: 1, date: 1 });
+db.events.createIndex({ tags: 1 });
+db.events.createIndex({ city: 1 });
+db.events.createIndex({ review_status: 1, _id: 1 });
+db.organisations.createIndex({ "members.user_id": 1 });
+db.venues.createIndex({ name: 1 });
+db.event_seats.createIndex({ event_id: 1, seat_number: 1 }, { unique: true });
+db.access_codes.createIndex({ event_id: 1, code: 1 }, { unique: true });
+db.access_codes.createIndex({ event_id: 1, presale_id: 1 });
+db.event_series.createIndex({ title: 1 });
+db.event_series.createIndex({ organisation_id: 1 });
+db.categories.createIndex({ slug: 1 }, { unique: true });
+db.categories.createIndex({ parent_id: 1 });
+db.event_reminders.createIndex(
+  { event_id: 1, offset_hours: 1, event_date: 1 },
+  { unique: true }
+);
 
 // 6. PAYMENTS DATABASE
 db = db.getSiblingDB("payments-service");
@@ -46,6 +124,11 @@ db.payments.createIndex({ ticket_id: 1 });
 db.payments.createIndex({ event_id: 1 });
 db.payments.createIndex({ status: 1 });
 db.payments.createIndex({ created_at: 1 });
+db.payments.createIndex({ event_id: 1, status: 1, created_at: 1 });
+db.payments.createIndex({ "event_shares.event_id": 1 });
+db.refunds.createIndex({ payment_id: 1 }, { unique: true });
+db.refunds.createIndex({ event_id: 1 });
+db.refunds.createIndex({ "event_shares.event_id": 1 });
 
 // 7 BACKUPS DATABASE
 db.backups.createIndex({ service_name: 1, date: -1 });
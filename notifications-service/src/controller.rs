@@ -5,14 +5,19 @@ use actix_web::{
 use common::{
     jwt::{external::user_has_any_of_these_roles, internal::authenticate_internal_request},
     models::AuthRole,
-    utils::api_response::{ApiResponse, DocErrorApiResponse, DocSuccessApiResponse},
+    utils::api_response::{
+        ApiResponse, DocErrorApiResponse, DocSuccessApiResponse, TooManyRequestsError,
+    },
 };
 use mongodb::Database;
 use utoipa::OpenApi;
 
 use crate::{
     doc::ApiDoc,
-    model::{CreateNotification, Notification, UpdateNotificationStatus},
+    model::{
+        Broadcast, BroadcastReport, CreateBroadcast, CreateNotification, Notification,
+        UpdateNotificationStatus,
+    },
     service,
 };
 
@@ -24,6 +29,8 @@ pub fn config(cfg: &mut ServiceConfig) {
         .service(get_notifications)
         .service(get_notification_by_id)
         .service(create_notification)
+        .service(create_broadcast)
+        .service(get_event_broadcasts)
         .service(update_notification_status_by_id)
         .service(delete_notification_by_id)
         .service(web::resource("/doc").route(web::get().to(|| async {
@@ -196,6 +203,104 @@ async fn create_notification(
 
 // =============================================================================================================================
 
+#[utoipa::path(
+    post,
+    path = "/api/notifications/broadcasts",
+    tag = "Internal Endpoints",
+    summary = "Broadcast a message to several users",
+    description = "Records a broadcast and queues one notification per recipient. Refused with a 429 when the event already had too many broadcasts in the last hour. This endpoint is called internally by the Tickets Service.",
+    request_body = CreateBroadcast,
+    responses(
+        (status = 200, description = "The broadcast was successfully queued.", body = DocSuccessApiResponse<Broadcast>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 429, description = "Too many broadcasts were sent for this event.", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred during the creation of the broadcast.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/broadcasts")]
+async fn create_broadcast(
+    db: Data<Database>,
+    broadcast: Json<CreateBroadcast>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let broadcast = broadcast.into_inner();
+
+    match service::create_broadcast(&db, broadcast).await {
+        Ok(broadcast) => {
+            let response: ApiResponse<Broadcast> =
+                ApiResponse::success("The broadcast was successfully queued.", Some(broadcast));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<TooManyRequestsError>() => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured during the creation of the broadcast.",
+                e.to_string(),
+            );
+            HttpResponse::TooManyRequests().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured during the creation of the broadcast.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/notifications/broadcasts/events/{event_id}",
+    tag = "Internal Endpoints",
+    summary = "Retrieve the broadcasts of an event",
+    description = "Returns the broadcasts of an event, the most recent first, with the number of their notifications still pending, sent and failed. This endpoint is called internally by the Tickets Service.",
+    responses(
+        (status = 200, description = "The broadcasts were successfully retrieved.", body = DocSuccessApiResponse<Vec<BroadcastReport>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred while retrieving the broadcasts.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[get("/broadcasts/events/{event_id}")]
+async fn get_event_broadcasts(
+    db: Data<Database>,
+    event_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+
+    match service::get_event_broadcasts(&db, event_id).await {
+        Ok(reports) => {
+            let response: ApiResponse<Vec<BroadcastReport>> =
+                ApiResponse::success("The broadcasts were successfully retrieved.", Some(reports));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured while retrieving the broadcasts.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
 #[utoipa::path(
     patch,
     path = "/api/notifications/{id}",
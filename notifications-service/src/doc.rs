@@ -2,12 +2,13 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder};
 use utoipa::{Modify, OpenApi, openapi::security::SecurityScheme};
 
 use crate::controller::{
-    __path_create_notification, __path_delete_notification_by_id, __path_get_notification_by_id,
-    __path_get_notifications, __path_health_check, __path_update_notification_status_by_id,
+    __path_create_broadcast, __path_create_notification, __path_delete_notification_by_id,
+    __path_get_event_broadcasts, __path_get_notification_by_id, __path_get_notifications,
+    __path_health_check, __path_update_notification_status_by_id,
 };
 use crate::model::{
-    CreateNotification, Notification, NotificationStatus, NotificationType,
-    UpdateNotificationStatus,
+    Broadcast, BroadcastReport, CreateBroadcast, CreateNotification, DeliveryStatistics,
+    Notification, NotificationStatus, NotificationType, UpdateNotificationStatus,
 };
 use common::models::AuthRole;
 
@@ -21,6 +22,7 @@ use common::models::AuthRole;
         description = r#"
 The Notifications Service sends confirmations (email or SMS) about ticket purchases or other user-related notifications.
 It provides endpoints for retrieving, creating, updating, and deleting notifications.
+Organisers can broadcast a message to the holders of an event through the Tickets Service, and follow its delivery.
 "#
     ),
     paths(
@@ -28,6 +30,8 @@ It provides endpoints for retrieving, creating, updating, and deleting notificat
         get_notifications,
         get_notification_by_id,
         create_notification,
+        create_broadcast,
+        get_event_broadcasts,
         update_notification_status_by_id,
         delete_notification_by_id
     ),
@@ -38,6 +42,10 @@ It provides endpoints for retrieving, creating, updating, and deleting notificat
             UpdateNotificationStatus,
             NotificationType,
             NotificationStatus,
+            Broadcast,
+            CreateBroadcast,
+            DeliveryStatistics,
+            BroadcastReport,
             AuthRole
         )
     ),
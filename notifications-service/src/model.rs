@@ -56,6 +56,14 @@ pub struct Notification {
     #[serde(rename = "user_id")]
     #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
     pub user_id: ObjectId,
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "6722d3e4f5a6b7c8d9e0f1a2", value_type = Option<String>)]
+    pub broadcast_id: Option<ObjectId>,
 }
 
 // =============================================================================================================================
@@ -95,3 +103,93 @@ pub struct GetUserInternalResponse {
 }
 
 // =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Broadcast {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "6722d3e4f5a6b7c8d9e0f1a2", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "event_id")]
+    #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    #[serde(rename = "sender_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub sender_id: ObjectId,
+
+    #[schema(example = "Doors open at 19:00, please bring an ID.", value_type = String)]
+    pub message: String,
+
+    #[schema(example = 420)]
+    pub recipients: u32,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-08-15T16:00:00.000Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateBroadcast {
+    #[serde(rename = "event_id")]
+    #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(default)]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    #[serde(rename = "sender_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub sender_id: ObjectId,
+
+    #[validate(length(
+        min = 10,
+        max = 100,
+        message = "Message must be between 10 and 100 characters"
+    ))]
+    #[schema(example = "Doors open at 19:00, please bring an ID.", value_type = String)]
+    pub message: String,
+
+    #[validate(length(min = 1, message = "A broadcast needs at least one recipient."))]
+    #[schema(example = json!(["63d88106c3f7903ba0f9211a"]), value_type = Vec<String>)]
+    pub user_ids: Vec<ObjectId>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct DeliveryStatistics {
+    #[schema(example = 120)]
+    pub pending: u32,
+
+    #[schema(example = 296)]
+    pub sent: u32,
+
+    #[schema(example = 4)]
+    pub failed: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BroadcastReport {
+    pub broadcast: Broadcast,
+    pub delivery: DeliveryStatistics,
+}
+
+// =============================================================================================================================
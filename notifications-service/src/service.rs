@@ -1,8 +1,14 @@
-use common::{jwt::internal::encode_internal_jwt, utils::api_response::ApiResponse};
+use common::utils::utils::{
+    BROADCASTS_PER_EVENT_PER_HOUR, NOTIFICATIONS_PER_RUN, is_duplicate_key_error,
+};
+use common::{
+    jwt::internal::encode_internal_jwt,
+    utils::api_response::{ApiResponse, TooManyRequestsError},
+};
 use futures_util::TryStreamExt;
 use mongodb::{
     Collection, Database,
-    bson::{DateTime, doc, oid::ObjectId, to_bson},
+    bson::{DateTime, Document, doc, oid::ObjectId, to_bson},
     options::ReturnDocument,
 };
 use validator::Validate;
@@ -10,14 +16,17 @@ use validator::Validate;
 use crate::{
     email::{SendMail, send_mail},
     model::{
-        CreateNotification, GetUserInternalResponse, Notification, NotificationStatus,
-        NotificationType, UpdateNotificationStatus,
+        Broadcast, BroadcastReport, CreateBroadcast, CreateNotification, DeliveryStatistics,
+        GetUserInternalResponse, Notification, NotificationStatus, NotificationType,
+        UpdateNotificationStatus,
     },
 };
 
 // =============================================================================================================================
 
 const COLLECTION_NAME: &str = "notifications";
+const BROADCASTS_COLLECTION_NAME: &str = "broadcasts";
+const BROADCAST_COUNTERS_COLLECTION_NAME: &str = "broadcast_counters";
 
 // =============================================================================================================================
 
@@ -62,6 +71,7 @@ pub async fn create_notification(
         status: NotificationStatus::Pending,
         created_at: DateTime::now(),
         user_id: notification.user_id,
+        broadcast_id: None,
     };
 
     let collection: Collection<Notification> = db.collection(COLLECTION_NAME);
@@ -114,12 +124,207 @@ pub async fn delete_notification_by_id(
 
 // =============================================================================================================================
 
+pub async fn create_broadcast(
+    db: &Database,
+    broadcast: CreateBroadcast,
+) -> Result<Broadcast, Box<dyn std::error::Error>> {
+    broadcast.validate()?;
+
+    let created_at = DateTime::now();
+    let counter_id = reserve_broadcast_slot(db, broadcast.event_id, created_at).await?;
+
+    let mut user_ids = broadcast.user_ids;
+    user_ids.sort();
+    user_ids.dedup();
+
+    let mut created = Broadcast {
+        id: None,
+        event_id: broadcast.event_id,
+        ticket_type_id: broadcast.ticket_type_id,
+        sender_id: broadcast.sender_id,
+        message: broadcast.message,
+        recipients: user_ids.len() as u32,
+        created_at,
+    };
+    let broadcasts: Collection<Broadcast> = db.collection(BROADCASTS_COLLECTION_NAME);
+    match broadcasts.insert_one(&created).await {
+        Ok(res) => created.id = res.inserted_id.as_object_id(),
+        Err(e) => {
+            release_broadcast_slot(db, &counter_id).await;
+            return Err(e.into());
+        }
+    }
+
+    // The notifications are queued like any other, the mail cron job delivers them at its own pace.
+    let notifications: Vec<Notification> = user_ids
+        .into_iter()
+        .map(|user_id| Notification {
+            id: None,
+            message: created.message.clone(),
+            notif_type: NotificationType::Email,
+            status: NotificationStatus::Pending,
+            created_at: created.created_at,
+            user_id,
+            broadcast_id: created.id,
+        })
+        .collect();
+    let collection: Collection<Notification> = db.collection(COLLECTION_NAME);
+    if let Err(e) = collection.insert_many(notifications).await {
+        // A broadcast without its notifications would never be delivered, so it is withdrawn and can be sent again.
+        if let Some(broadcast_id) = created.id {
+            if let Err(e) = collection
+                .delete_many(doc! { "broadcast_id": broadcast_id })
+                .await
+            {
+                eprintln!(
+                    "Failed to delete the notifications of a failed broadcast: {}",
+                    e
+                );
+            }
+            if let Err(e) = broadcasts.delete_one(doc! { "_id": broadcast_id }).await {
+                eprintln!(
+                    "Failed to delete a broadcast left without notifications: {}",
+                    e
+                );
+            }
+        }
+        release_broadcast_slot(db, &counter_id).await;
+        return Err(e.into());
+    }
+
+    Ok(created)
+}
+
+// =============================================================================================================================
+
+// The broadcasts of an event are counted per hour by a single conditional upsert, so concurrent broadcasts
+// cannot go beyond the limit. Once it is reached the filter misses the counter and the upsert collides with it.
+async fn reserve_broadcast_slot(
+    db: &Database,
+    event_id: ObjectId,
+    now: DateTime,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let counter_id = format!(
+        "{}:{}",
+        event_id.to_hex(),
+        now.to_chrono().format("%Y-%m-%dT%H")
+    );
+    let filter = doc! { "_id": &counter_id, "count": { "$lt": *BROADCASTS_PER_EVENT_PER_HOUR } };
+    let update = doc! {
+        "$inc": { "count": 1 },
+        "$setOnInsert": { "event_id": event_id },
+    };
+
+    let collection: Collection<Document> = db.collection(BROADCAST_COUNTERS_COLLECTION_NAME);
+    let reserved = match collection
+        .update_one(filter.clone(), update.clone())
+        .upsert(true)
+        .await
+    {
+        Ok(_) => true,
+        // Two first broadcasts of the hour can race to create the counter, the loser retries on the existing one.
+        Err(e) if is_duplicate_key_error(&e) => {
+            collection.update_one(filter, update).await?.modified_count == 1
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    if !reserved {
+        return Err(Box::new(TooManyRequestsError(format!(
+            "Only {} broadcasts can be sent per event and per hour.",
+            *BROADCASTS_PER_EVENT_PER_HOUR
+        ))));
+    }
+
+    Ok(counter_id)
+}
+
+// =============================================================================================================================
+
+async fn release_broadcast_slot(db: &Database, counter_id: &str) {
+    let collection: Collection<Document> = db.collection(BROADCAST_COUNTERS_COLLECTION_NAME);
+    if let Err(e) = collection
+        .update_one(
+            doc! { "_id": counter_id, "count": { "$gte": 1 } },
+            doc! { "$inc": { "count": -1 } },
+        )
+        .await
+    {
+        eprintln!("Failed to release the broadcast slot {}: {}", counter_id, e);
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_broadcasts(
+    db: &Database,
+    event_id: String,
+) -> Result<Vec<BroadcastReport>, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+
+    let broadcasts: Vec<Broadcast> = db
+        .collection::<Broadcast>(BROADCASTS_COLLECTION_NAME)
+        .find(doc! { "event_id": event_id })
+        .sort(doc! { "created_at": -1 })
+        .await?
+        .try_collect()
+        .await?;
+    let broadcast_ids: Vec<ObjectId> = broadcasts.iter().filter_map(|b| b.id).collect();
+
+    let collection: Collection<Notification> = db.collection(COLLECTION_NAME);
+    let mut cursor = collection
+        .aggregate(vec![
+            doc! { "$match": { "broadcast_id": { "$in": broadcast_ids } } },
+            doc! {
+                "$group": {
+                    "_id": { "broadcast_id": "$broadcast_id", "status": "$status" },
+                    "count": { "$sum": 1 }
+                }
+            },
+        ])
+        .await?;
+
+    let mut reports: Vec<BroadcastReport> = broadcasts
+        .into_iter()
+        .map(|broadcast| BroadcastReport {
+            broadcast,
+            delivery: DeliveryStatistics::default(),
+        })
+        .collect();
+    while let Some(group) = cursor.try_next().await? {
+        let key = group.get_document("_id")?;
+        let broadcast_id = key.get_object_id("broadcast_id")?;
+        let count = group.get_i32("count")? as u32;
+
+        let Some(report) = reports
+            .iter_mut()
+            .find(|report| report.broadcast.id == Some(broadcast_id))
+        else {
+            continue;
+        };
+        match key.get_str("status")? {
+            "Pending" => report.delivery.pending += count,
+            "Sent" => report.delivery.sent += count,
+            _ => report.delivery.failed += count,
+        }
+    }
+
+    Ok(reports)
+}
+
+// =============================================================================================================================
+
 pub async fn check_notifications_and_try_to_send_mail(
     db: &Database,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let collection: Collection<Notification> = db.collection(COLLECTION_NAME);
     let status_bson = to_bson(&NotificationStatus::Pending).unwrap();
-    let cursor = collection.find(doc! { "status": status_bson }).await?;
+    // Only a batch is sent per run, so a large broadcast is spread out instead of flooding the mail server.
+    let cursor = collection
+        .find(doc! { "status": status_bson })
+        .sort(doc! { "_id": 1 })
+        .limit(*NOTIFICATIONS_PER_RUN)
+        .await?;
     let pending_notifications: Vec<Notification> = cursor.try_collect().await?;
 
     let client = reqwest::Client::new();
@@ -15,7 +15,10 @@ use utoipa::OpenApi;
 
 use crate::{
     doc::ApiDoc,
-    model::{CreatePaymentRequest, Payment, UpdatePaymentStatusByIdRequest},
+    model::{
+        CreatePaymentRequest, CreateRefundRequest, EventPaymentStatistics, Payment,
+        PaymentStatisticsRequest, UpdatePaymentStatusByIdRequest,
+    },
     service,
 };
 
@@ -25,8 +28,11 @@ pub fn config(cfg: &mut ServiceConfig) {
     let scope = web::scope("/api/payments")
         .service(health_check)
         .service(get_payments)
+        .service(get_payments_by_event_id)
+        .service(get_payment_statistics)
         .service(get_payment_by_id)
         .service(create_payment)
+        .service(refund_payment_by_ticket_id)
         .service(update_payment_status_by_id)
         .service(delete_payment_by_id)
         .service(web::resource("/doc").route(web::get().to(|| async {
@@ -107,7 +113,7 @@ async fn get_payments(db: Data<Database>, req: HttpRequest) -> impl Responder {
     path = "/api/payments/{id}",
     tag = "Protected Endpoints",
     summary = "Retrieve a payment by ID",
-    description = "Fetches the details of a specific payment by its ID. Access is restricted to authenticated users.",
+    description = "Fetches the details of a specific payment by its ID. Access is restricted to Admin users, the owner of the payment and the members of the organisation running the event.",
     responses(
         (status = 200, description = "Payment was successfully retrieved.", body = DocSuccessApiResponse<Payment>),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
@@ -191,6 +197,48 @@ async fn create_payment(
 
 // =============================================================================================================================
 
+#[utoipa::path(
+    post,
+    path = "/api/payments/refunds",
+    tag = "Internal Endpoints",
+    summary = "Refund the payment of a ticket",
+    description = "Refunds the captured payment of a ticket and records the refund, or cancels the payment when it was not captured yet. Payments that were already refunded, cancelled or failed are returned unchanged. This endpoint is intended for internal use.",
+    request_body = CreateRefundRequest,
+    responses(
+        (status = 200, description = "Payment was successfully refunded.", body = DocSuccessApiResponse<Payment>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred during the payment refund.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/refunds")]
+async fn refund_payment_by_ticket_id(
+    db: Data<Database>,
+    refund_data: Json<CreateRefundRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(jwt_payload) => jwt_payload,
+        Err(err_res) => return err_res,
+    };
+
+    let refund_data = refund_data.into_inner();
+
+    match service::refund_payment_by_ticket_id(&db, refund_data).await {
+        Ok(payment) => {
+            let response: ApiResponse<Payment> =
+                ApiResponse::success("Payment was successfully refunded.", Some(payment));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("An error occured during the payment refund.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
 #[utoipa::path(
     patch,
     path = "/api/payments/{id}",
@@ -285,3 +333,94 @@ async fn delete_payment_by_id(
 }
 
 // =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/payments/events/{event_id}",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the payments of an event",
+    description = "Fetches every payment made for an event, including the payments of the passes that include it. Access is restricted to Admin users and the members of the organisation running the event.",
+    responses(
+        (status = 200, description = "Payments were successfully retrieved.", body = DocSuccessApiResponse<Vec<Payment>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred while trying to get the payments of the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[get("/events/{event_id}")]
+async fn get_payments_by_event_id(
+    db: Data<Database>,
+    event_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload: ExternalClaims = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+    let event_id = event_id.into_inner();
+
+    match service::get_payments_by_event_id(&db, event_id, jwt_payload).await {
+        Ok(payments) => {
+            let response: ApiResponse<Vec<Payment>> =
+                ApiResponse::success("Payments were successfully retrieved.", Some(payments));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured while trying to get the payments of the event.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/payments/statistics",
+    tag = "Internal Endpoints",
+    summary = "Aggregate the payments of events",
+    description = "Returns, for each given event, its revenue by currency and by day from the captured payments, and the amount and number of its refunds. Called by the Events Service to build the sales statistics of organisers. This endpoint is intended for internal use.",
+    request_body = PaymentStatisticsRequest,
+    responses(
+        (status = 200, description = "Payment statistics were successfully retrieved.", body = DocSuccessApiResponse<Vec<EventPaymentStatistics>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred while trying to get the payment statistics.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/statistics")]
+async fn get_payment_statistics(
+    db: Data<Database>,
+    request: Json<PaymentStatisticsRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(jwt_payload) => jwt_payload,
+        Err(err_res) => return err_res,
+    };
+
+    let request = request.into_inner();
+
+    match service::get_payment_statistics(&db, request).await {
+        Ok(statistics) => {
+            let response: ApiResponse<Vec<EventPaymentStatistics>> = ApiResponse::success(
+                "Payment statistics were successfully retrieved.",
+                Some(statistics),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured while trying to get the payment statistics.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
@@ -3,9 +3,14 @@ use utoipa::{Modify, OpenApi, openapi::security::SecurityScheme};
 
 use crate::controller::{
     __path_create_payment, __path_delete_payment_by_id, __path_get_payment_by_id,
-    __path_get_payments, __path_health_check, __path_update_payment_status_by_id,
+    __path_get_payment_statistics, __path_get_payments, __path_get_payments_by_event_id,
+    __path_health_check, __path_refund_payment_by_ticket_id, __path_update_payment_status_by_id,
+};
+use crate::model::{
+    CreateEventShareRequest, CreatePaymentRequest, CreateRefundRequest, CurrencyAmount,
+    DailyRevenue, EventPaymentStatistics, EventShare, Payment, PaymentStatisticsRequest,
+    PaymentStatus, Refund, UpdatePaymentStatusByIdRequest,
 };
-use crate::model::{CreatePaymentRequest, Payment, PaymentStatus, UpdatePaymentStatusByIdRequest};
 use common::models::AuthRole;
 
 // =============================================================================================================================
@@ -17,7 +22,7 @@ use common::models::AuthRole;
         version = "1.0.0",
         description = r#"
 The Payments Service handles credit card payments.
-It provides endpoints for retrieving, creating, updating, and deleting payment records.
+It provides endpoints for retrieving, creating, updating, and deleting payment records, as well as refunding them.
 "#
     ),
     paths(
@@ -26,14 +31,25 @@ It provides endpoints for retrieving, creating, updating, and deleting payment r
         get_payment_by_id,
         create_payment,
         update_payment_status_by_id,
-        delete_payment_by_id
+        delete_payment_by_id,
+        get_payments_by_event_id,
+        refund_payment_by_ticket_id,
+        get_payment_statistics
     ),
     components(
         schemas(
             Payment,
+            EventShare,
             CreatePaymentRequest,
+            CreateEventShareRequest,
             UpdatePaymentStatusByIdRequest,
+            CreateRefundRequest,
+            Refund,
             PaymentStatus,
+            PaymentStatisticsRequest,
+            EventPaymentStatistics,
+            CurrencyAmount,
+            DailyRevenue,
             AuthRole
         )
     ),
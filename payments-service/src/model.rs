@@ -20,6 +20,8 @@ pub enum PaymentStatus {
     Pending,
     Success,
     Failed,
+    Refunded,
+    Cancelled,
 }
 
 // =============================================================================================================================
@@ -62,6 +64,34 @@ pub struct Payment {
     #[serde(rename = "ticket_id")]
     #[schema(example = "63daf9aefc24646c8d3fb79e", value_type = String)]
     pub ticket_id: ObjectId,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub event_shares: Vec<EventShare>,
+}
+
+// =============================================================================================================================
+
+// The part of a payment earned by each event, when a single payment is for several of them.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventShare {
+    #[serde(rename = "event_id")]
+    #[schema(example = "63da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[schema(example = 50)]
+    pub amount: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateEventShareRequest {
+    #[serde(rename = "event_id")]
+    #[schema(example = "63da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: String,
+
+    #[schema(example = 50)]
+    pub amount: u32,
 }
 
 // =============================================================================================================================
@@ -110,6 +140,9 @@ pub struct CreatePaymentRequest {
     #[serde(rename = "ticket_id")]
     #[schema(example = "63daf9aefc24646c8d3fb79e", value_type = String)]
     pub ticket_id: String,
+
+    #[serde(default)]
+    pub event_shares: Vec<CreateEventShareRequest>,
 }
 
 // =============================================================================================================================
@@ -121,3 +154,127 @@ pub struct UpdatePaymentStatusByIdRequest {
 }
 
 // =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Refund {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "64b2c3d4e5f6a7b8c9d0e1f2", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "payment_id")]
+    #[schema(example = "63f7b1c0a1234567890abcde", value_type = String)]
+    pub payment_id: ObjectId,
+
+    #[schema(example = 150)]
+    pub amount: u32,
+
+    #[schema(example = "USD", value_type = String)]
+    pub currency: PaymentCurrency,
+
+    #[schema(example = "The event was cancelled.")]
+    pub reason: String,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T08:37:10.975Z", value_type = String)]
+    pub created_at: DateTime,
+
+    #[serde(rename = "user_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[serde(rename = "event_id")]
+    #[schema(example = "63da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(rename = "ticket_id")]
+    #[schema(example = "63daf9aefc24646c8d3fb79e", value_type = String)]
+    pub ticket_id: ObjectId,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub event_shares: Vec<EventShare>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateRefundRequest {
+    #[serde(rename = "ticket_id")]
+    #[schema(example = "63daf9aefc24646c8d3fb79e", value_type = String)]
+    pub ticket_id: String,
+
+    #[validate(length(
+        min = 2,
+        max = 200,
+        message = "Reason must be between 2 and 200 characters"
+    ))]
+    #[schema(example = "The event was cancelled.")]
+    pub reason: String,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct PaymentStatisticsRequest {
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Between 1 and 500 events can be requested"
+    ))]
+    #[schema(example = json!(["63da941412d5bd6dbc358950"]))]
+    pub event_ids: Vec<String>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CurrencyAmount {
+    #[schema(example = "Eur", value_type = String)]
+    pub currency: PaymentCurrency,
+
+    #[schema(example = 125000)]
+    pub amount: u64,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DailyRevenue {
+    #[schema(example = "2025-03-23")]
+    pub day: String,
+
+    #[schema(example = "Eur", value_type = String)]
+    pub currency: PaymentCurrency,
+
+    #[schema(example = 4500)]
+    pub amount: u64,
+
+    #[schema(example = 3)]
+    pub payments: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventPaymentStatistics {
+    #[schema(example = "63da941412d5bd6dbc358950")]
+    pub event_id: String,
+
+    pub revenue: Vec<CurrencyAmount>,
+
+    pub revenue_by_day: Vec<DailyRevenue>,
+
+    pub refunded: Vec<CurrencyAmount>,
+
+    #[schema(example = 4)]
+    pub refunds: u32,
+}
+
+// =============================================================================================================================
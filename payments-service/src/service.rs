@@ -1,22 +1,30 @@
 use common::{
     jwt::{external::ExternalClaims, internal::encode_internal_jwt},
-    models::{AuthRole, TriggerNotificationRequest},
-    utils::{api_response::ApiResponse, utils::trigger_notification},
+    models::{AuthRole, PaymentCurrency, TriggerNotificationRequest},
+    utils::{
+        api_response::ApiResponse,
+        utils::{get_event_organiser_role, trigger_notification},
+    },
 };
 use futures_util::TryStreamExt;
 use mongodb::{
     Collection, Database,
-    bson::{DateTime, doc, oid::ObjectId, to_document},
+    bson::{Bson, DateTime, Document, doc, from_bson, oid::ObjectId, to_bson, to_document},
     options::ReturnDocument,
 };
 use std::error::Error;
 use validator::Validate;
 
-use crate::model::{CreatePaymentRequest, Payment, PaymentStatus, UpdatePaymentStatusByIdRequest};
+use crate::model::{
+    CreatePaymentRequest, CreateRefundRequest, CurrencyAmount, DailyRevenue,
+    EventPaymentStatistics, EventShare, Payment, PaymentStatisticsRequest, PaymentStatus, Refund,
+    UpdatePaymentStatusByIdRequest,
+};
 
 // =============================================================================================================================
 
 const COLLECTION_NAME: &str = "payments";
+const REFUNDS_COLLECTION_NAME: &str = "refunds";
 
 // =============================================================================================================================
 
@@ -44,10 +52,15 @@ pub async fn get_payment_by_id(
         None => return Err("No Payment with this id exist".into()),
     };
 
-    let user_id = ObjectId::parse_str(jwt_payload.user_id)?;
+    let user_id = ObjectId::parse_str(&jwt_payload.user_id)?;
 
-    if jwt_payload.role != AuthRole::Admin && user_id != payment.user_id {
-        return Err("You must be an admin or the owner of the payment to access it.".into());
+    if jwt_payload.role != AuthRole::Admin
+        && user_id != payment.user_id
+        && get_event_organiser_role(payment.event_id, &jwt_payload.user_id)
+            .await?
+            .is_none()
+    {
+        return Err("You must be an admin, the owner of the payment or an organiser of its event to access it.".into());
     };
 
     Ok(payment)
@@ -55,6 +68,184 @@ pub async fn get_payment_by_id(
 
 // =============================================================================================================================
 
+pub async fn get_payments_by_event_id(
+    db: &Database,
+    event_id: String,
+    jwt_payload: ExternalClaims,
+) -> Result<Vec<Payment>, Box<dyn Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+
+    if jwt_payload.role != AuthRole::Admin
+        && get_event_organiser_role(event_id, &jwt_payload.user_id)
+            .await?
+            .is_none()
+    {
+        return Err(
+            "You must be an admin or an organiser of the event to access its payments.".into(),
+        );
+    }
+
+    let collection: Collection<Payment> = db.collection(COLLECTION_NAME);
+    // The payment of a pass is shared between its events, so it is listed with each of them.
+    let filter = doc! {
+        "$or": [
+            { "event_id": event_id },
+            { "event_shares.event_id": event_id }
+        ]
+    };
+    let cursor = collection.find(filter).await?;
+    let payments = cursor.try_collect().await?;
+
+    Ok(payments)
+}
+
+// =============================================================================================================================
+
+pub async fn get_payment_statistics(
+    db: &Database,
+    request: PaymentStatisticsRequest,
+) -> Result<Vec<EventPaymentStatistics>, Box<dyn Error>> {
+    request.validate()?;
+
+    let event_ids = request
+        .event_ids
+        .iter()
+        .map(ObjectId::parse_str)
+        .collect::<Result<Vec<ObjectId>, _>>()?;
+    let mut statistics: Vec<EventPaymentStatistics> = event_ids
+        .iter()
+        .map(|event_id| EventPaymentStatistics {
+            event_id: event_id.to_hex(),
+            revenue: vec![],
+            revenue_by_day: vec![],
+            refunded: vec![],
+            refunds: 0,
+        })
+        .collect();
+
+    // Refunded payments leave the revenue, so only the captured payments are summed.
+    let payments: Collection<Payment> = db.collection(COLLECTION_NAME);
+    let mut pipeline = vec![doc! { "$match": { "status": to_bson(&PaymentStatus::Success)? } }];
+    pipeline.extend(event_shares_stages(&event_ids));
+    pipeline.extend([
+        doc! {
+            "$group": {
+                "_id": {
+                    "event_id": "$share.event_id",
+                    "day": { "$substrBytes": ["$created_at", 0, 10] },
+                    "currency": "$currency"
+                },
+                "amount": { "$sum": { "$toLong": "$share.amount" } },
+                "payments": { "$sum": 1 }
+            }
+        },
+        doc! { "$sort": { "_id.day": 1, "_id.currency": 1 } },
+    ]);
+    let mut cursor = payments.aggregate(pipeline).await?;
+    while let Some(group) = cursor.try_next().await? {
+        let key = group.get_document("_id")?;
+        let Some(event) = statistics_of(&mut statistics, key)? else {
+            continue;
+        };
+        let currency = from_bson(key.get("currency").cloned().unwrap_or(Bson::Null))?;
+        let amount = group.get_i64("amount")? as u64;
+
+        add_amount(&mut event.revenue, &currency, amount);
+        event.revenue_by_day.push(DailyRevenue {
+            day: key.get_str("day")?.to_string(),
+            currency,
+            amount,
+            payments: group.get_i32("payments")? as u32,
+        });
+    }
+
+    let refunds: Collection<Refund> = db.collection(REFUNDS_COLLECTION_NAME);
+    let mut pipeline = event_shares_stages(&event_ids);
+    pipeline.extend([
+        doc! {
+            "$group": {
+                "_id": { "event_id": "$share.event_id", "currency": "$currency" },
+                "amount": { "$sum": { "$toLong": "$share.amount" } },
+                "refunds": { "$sum": 1 }
+            }
+        },
+        doc! { "$sort": { "_id.currency": 1 } },
+    ]);
+    let mut cursor = refunds.aggregate(pipeline).await?;
+    while let Some(group) = cursor.try_next().await? {
+        let key = group.get_document("_id")?;
+        let Some(event) = statistics_of(&mut statistics, key)? else {
+            continue;
+        };
+        let currency = from_bson(key.get("currency").cloned().unwrap_or(Bson::Null))?;
+
+        let amount = group.get_i64("amount")? as u64;
+
+        add_amount(&mut event.refunded, &currency, amount);
+        event.refunds += group.get_i32("refunds")? as u32;
+    }
+
+    Ok(statistics)
+}
+
+// =============================================================================================================================
+
+// A payment for several events, such as a pass, counts its share in each of them, the others count as a whole.
+fn event_shares_stages(event_ids: &[ObjectId]) -> Vec<Document> {
+    vec![
+        doc! {
+            "$match": {
+                "$or": [
+                    { "event_id": { "$in": event_ids } },
+                    { "event_shares.event_id": { "$in": event_ids } }
+                ]
+            }
+        },
+        doc! {
+            "$project": {
+                "created_at": 1,
+                "currency": 1,
+                "share": {
+                    "$cond": [
+                        { "$gt": [{ "$size": { "$ifNull": ["$event_shares", []] } }, 0] },
+                        "$event_shares",
+                        [{ "event_id": "$event_id", "amount": "$amount" }]
+                    ]
+                }
+            }
+        },
+        doc! { "$unwind": "$share" },
+        doc! { "$match": { "share.event_id": { "$in": event_ids } } },
+    ]
+}
+
+// =============================================================================================================================
+
+fn statistics_of<'a>(
+    statistics: &'a mut [EventPaymentStatistics],
+    key: &Document,
+) -> Result<Option<&'a mut EventPaymentStatistics>, Box<dyn Error>> {
+    let event_id = key.get_object_id("event_id")?.to_hex();
+
+    Ok(statistics
+        .iter_mut()
+        .find(|event| event.event_id == event_id))
+}
+
+// =============================================================================================================================
+
+fn add_amount(amounts: &mut Vec<CurrencyAmount>, currency: &PaymentCurrency, amount: u64) {
+    match amounts.iter_mut().find(|total| total.currency == *currency) {
+        Some(total) => total.amount += amount,
+        None => amounts.push(CurrencyAmount {
+            currency: currency.clone(),
+            amount,
+        }),
+    }
+}
+
+// =============================================================================================================================
+
 pub async fn create_payment(
     db: &Database,
     payment_data: CreatePaymentRequest,
@@ -65,6 +256,21 @@ pub async fn create_payment(
     let user_id = ObjectId::parse_str(payment_data.user_id)?;
     let ticket_id = ObjectId::parse_str(payment_data.ticket_id)?;
 
+    let event_shares = payment_data
+        .event_shares
+        .iter()
+        .map(|share| {
+            Ok(EventShare {
+                event_id: ObjectId::parse_str(&share.event_id)?,
+                amount: share.amount,
+            })
+        })
+        .collect::<Result<Vec<EventShare>, Box<dyn Error>>>()?;
+    let shared_amount: u64 = event_shares.iter().map(|share| share.amount as u64).sum();
+    if !event_shares.is_empty() && shared_amount != payment_data.amount as u64 {
+        return Err("The event shares must add up to the amount of the payment.".into());
+    }
+
     let mut payment = Payment {
         id: None,
         amount: payment_data.amount,
@@ -74,6 +280,7 @@ pub async fn create_payment(
         event_id,
         user_id,
         ticket_id,
+        event_shares,
     };
 
     let collection: Collection<Payment> = db.collection(COLLECTION_NAME);
@@ -126,6 +333,62 @@ pub async fn delete_payment_by_id(
 
 // =============================================================================================================================
 
+pub async fn refund_payment_by_ticket_id(
+    db: &Database,
+    refund_data: CreateRefundRequest,
+) -> Result<Payment, Box<dyn Error>> {
+    refund_data.validate()?;
+
+    let ticket_id = ObjectId::parse_str(&refund_data.ticket_id)?;
+    let collection: Collection<Payment> = db.collection(COLLECTION_NAME);
+
+    let payment = match collection.find_one(doc! { "ticket_id": ticket_id }).await? {
+        Some(payment) => payment,
+        None => return Err("No payment was found for this ticket.".into()),
+    };
+
+    // A payment that was not captured yet is cancelled instead of refunded.
+    let (current_status, new_status) = match payment.status {
+        PaymentStatus::Success => (PaymentStatus::Success, PaymentStatus::Refunded),
+        PaymentStatus::Pending => (PaymentStatus::Pending, PaymentStatus::Cancelled),
+        _ => return Ok(payment),
+    };
+
+    let payment = match collection
+        .find_one_and_update(
+            doc! { "_id": payment.id, "status": to_bson(&current_status)? },
+            doc! { "$set": { "status": to_bson(&new_status)? } },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(payment) => payment,
+        None => return Err("The payment status was changed concurrently, please retry.".into()),
+    };
+
+    if let PaymentStatus::Refunded = payment.status {
+        let refund = Refund {
+            id: None,
+            payment_id: payment.id.ok_or("The payment has no id.")?,
+            amount: payment.amount,
+            currency: payment.currency.clone(),
+            reason: refund_data.reason,
+            created_at: DateTime::now(),
+            user_id: payment.user_id,
+            event_id: payment.event_id,
+            ticket_id: payment.ticket_id,
+            event_shares: payment.event_shares.clone(),
+        };
+
+        let refunds: Collection<Refund> = db.collection(REFUNDS_COLLECTION_NAME);
+        refunds.insert_one(&refund).await?;
+    }
+
+    Ok(payment)
+}
+
+// =============================================================================================================================
+
 pub async fn process_pending_payments(db: &Database) -> Result<(), Box<dyn Error>> {
     let collection: Collection<Payment> = db.collection("payments");
 
@@ -8,14 +8,24 @@ use common::{
         internal::authenticate_internal_request,
     },
     models::AuthRole,
-    utils::api_response::{ApiResponse, DocErrorApiResponse, DocSuccessApiResponse},
+    utils::api_response::{
+        ApiResponse, BadRequestError, ConflictError, DocErrorApiResponse, DocSuccessApiResponse,
+        NotFoundError, TooManyRequestsError,
+    },
 };
 use mongodb::Database;
 use utoipa::OpenApi;
+use validator::ValidationErrors;
 
 use crate::{
     doc::ApiDoc,
-    model::{CreateTicketRequest, Ticket, UpdateTicketSeatNumberByIdRequest},
+    model::{
+        Broadcast, BroadcastReport, BroadcastRequest, CalendarFeedUrl, CreatePassTicketRequest,
+        CreateTicketRequest, EventCancellation, EventTicketStatistics, JoinWaitlistRequest,
+        NotifyTicketHoldersRequest, OfferWaitlistSeatsRequest, PurchaseLimitRejection,
+        SeatAvailability, Ticket, TicketHoldersNotification, TicketStatisticsRequest,
+        UpdateTicketSeatNumberByIdRequest, WaitlistEntry, WaitlistPosition, WaitlistSeatsOffer,
+    },
     service,
 };
 
@@ -33,13 +43,34 @@ pub fn config(cfg: &mut web::ServiceConfig) {
         .service(web::scope("/doc").service(
             utoipa_swagger_ui::SwaggerUi::new("{_:.*}").url("openapi.json", ApiDoc::openapi()),
         ))
+        .service(get_deleted_tickets)
+        .service(get_tickets_by_event_id)
+        .service(get_seat_availability)
+        .service(get_event_cancellation)
+        .service(start_event_cancellation)
+        .service(notify_ticket_holders)
+        .service(broadcast_to_ticket_holders)
+        .service(get_event_broadcasts)
+        .service(get_ticket_statistics)
+        .service(create_calendar_feed)
+        .service(delete_calendar_feed)
+        .service(get_calendar_feed)
+        .service(get_event_waitlist)
+        .service(get_waitlist_position)
+        .service(join_waitlist)
+        .service(leave_waitlist)
+        .service(offer_waitlist_seats)
+        .service(get_purchase_limit_rejections)
         .service(get_ticket_by_id)
         .service(create_ticket)
+        .service(create_pass_ticket)
+        .service(check_in_admission)
         .service(update_ticket_seat_number_by_id)
         .service(active_ticket_by_id)
         .service(cancel_ticket_by_id)
         .service(refund_ticket_by_id)
-        .service(delete_ticket_by_id);
+        .service(delete_ticket_by_id)
+        .service(restore_ticket_by_id);
 
     cfg.service(scope);
 }
@@ -151,11 +182,13 @@ async fn get_ticket_by_id(
     path = "/api/tickets",
     tag = "Protected Endpoints",
     summary = "Create a new ticket",
-    description = "Creates a new ticket for an event. Requires the user to be authenticated.",
+    description = "Creates a new ticket for an event. Requires the user to be authenticated. The seat is reserved atomically on the event: the purchase is refused with a 409 when the event or the ticket type is sold out, when the seat is already taken, or when a purchase limit per user, payment card or household is reached.",
     request_body = CreateTicketRequest,
     responses(
         (status = 200, description = "The ticket was successfully created.", body = DocSuccessApiResponse<Ticket>),
+        (status = 400, description = "The seat does not exist, seats are numbered from 1 to the capacity of the event.", body = DocErrorApiResponse),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "No seat is left, the seat is already taken or a purchase limit is reached.", body = DocErrorApiResponse),
         (status = 500, description = "Failed to create the ticket.", body = DocErrorApiResponse)
     )
 )]
@@ -178,6 +211,16 @@ async fn create_ticket(
                 ApiResponse::success("The ticket was successfully created.", Some(ticket));
             HttpResponse::Ok().json(response)
         }
+        Err(e) if e.is::<BadRequestError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to create the ticket.", e.to_string());
+            HttpResponse::BadRequest().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to create the ticket.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
         Err(e) => {
             let response: ApiResponse<()> =
                 ApiResponse::error("Failed to create the ticket.", e.to_string());
@@ -188,6 +231,113 @@ async fn create_ticket(
 
 // =============================================================================================================================
 
+#[utoipa::path(
+    post,
+    path = "/api/tickets/passes",
+    tag = "Protected Endpoints",
+    summary = "Buy a pass",
+    description = "Buys a pass granting entry to a set of events with a single payment. Requires the user to be authenticated. A seat is reserved on each event of the pass, and the purchase is refused with a 409 when one of them, or one of the ticket types of the pass, is sold out. The pass is a single ticket whose admissions hold the check-in state of each event.",
+    request_body = CreatePassTicketRequest,
+    responses(
+        (status = 200, description = "The pass was successfully bought.", body = DocSuccessApiResponse<Ticket>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "One of the events of the pass is sold out.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to buy the pass.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/passes")]
+async fn create_pass_ticket(
+    db: Data<Database>,
+    pass_data: Json<CreatePassTicketRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let pass_data = pass_data.into_inner();
+
+    match service::create_pass_ticket(&db, pass_data).await {
+        Ok(ticket) => {
+            let response: ApiResponse<Ticket> =
+                ApiResponse::success("The pass was successfully bought.", Some(ticket));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to buy the pass.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to buy the pass.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/tickets/{ticket_id}/admissions/{event_id}/check-in",
+    tag = "Protected Endpoints",
+    summary = "Check in a pass for one of its events",
+    description = "Records the entry of an active pass holder to one of the events of the pass. Each admission can only be checked in once, a second check-in is refused with a 409. Accessible by administrators, operators and the owners, managers and box office members of the organisation running the event.",
+    responses(
+        (status = 200, description = "The pass was successfully checked in.", body = DocSuccessApiResponse<Ticket>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "The pass was already checked in for this event.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to check in the pass.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("ticket_id" = String, Path, description = "Ticket ID of the pass"),
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[patch("/{ticket_id}/admissions/{event_id}/check-in")]
+async fn check_in_admission(
+    db: Data<Database>,
+    path: Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let (ticket_id, event_id) = path.into_inner();
+
+    match service::check_in_admission(
+        &db,
+        ticket_id,
+        event_id,
+        jwt_payload.role,
+        jwt_payload.user_id,
+    )
+    .await
+    {
+        Ok(ticket) => {
+            let response: ApiResponse<Ticket> =
+                ApiResponse::success("The pass was successfully checked in.", Some(ticket));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to check in the pass.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to check in the pass.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
 #[utoipa::path(
     patch,
     path = "/api/tickets/{ticket_id}/seat",
@@ -197,7 +347,9 @@ async fn create_ticket(
     request_body = UpdateTicketSeatNumberByIdRequest,
     responses(
         (status = 200, description = "The ticket seat number was successfully updated.", body = DocSuccessApiResponse<Ticket>),
+        (status = 400, description = "The seat does not exist, seats are numbered from 1 to the capacity of the event.", body = DocErrorApiResponse),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "The seat is already taken.", body = DocErrorApiResponse),
         (status = 500, description = "Failed to update the ticket seat number.", body = DocErrorApiResponse)
     ),
     params(
@@ -235,6 +387,16 @@ async fn update_ticket_seat_number_by_id(
             );
             HttpResponse::Ok().json(response)
         }
+        Err(e) if e.is::<BadRequestError>() || e.is::<ValidationErrors>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the ticket seat number.", e.to_string());
+            HttpResponse::BadRequest().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the ticket seat number.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
         Err(e) => {
             let response: ApiResponse<()> =
                 ApiResponse::error("Failed to update the ticket seat number.", e.to_string());
@@ -291,7 +453,7 @@ async fn active_ticket_by_id(
     path = "/api/tickets/{ticket_id}/cancel",
     tag = "Protected Endpoints",
     summary = "Cancel a ticket",
-    description = "Cancels a ticket by updating its status to Cancelled. Accessible by the ticket owner or an administrator.",
+    description = "Cancels a ticket by updating its status to Cancelled. Accessible by the ticket owner, an administrator or the owners, managers and box office members of the organisation running the event.",
     responses(
         (status = 200, description = "The ticket was successfully cancelled.", body = DocSuccessApiResponse<Ticket>),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
@@ -336,7 +498,7 @@ async fn cancel_ticket_by_id(
     path = "/api/tickets/{ticket_id}/refund",
     tag = "Protected Endpoints",
     summary = "Refund a ticket",
-    description = "Refunds a ticket by updating its status to Refunded. Accessible by the ticket owner or an administrator.",
+    description = "Refunds a ticket by updating its status to Refunded. Accessible by the ticket owner, an administrator or the owners, managers and box office members of the organisation running the event.",
     responses(
         (status = 200, description = "The ticket was successfully refunded.", body = DocSuccessApiResponse<Ticket>),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
@@ -381,7 +543,7 @@ async fn refund_ticket_by_id(
     path = "/api/tickets/{ticket_id}",
     tag = "Protected Endpoints",
     summary = "Delete a ticket",
-    description = "Permanently deletes a ticket specified by its ID. This action is restricted to administrators.",
+    description = "Soft deletes a ticket specified by its ID. The ticket is hidden from every other endpoint and permanently purged once the retention period is over. This action is restricted to administrators.",
     responses(
         (status = 200, description = "The ticket was successfully deleted.", body = DocSuccessApiResponse<Ticket>),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
@@ -398,14 +560,14 @@ async fn delete_ticket_by_id(
     req: HttpRequest,
 ) -> impl Responder {
     let required_roles = &[AuthRole::Admin];
-    match user_has_any_of_these_roles(&req, required_roles) {
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
         Ok(claims) => claims,
         Err(err_res) => return err_res,
     };
 
     let ticket_id = ticket_id.into_inner();
 
-    match service::delete_ticket_by_id(&db, ticket_id).await {
+    match service::delete_ticket_by_id(&db, ticket_id, jwt_payload.user_id).await {
         Ok(ticket) => {
             let response: ApiResponse<Ticket> =
                 ApiResponse::success("The ticket was successfully deleted.", Some(ticket));
@@ -420,3 +582,885 @@ async fn delete_ticket_by_id(
 }
 
 // =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/tickets/deleted",
+    tag = "Protected Endpoints",
+    summary = "Retrieve deleted tickets",
+    description = "Fetches the list of soft deleted tickets that have not been purged yet. This action is restricted to administrators.",
+    responses(
+        (status = 200, description = "Deleted tickets were successfully retrieved.", body = DocSuccessApiResponse<Vec<Ticket>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve deleted tickets.", body = DocErrorApiResponse)
+    )
+)]
+#[get("/deleted")]
+async fn get_deleted_tickets(db: Data<Database>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    match service::get_deleted_tickets(&db).await {
+        Ok(tickets) => {
+            let response: ApiResponse<Vec<Ticket>> = ApiResponse::success(
+                "Deleted tickets were successfully retrieved.",
+                Some(tickets),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve deleted tickets.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/tickets/{ticket_id}/restore",
+    tag = "Protected Endpoints",
+    summary = "Restore a deleted ticket",
+    description = "Restores a soft deleted ticket specified by its ID. This action is restricted to administrators.",
+    responses(
+        (status = 200, description = "The ticket was successfully restored.", body = DocSuccessApiResponse<Ticket>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "The seat of the ticket is no longer available.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to restore the ticket.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("ticket_id" = String, Path, description = "Ticket ID")
+    )
+)]
+#[patch("/{ticket_id}/restore")]
+async fn restore_ticket_by_id(
+    db: Data<Database>,
+    ticket_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let ticket_id = ticket_id.into_inner();
+
+    match service::restore_ticket_by_id(&db, ticket_id).await {
+        Ok(ticket) => {
+            let response: ApiResponse<Ticket> =
+                ApiResponse::success("The ticket was successfully restored.", Some(ticket));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to restore the ticket.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to restore the ticket.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/tickets/events/{event_id}",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the tickets of an event",
+    description = "Fetches every ticket sold for an event. Accessible by Admin and Operator users and by the members of the organisation running the event.",
+    responses(
+        (status = 200, description = "Tickets were successfully retrieved.", body = DocSuccessApiResponse<Vec<Ticket>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the tickets of the event.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[get("/events/{event_id}")]
+async fn get_tickets_by_event_id(
+    db: Data<Database>,
+    event_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+
+    match service::get_tickets_by_event_id(&db, event_id, jwt_payload.role, jwt_payload.user_id)
+        .await
+    {
+        Ok(tickets) => {
+            let response: ApiResponse<Vec<Ticket>> =
+                ApiResponse::success("Tickets were successfully retrieved.", Some(tickets));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "Failed to retrieve the tickets of the event.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/tickets/events/{event_id}/seats",
+    tag = "Public Endpoints",
+    summary = "Retrieve the seat availability of an event",
+    description = "Returns the ranges of seats of an event that are held by a ticket awaiting its payment, sold or blocked. Every other seat from 1 to the capacity is available. Seats are blocked when no ticket type on sale covers them. When the event is not on sale, `on_sale` is false and no seat is listed nor available. The availability is rebuilt at most every couple of seconds and comes with an `ETag`: polling clients sending it back in `If-None-Match` get a 304 while it has not changed.",
+    responses(
+        (status = 200, description = "Seat availability was successfully retrieved.", body = DocSuccessApiResponse<SeatAvailability>),
+        (status = 304, description = "Seat availability has not changed since the given ETag."),
+        (status = 500, description = "Failed to retrieve the seat availability.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/events/{event_id}/seats")]
+async fn get_seat_availability(
+    req: HttpRequest,
+    db: Data<Database>,
+    event_id: Path<String>,
+) -> impl Responder {
+    match service::get_seat_availability(&db, event_id.into_inner()).await {
+        Ok(availability) => {
+            let etag = service::seat_availability_etag(&availability);
+            let not_modified = req
+                .headers()
+                .get("If-None-Match")
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.split(',').any(|tag| tag.trim() == etag));
+            if not_modified {
+                return HttpResponse::NotModified()
+                    .insert_header(("ETag", etag))
+                    .insert_header(("Cache-Control", "public, max-age=2"))
+                    .finish();
+            }
+
+            let response: ApiResponse<SeatAvailability> = ApiResponse::success(
+                "Seat availability was successfully retrieved.",
+                Some(availability),
+            );
+            HttpResponse::Ok()
+                .insert_header(("ETag", etag))
+                .insert_header(("Cache-Control", "public, max-age=2"))
+                .json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the seat availability.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/tickets/events/{event_id}/cancellation",
+    tag = "Protected Endpoints",
+    summary = "Follow the cancellation of an event",
+    description = "Returns the progress of the refunds of a cancelled event: the number of tickets to refund, the number already refunded and the tickets that could not be refunded. Accessible by Admin and Operator users and by the members of the organisation running the event.",
+    responses(
+        (status = 200, description = "Event cancellation was successfully retrieved.", body = DocSuccessApiResponse<EventCancellation>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the event cancellation.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[get("/events/{event_id}/cancellation")]
+async fn get_event_cancellation(
+    db: Data<Database>,
+    event_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+
+    match service::get_event_cancellation(&db, event_id, jwt_payload.role, jwt_payload.user_id)
+        .await
+    {
+        Ok(cancellation) => {
+            let response: ApiResponse<EventCancellation> = ApiResponse::success(
+                "Event cancellation was successfully retrieved.",
+                Some(cancellation),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the event cancellation.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/tickets/events/{event_id}/cancellation",
+    tag = "Protected Endpoints",
+    summary = "Refund the tickets of a cancelled event",
+    description = "Queues the background refund of every pending and active ticket of a cancelled event. Each ticket is marked as Refunded, its payment is refunded and its holder is notified. Called by the Events Service when an event is cancelled, it can be called again by Admin users or the owners and managers of the event to retry the tickets that failed.",
+    responses(
+        (status = 200, description = "Event cancellation was successfully started.", body = DocSuccessApiResponse<EventCancellation>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to start the event cancellation.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[post("/events/{event_id}/cancellation")]
+async fn start_event_cancellation(
+    db: Data<Database>,
+    event_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let requested_by = match authenticate_internal_request(&req) {
+        Ok(_) => None,
+        Err(_) => {
+            let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+            match user_has_any_of_these_roles(&req, required_roles) {
+                Ok(claims) => Some((claims.user_id, claims.role)),
+                Err(err_res) => return err_res,
+            }
+        }
+    };
+
+    let event_id = event_id.into_inner();
+
+    match service::start_event_cancellation(&db, event_id, requested_by).await {
+        Ok(cancellation) => {
+            let response: ApiResponse<EventCancellation> = ApiResponse::success(
+                "Event cancellation was successfully started.",
+                Some(cancellation),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to start the event cancellation.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+#[utoipa::path(
+    post,
+    path = "/api/tickets/events/{event_id}/holders/notifications",
+    tag = "Internal Endpoints",
+    summary = "Notify the ticket holders of an event",
+    description = "Sends the given message to every user holding a pending or active ticket of the event, or only an active one with active_only, and returns the number of recipients. The message is sent in the background, unless a delivery_key is given: the holders already notified with this key are then skipped, the others are notified before the response, which also returns the number of failed notifications. Called by the Events Service when the date or the location of an event changes, and to remind the holders of an upcoming event. This endpoint is for internal use only.",
+    request_body = NotifyTicketHoldersRequest,
+    responses(
+        (status = 200, description = "Ticket holders are being notified.", body = DocSuccessApiResponse<TicketHoldersNotification>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to notify the ticket holders.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[post("/events/{event_id}/holders/notifications")]
+async fn notify_ticket_holders(
+    db: Data<Database>,
+    event_id: Path<String>,
+    payload: Json<NotifyTicketHoldersRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::notify_ticket_holders(&db, event_id, payload).await {
+        Ok(notification) => {
+            let response: ApiResponse<TicketHoldersNotification> =
+                ApiResponse::success("Ticket holders are being notified.", Some(notification));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to notify the ticket holders.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/tickets/events/{event_id}/broadcasts",
+    tag = "Protected Endpoints",
+    summary = "Broadcast a message to the ticket holders of an event",
+    description = "Sends a message, such as \"Doors open at 19:00\", to every user holding an Active ticket of the event, optionally only of one ticket type. The notifications are delivered in the background. Each event can only send a limited number of broadcasts per hour, further ones are refused with a 429. Access is restricted to Admin users and to the owners and managers of the organisation running the event.",
+    request_body = BroadcastRequest,
+    responses(
+        (status = 200, description = "The broadcast was successfully queued.", body = DocSuccessApiResponse<Broadcast>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 429, description = "Too many broadcasts were sent for this event.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to broadcast the message.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[post("/events/{event_id}/broadcasts")]
+async fn broadcast_to_ticket_holders(
+    event_id: Path<String>,
+    payload: Json<BroadcastRequest>,
+    db: Data<Database>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::broadcast_to_ticket_holders(
+        &db,
+        event_id,
+        payload,
+        jwt_payload.role,
+        jwt_payload.user_id,
+    )
+    .await
+    {
+        Ok(broadcast) => {
+            let response: ApiResponse<Broadcast> =
+                ApiResponse::success("The broadcast was successfully queued.", Some(broadcast));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<TooManyRequestsError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to broadcast the message.", e.to_string());
+            HttpResponse::TooManyRequests().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to broadcast the message.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/tickets/events/{event_id}/broadcasts",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the broadcasts of an event",
+    description = "Returns the broadcasts of an event, the most recent first, with the number of their notifications still pending, sent and failed. Accessible by Admin and Operator users and by the members of the organisation running the event.",
+    responses(
+        (status = 200, description = "Broadcasts were successfully retrieved.", body = DocSuccessApiResponse<Vec<BroadcastReport>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the broadcasts.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[get("/events/{event_id}/broadcasts")]
+async fn get_event_broadcasts(event_id: Path<String>, req: HttpRequest) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+
+    match service::get_event_broadcasts(event_id, jwt_payload.role, jwt_payload.user_id).await {
+        Ok(reports) => {
+            let response: ApiResponse<Vec<BroadcastReport>> =
+                ApiResponse::success("Broadcasts were successfully retrieved.", Some(reports));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the broadcasts.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/tickets/events/{event_id}/waitlist",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the waitlist of an event",
+    description = "Returns the users waiting for a seat of a sold-out event, in the order they will be served, and the users a seat is currently held for. Accessible by Admin and Operator users and by the members of the organisation running the event.",
+    responses(
+        (status = 200, description = "Waitlist was successfully retrieved.", body = DocSuccessApiResponse<Vec<WaitlistEntry>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the waitlist.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[get("/events/{event_id}/waitlist")]
+async fn get_event_waitlist(
+    db: Data<Database>,
+    event_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+
+    match service::get_event_waitlist(&db, event_id, jwt_payload.role, jwt_payload.user_id).await {
+        Ok(entries) => {
+            let response: ApiResponse<Vec<WaitlistEntry>> =
+                ApiResponse::success("Waitlist was successfully retrieved.", Some(entries));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the waitlist.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/tickets/events/{event_id}/purchase-limit-rejections",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the refused purchases of an event",
+    description = "Returns the purchases of an event refused because a purchase limit per user, payment card or household was reached, the most recent first. Accessible by Admin and Operator users and by the members of the organisation running the event.",
+    responses(
+        (status = 200, description = "Refused purchases were successfully retrieved.", body = DocSuccessApiResponse<Vec<PurchaseLimitRejection>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the refused purchases.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[get("/events/{event_id}/purchase-limit-rejections")]
+async fn get_purchase_limit_rejections(
+    db: Data<Database>,
+    event_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+
+    match service::get_purchase_limit_rejections(
+        &db,
+        event_id,
+        jwt_payload.role,
+        jwt_payload.user_id,
+    )
+    .await
+    {
+        Ok(rejections) => {
+            let response: ApiResponse<Vec<PurchaseLimitRejection>> = ApiResponse::success(
+                "Refused purchases were successfully retrieved.",
+                Some(rejections),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the refused purchases.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/tickets/events/{event_id}/waitlist/me",
+    tag = "Protected Endpoints",
+    summary = "Retrieve my place on the waitlist of an event",
+    description = "Returns the waitlist entry of the authenticated user and their position in the line. The position is empty once a seat is held for the user: it can then be bought with the usual ticket creation until the hold expires.",
+    responses(
+        (status = 200, description = "Waitlist position was successfully retrieved.", body = DocSuccessApiResponse<WaitlistPosition>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the waitlist position.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[get("/events/{event_id}/waitlist/me")]
+async fn get_waitlist_position(
+    db: Data<Database>,
+    event_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+
+    match service::get_waitlist_position(&db, event_id, jwt_payload.user_id).await {
+        Ok(position) => {
+            let response: ApiResponse<WaitlistPosition> = ApiResponse::success(
+                "Waitlist position was successfully retrieved.",
+                Some(position),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the waitlist position.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/tickets/events/{event_id}/waitlist",
+    tag = "Protected Endpoints",
+    summary = "Join the waitlist of a sold-out event",
+    description = "Adds the authenticated user to the waitlist of an event on sale whose seats, or the seats of the given ticket type, are sold out. When a seat is freed, it is held for the first user in line for 15 minutes and they are notified; an unclaimed hold goes to the next user.",
+    request_body = JoinWaitlistRequest,
+    responses(
+        (status = 200, description = "The waitlist was successfully joined.", body = DocSuccessApiResponse<WaitlistEntry>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 409, description = "Error: Already on the waitlist", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to join the waitlist.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[post("/events/{event_id}/waitlist")]
+async fn join_waitlist(
+    db: Data<Database>,
+    event_id: Path<String>,
+    payload: Json<JoinWaitlistRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::join_waitlist(&db, event_id, jwt_payload.user_id, payload).await {
+        Ok(entry) => {
+            let response: ApiResponse<WaitlistEntry> =
+                ApiResponse::success("The waitlist was successfully joined.", Some(entry));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) if e.is::<ConflictError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to join the waitlist.", e.to_string());
+            HttpResponse::Conflict().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to join the waitlist.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/tickets/events/{event_id}/waitlist",
+    tag = "Protected Endpoints",
+    summary = "Leave the waitlist of an event",
+    description = "Removes the authenticated user from the waitlist of an event. A seat held for the user is handed over to the next user in line.",
+    responses(
+        (status = 200, description = "The waitlist was successfully left.", body = DocSuccessApiResponse<WaitlistEntry>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to leave the waitlist.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[delete("/events/{event_id}/waitlist")]
+async fn leave_waitlist(
+    db: Data<Database>,
+    event_id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+
+    match service::leave_waitlist(&db, event_id, jwt_payload.user_id).await {
+        Ok(entry) => {
+            let response: ApiResponse<WaitlistEntry> =
+                ApiResponse::success("The waitlist was successfully left.", Some(entry));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to leave the waitlist.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/tickets/events/{event_id}/waitlist/offers",
+    tag = "Internal Endpoints",
+    summary = "Offer added seats to the waitlist of an event",
+    description = "Holds up to the given number of seats for the next users waiting for the event, as freed seats are, and returns the number of seats offered. Called by the Events Service when the capacity of an event is increased. This endpoint is for internal use only.",
+    request_body = OfferWaitlistSeatsRequest,
+    responses(
+        (status = 200, description = "The seats were successfully offered.", body = DocSuccessApiResponse<WaitlistSeatsOffer>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to offer the seats.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("event_id" = String, Path, description = "Event ID")
+    )
+)]
+#[post("/events/{event_id}/waitlist/offers")]
+async fn offer_waitlist_seats(
+    db: Data<Database>,
+    event_id: Path<String>,
+    payload: Json<OfferWaitlistSeatsRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let event_id = event_id.into_inner();
+    let payload = payload.into_inner();
+
+    match service::offer_waitlist_seats(&db, event_id, payload).await {
+        Ok(offer) => {
+            let response: ApiResponse<WaitlistSeatsOffer> =
+                ApiResponse::success("The seats were successfully offered.", Some(offer));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to offer the seats.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/tickets/statistics",
+    tag = "Internal Endpoints",
+    summary = "Count the tickets of events",
+    description = "Returns, for each given event, the number of its tickets by status. Called by the Events Service to build the sales statistics of organisers. This endpoint is for internal use only.",
+    request_body = TicketStatisticsRequest,
+    responses(
+        (status = 200, description = "Ticket statistics were successfully retrieved.", body = DocSuccessApiResponse<Vec<EventTicketStatistics>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the ticket statistics.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/statistics")]
+async fn get_ticket_statistics(
+    db: Data<Database>,
+    request: Json<TicketStatisticsRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    match authenticate_internal_request(&req) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let request = request.into_inner();
+
+    match service::get_ticket_statistics(&db, request).await {
+        Ok(statistics) => {
+            let response: ApiResponse<Vec<EventTicketStatistics>> = ApiResponse::success(
+                "Ticket statistics were successfully retrieved.",
+                Some(statistics),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the ticket statistics.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/tickets/calendar-feed",
+    tag = "Protected Endpoints",
+    summary = "Create my calendar feed",
+    description = "Returns the URL of a calendar feed listing the events of the active tickets of the authenticated user, that calendar apps can subscribe to without a JWT. Calling it again replaces the URL and revokes the previous one.",
+    responses(
+        (status = 200, description = "The calendar feed was successfully created.", body = DocSuccessApiResponse<CalendarFeedUrl>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to create the calendar feed.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/calendar-feed")]
+async fn create_calendar_feed(db: Data<Database>, req: HttpRequest) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    match service::create_calendar_feed(&db, jwt_payload.user_id).await {
+        Ok(feed) => {
+            let response: ApiResponse<CalendarFeedUrl> =
+                ApiResponse::success("The calendar feed was successfully created.", Some(feed));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to create the calendar feed.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    delete,
+    path = "/api/tickets/calendar-feed",
+    tag = "Protected Endpoints",
+    summary = "Revoke my calendar feed",
+    description = "Revokes the calendar feed of the authenticated user, its URL stops working.",
+    responses(
+        (status = 200, description = "The calendar feed was successfully revoked.", body = DocSuccessApiResponse<serde_json::Value>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to revoke the calendar feed.", body = DocErrorApiResponse)
+    )
+)]
+#[delete("/calendar-feed")]
+async fn delete_calendar_feed(db: Data<Database>, req: HttpRequest) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    match service::delete_calendar_feed(&db, jwt_payload.user_id).await {
+        Ok(()) => {
+            let response: ApiResponse<()> =
+                ApiResponse::success("The calendar feed was successfully revoked.", None);
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to revoke the calendar feed.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/tickets/calendar-feed/{token}",
+    tag = "Public Endpoints",
+    summary = "Subscribe to a calendar feed",
+    description = "Returns, as an iCalendar file (RFC 5545), the events of the active tickets of the user owning the feed. The token of the URL authenticates the request, so calendar apps can poll it.",
+    responses(
+        (status = 200, description = "The iCalendar file of the feed.", body = String, content_type = "text/calendar"),
+        (status = 404, description = "This calendar feed does not exist or was revoked.", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the calendar feed.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("token" = String, Path, description = "Calendar feed token")
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/calendar-feed/{token}")]
+async fn get_calendar_feed(db: Data<Database>, token: Path<String>) -> impl Responder {
+    match service::get_calendar_feed(&db, token.into_inner()).await {
+        Ok(calendar) => HttpResponse::Ok()
+            .content_type("text/calendar; charset=utf-8")
+            .body(calendar),
+        Err(e) if e.is::<NotFoundError>() => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the calendar feed.", e.to_string());
+            HttpResponse::NotFound().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the calendar feed.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
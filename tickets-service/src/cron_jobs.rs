@@ -0,0 +1,53 @@
+use actix_rt::spawn;
+use mongodb::Database;
+use tokio_schedule::{Job, every};
+
+use crate::service::{
+    expire_waitlist_offers, process_event_cancellations, purge_deleted_tickets,
+    retry_pending_handovers,
+};
+
+// =============================================================================================================================
+
+pub async fn cron_jobs(db: Database) {
+    let cancellations_db = db.clone();
+    let every_10_seconds: std::pin::Pin<Box<dyn Future<Output = ()> + Send>> =
+        every(10).seconds().perform(move || {
+            let local_db = cancellations_db.clone();
+            async move {
+                if let Err(e) = process_event_cancellations(&local_db).await {
+                    eprintln!("Erreur dans le cron job: {:?}", e);
+                }
+            }
+        });
+    spawn(every_10_seconds);
+
+    let waitlist_db = db.clone();
+    let every_10_seconds: std::pin::Pin<Box<dyn Future<Output = ()> + Send>> =
+        every(10).seconds().perform(move || {
+            let local_db = waitlist_db.clone();
+            async move {
+                if let Err(e) = expire_waitlist_offers(&local_db).await {
+                    eprintln!("Erreur dans le cron job: {:?}", e);
+                }
+                if let Err(e) = retry_pending_handovers(&local_db).await {
+                    eprintln!("Erreur dans le cron job: {:?}", e);
+                }
+            }
+        });
+    spawn(every_10_seconds);
+
+    let every_hour: std::pin::Pin<Box<dyn Future<Output = ()> + Send>> =
+        every(1).hour().perform(move || {
+            let local_db = db.clone();
+            async move {
+                println!("Running cron job: Purge deleted tickets");
+                if let Err(e) = purge_deleted_tickets(&local_db).await {
+                    eprintln!("Erreur dans le cron job: {:?}", e);
+                }
+            }
+        });
+    spawn(every_hour);
+}
+
+// =============================================================================================================================
@@ -1,6 +1,13 @@
-use mongodb::{Client, Database, options::ClientOptions};
+use futures_util::TryStreamExt;
+use mongodb::bson::{Document, doc};
+use mongodb::error::ErrorKind;
+use mongodb::options::IndexOptions;
+use mongodb::{Client, Collection, Database, IndexModel, options::ClientOptions};
 use std::error::Error;
 
+const SEAT_INDEX_NAME: &str = "event_id_1_seat_number_1";
+const NAMESPACE_NOT_FOUND: i32 = 26;
+
 // =============================================================================================================================
 
 pub async fn init_db() -> Result<Database, Box<dyn Error>> {
@@ -15,3 +22,65 @@ pub async fn init_db() -> Result<Database, Box<dyn Error>> {
 }
 
 // =============================================================================================================================
+
+// The seat index only covers the Pending and Active tickets with a seat. Databases created before that keep
+// a unique index on every ticket, which refuses the seats given back and the passes, so it is replaced.
+pub async fn migrate_seat_index(db: &Database) -> Result<(), Box<dyn Error>> {
+    let collection: Collection<Document> = db.collection("tickets");
+
+    let indexes: Vec<IndexModel> = match collection.list_indexes().await {
+        Ok(cursor) => cursor.try_collect().await?,
+        Err(e) if is_namespace_not_found(&e) => vec![],
+        Err(e) => return Err(e.into()),
+    };
+    let seat_index = indexes.iter().find_map(|index| {
+        index
+            .options
+            .as_ref()
+            .filter(|options| options.name.as_deref() == Some(SEAT_INDEX_NAME))
+    });
+
+    match seat_index {
+        Some(options)
+            if options
+                .partial_filter_expression
+                .as_ref()
+                .is_some_and(|filter| filter.contains_key("seat_number")) =>
+        {
+            return Ok(());
+        }
+        Some(_) => {
+            collection.drop_index(SEAT_INDEX_NAME).await?;
+            println!("Dropped the former seat index of the tickets");
+        }
+        None => {}
+    }
+
+    let options = IndexOptions::builder()
+        .name(SEAT_INDEX_NAME.to_string())
+        .unique(true)
+        .partial_filter_expression(doc! {
+            "status": { "$in": ["Pending", "Active"] },
+            "deleted_at": { "$type": "null" },
+            "seat_number": { "$gt": 0 },
+        })
+        .build();
+    collection
+        .create_index(
+            IndexModel::builder()
+                .keys(doc! { "event_id": 1, "seat_number": 1 })
+                .options(options)
+                .build(),
+        )
+        .await?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+fn is_namespace_not_found(e: &mongodb::error::Error) -> bool {
+    matches!(*e.kind, ErrorKind::Command(ref err) if err.code == NAMESPACE_NOT_FOUND)
+}
+
+// =============================================================================================================================
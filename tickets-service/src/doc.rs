@@ -1,9 +1,24 @@
 use crate::controller::{
-    __path_active_ticket_by_id, __path_cancel_ticket_by_id, __path_create_ticket,
-    __path_delete_ticket_by_id, __path_get_ticket_by_id, __path_get_tickets, __path_health_check,
-    __path_refund_ticket_by_id, __path_update_ticket_seat_number_by_id,
+    __path_active_ticket_by_id, __path_broadcast_to_ticket_holders, __path_cancel_ticket_by_id,
+    __path_check_in_admission, __path_create_calendar_feed, __path_create_pass_ticket,
+    __path_create_ticket, __path_delete_calendar_feed, __path_delete_ticket_by_id,
+    __path_get_calendar_feed, __path_get_deleted_tickets, __path_get_event_broadcasts,
+    __path_get_event_cancellation, __path_get_event_waitlist, __path_get_purchase_limit_rejections,
+    __path_get_seat_availability, __path_get_ticket_by_id, __path_get_ticket_statistics,
+    __path_get_tickets, __path_get_tickets_by_event_id, __path_get_waitlist_position,
+    __path_health_check, __path_join_waitlist, __path_leave_waitlist, __path_notify_ticket_holders,
+    __path_offer_waitlist_seats, __path_refund_ticket_by_id, __path_restore_ticket_by_id,
+    __path_start_event_cancellation, __path_update_ticket_seat_number_by_id,
+};
+use crate::model::{
+    Admission, Broadcast, BroadcastReport, BroadcastRequest, CalendarFeedUrl,
+    CreatePassTicketRequest, CreateTicketRequest, DeliveryStatistics, EventCancellation,
+    EventCancellationFailure, EventCancellationStatus, EventTicketStatistics, JoinWaitlistRequest,
+    NotifyTicketHoldersRequest, OfferWaitlistSeatsRequest, PurchaseLimitRejection,
+    PurchaseLimitRule, SeatAvailability, SeatRange, Ticket, TicketHoldersNotification,
+    TicketStatisticsRequest, UpdateTicketSeatNumberByIdRequest, WaitlistEntry, WaitlistPosition,
+    WaitlistSeatsOffer, WaitlistStatus,
 };
-use crate::model::{CreateTicketRequest, Ticket, UpdateTicketSeatNumberByIdRequest};
 use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder};
 use utoipa::{
     openapi::security::SecurityScheme,
@@ -20,6 +35,13 @@ use utoipa::{
         description = r#"
 The Tickets Service handles ticket purchases and management.
 It provides endpoints for retrieving, creating, updating, activating, cancelling, refunding, and deleting tickets.
+Buyers can poll the availability of the seats of an event before choosing one.
+Organisers can broadcast a message to the holders of an event and follow its delivery.
+When an event is cancelled, its tickets are refunded in the background and the progress can be followed by the organisers.
+Users can join the waitlist of a sold-out event and get a freed seat held for them for a limited time.
+Users can subscribe their calendar app to a feed of the events they hold a ticket for.
+Purchases are limited per user, payment card and household when the event sets limits, and the refused purchases are logged.
+Passes grant entry to a set of events with a single payment, and are checked in at each of their events.
 "#
     ),
     paths(
@@ -27,11 +49,32 @@ It provides endpoints for retrieving, creating, updating, activating, cancelling
         get_tickets,
         get_ticket_by_id,
         create_ticket,
+        create_pass_ticket,
+        check_in_admission,
         update_ticket_seat_number_by_id,
         active_ticket_by_id,
         cancel_ticket_by_id,
         refund_ticket_by_id,
-        delete_ticket_by_id
+        delete_ticket_by_id,
+        get_deleted_tickets,
+        restore_ticket_by_id,
+        get_tickets_by_event_id,
+        get_seat_availability,
+        get_event_cancellation,
+        start_event_cancellation,
+        notify_ticket_holders,
+        broadcast_to_ticket_holders,
+        get_event_broadcasts,
+        get_event_waitlist,
+        get_waitlist_position,
+        join_waitlist,
+        leave_waitlist,
+        offer_waitlist_seats,
+        get_ticket_statistics,
+        create_calendar_feed,
+        delete_calendar_feed,
+        get_calendar_feed,
+        get_purchase_limit_rejections
     ),
     security(
         (),
@@ -40,7 +83,35 @@ It provides endpoints for retrieving, creating, updating, activating, cancelling
     ),
     modifiers(&SecurityAddon),
     components(
-        schemas(CreateTicketRequest, Ticket, UpdateTicketSeatNumberByIdRequest),
+        schemas(
+            CreateTicketRequest,
+            Ticket,
+            Admission,
+            CreatePassTicketRequest,
+            UpdateTicketSeatNumberByIdRequest,
+            EventCancellation,
+            EventCancellationFailure,
+            EventCancellationStatus,
+            SeatAvailability,
+            SeatRange,
+            NotifyTicketHoldersRequest,
+            TicketHoldersNotification,
+            BroadcastRequest,
+            Broadcast,
+            DeliveryStatistics,
+            BroadcastReport,
+            JoinWaitlistRequest,
+            WaitlistEntry,
+            WaitlistPosition,
+            WaitlistStatus,
+            OfferWaitlistSeatsRequest,
+            WaitlistSeatsOffer,
+            TicketStatisticsRequest,
+            EventTicketStatistics,
+            CalendarFeedUrl,
+            PurchaseLimitRule,
+            PurchaseLimitRejection
+        ),
         
     )
 )]
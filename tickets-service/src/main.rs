@@ -1,9 +1,11 @@
 use actix_web::{App, HttpServer, web};
 use controller::config;
-use db::init_db;
+use cron_jobs::cron_jobs;
+use db::{init_db, migrate_seat_index};
 use extractor::deserialize_error_extractor;
 
 mod controller;
+mod cron_jobs;
 mod db;
 mod doc;
 mod extractor;
@@ -17,6 +19,14 @@ async fn main() -> std::io::Result<()> {
     dotenv::dotenv().ok();
 
     let db = init_db().await.expect("❌ Failed to connect to database");
+    migrate_seat_index(&db)
+        .await
+        .expect("❌ Failed to migrate the seat index of the tickets");
+    let db_for_cron = db.clone();
+
+    actix_rt::spawn(async move {
+        cron_jobs(db_for_cron).await;
+    });
 
     HttpServer::new(move || {
         App::new()
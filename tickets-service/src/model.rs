@@ -1,6 +1,7 @@
-use common::models::PaymentCurrency;
+use common::models::{EventStatus, PaymentCurrency};
 use common::utils::utils::{
-    deserialize_datetime_from_any, serialize_option_object_id_as_hex_string,
+    deserialize_datetime_from_any, deserialize_option_datetime_from_any,
+    serialize_option_datetime_as_rfc3339_string, serialize_option_object_id_as_hex_string,
     validate_date_not_in_past,
 };
 use mongodb::bson::serde_helpers::serialize_bson_datetime_as_rfc3339_string;
@@ -49,9 +50,96 @@ pub struct Ticket {
     #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
     pub event_id: ObjectId,
 
+    #[serde(default)]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "66f1a2b3c4d5e6f7a8b9c0d1", value_type = Option<String>)]
+    pub presale_id: Option<ObjectId>,
+
     #[serde(rename = "user_id")]
     #[schema(example = "67d88106c3f7903ba0f9211a", value_type = String)]
     pub user_id: ObjectId,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(example = json!(["67da941412d5bd6dbc358950:event:user:67d88106c3f7903ba0f9211a"]))]
+    pub purchase_counters: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "6731d2e3f4a5b6c7d8e9f0a1", value_type = Option<String>)]
+    pub pass_id: Option<ObjectId>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "Festival 3-day pass", value_type = Option<String>)]
+    pub pass_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub admissions: Vec<Admission>,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T09:00:00.000Z", value_type = String)]
+    pub deleted_at: Option<DateTime>,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "67d88106c3f7903ba0f9211a", value_type = String)]
+    pub deleted_by: Option<ObjectId>,
+}
+
+impl Ticket {
+    // A pass holds a seat on each of its events, a ticket on its only event.
+    pub fn seats(&self) -> Vec<(ObjectId, Option<ObjectId>)> {
+        if self.admissions.is_empty() {
+            vec![(self.event_id, self.ticket_type_id)]
+        } else {
+            self.admissions
+                .iter()
+                .map(|admission| (admission.event_id, admission.ticket_type_id))
+                .collect()
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Admission {
+    #[serde(rename = "event_id")]
+    #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(default)]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    // The part of the price of the pass earned by this event.
+    #[schema(example = 40)]
+    pub price: u32,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-08-15T18:42:10.000Z", value_type = Option<String>)]
+    pub checked_in_at: Option<DateTime>,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "67d88106c3f7903ba0f9211a", value_type = Option<String>)]
+    pub checked_in_by: Option<ObjectId>,
+}
+
+// =============================================================================================================================
+
+pub struct PaymentCard {
+    pub card_number: String,
+    pub expiration_date: DateTime,
+    pub cvv: String,
+    pub card_holder: String,
 }
 
 // =============================================================================================================================
@@ -82,7 +170,9 @@ pub struct CreateTicketRequest {
     #[schema(example = "Bryan Cellier")]
     pub card_holder: String,
 
-    pub currency: PaymentCurrency,
+    #[serde(default)]
+    #[schema(example = "Eur", value_type = Option<String>)]
+    pub currency: Option<PaymentCurrency>,
 
     #[validate(range(min = 1, message = "Seat number must be at least one."))]
     #[schema(example = 45)]
@@ -92,11 +182,124 @@ pub struct CreateTicketRequest {
     #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
     pub event_id: ObjectId,
 
+    #[serde(default)]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    #[serde(default)]
+    #[schema(example = "FANCLUB2025", value_type = Option<String>)]
+    #[validate(length(max = 32, message = "Invalid access code"))]
+    pub access_code: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = "12 rue de la Paix, 75002 Paris", value_type = Option<String>)]
+    #[validate(length(
+        min = 5,
+        max = 200,
+        message = "Household address must be between 5 and 200 characters"
+    ))]
+    pub household_address: Option<String>,
+
+    #[serde(rename = "user_id")]
+    #[schema(example = "67d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+}
+
+impl CreateTicketRequest {
+    pub fn card(&self) -> PaymentCard {
+        PaymentCard {
+            card_number: self.card_number.clone(),
+            expiration_date: self.expiration_date,
+            cvv: self.cvv.clone(),
+            card_holder: self.card_holder.clone(),
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreatePassTicketRequest {
+    #[validate(length(min = 12, max = 19, message = "Invalid card number length"))]
+    #[schema(example = "120156526352326230")]
+    pub card_number: String,
+
+    #[serde(
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string",
+        deserialize_with = "deserialize_datetime_from_any"
+    )]
+    #[validate(custom(function = "validate_date_not_in_past"))]
+    #[schema(example = "2027-04-12T00:00:00Z", value_type = String)]
+    pub expiration_date: DateTime,
+
+    #[validate(length(min = 3, max = 4, message = "Invalid CVV"))]
+    #[schema(example = "373")]
+    pub cvv: String,
+
+    #[validate(length(
+        min = 2,
+        max = 50,
+        message = "Card holder name must be between 2 and 50 characters"
+    ))]
+    #[schema(example = "Bryan Cellier")]
+    pub card_holder: String,
+
+    #[serde(rename = "pass_id")]
+    #[schema(example = "6731d2e3f4a5b6c7d8e9f0a1", value_type = String)]
+    pub pass_id: ObjectId,
+
+    #[serde(default)]
+    #[schema(example = "12 rue de la Paix, 75002 Paris", value_type = Option<String>)]
+    #[validate(length(
+        min = 5,
+        max = 200,
+        message = "Household address must be between 5 and 200 characters"
+    ))]
+    pub household_address: Option<String>,
+
     #[serde(rename = "user_id")]
     #[schema(example = "67d88106c3f7903ba0f9211a", value_type = String)]
     pub user_id: ObjectId,
 }
 
+impl CreatePassTicketRequest {
+    pub fn card(&self) -> PaymentCard {
+        PaymentCard {
+            card_number: self.card_number.clone(),
+            expiration_date: self.expiration_date,
+            cvv: self.cvv.clone(),
+            card_holder: self.card_holder.clone(),
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetPassInternalResponse {
+    pub name: String,
+    pub events: Vec<PassEvent>,
+    pub price: u32,
+    pub currency: PaymentCurrency,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    pub sale_ends_at: Option<DateTime>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PassEvent {
+    #[serde(rename = "event_id")]
+    pub event_id: ObjectId,
+
+    #[serde(default)]
+    pub ticket_type_id: Option<ObjectId>,
+}
+
 // =============================================================================================================================
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
@@ -134,12 +337,183 @@ pub struct GetEventInternalResponse {
 
     pub price: u32,
 
+    #[serde(default)]
+    pub status: EventStatus,
+
+    #[serde(default)]
+    pub ticket_types: Vec<EventTicketType>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    pub sale_ends_at: Option<DateTime>,
+
+    #[serde(default)]
+    pub presales: Vec<EventPresale>,
+
+    #[serde(default)]
+    pub purchase_limits: EventPurchaseLimits,
+
+    #[serde(default)]
+    pub geo_location: Option<EventGeoLocation>,
+
     #[serde(rename = "creator_id")]
     pub creator_id: ObjectId,
 }
 
 // =============================================================================================================================
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventGeoLocation {
+    pub coordinates: [f64; 2],
+}
+
+impl GetEventInternalResponse {
+    pub fn public_sale_is_open(&self, now: DateTime) -> bool {
+        self.sale_starts_at.is_none_or(|starts_at| now >= starts_at)
+            && self.sale_ends_at.is_none_or(|ends_at| now <= ends_at)
+    }
+
+    pub fn presale_is_open(&self, now: DateTime) -> bool {
+        self.presales
+            .iter()
+            .any(|presale| now >= presale.starts_at && now <= presale.ends_at)
+    }
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventPresale {
+    pub id: ObjectId,
+
+    #[serde(deserialize_with = "deserialize_datetime_from_any")]
+    pub starts_at: DateTime,
+
+    #[serde(deserialize_with = "deserialize_datetime_from_any")]
+    pub ends_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedeemedAccessCode {
+    #[serde(rename = "presale_id")]
+    pub presale_id: ObjectId,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventTicketType {
+    pub id: ObjectId,
+    pub name: String,
+    pub price: u32,
+    pub currency: PaymentCurrency,
+    pub remaining: u32,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    pub sale_starts_at: Option<DateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    pub sale_ends_at: Option<DateTime>,
+
+    #[serde(default)]
+    pub seat_from: Option<u32>,
+
+    #[serde(default)]
+    pub seat_to: Option<u32>,
+
+    #[serde(default)]
+    pub purchase_limits: EventPurchaseLimits,
+}
+
+impl EventTicketType {
+    pub fn contains_seat(&self, seat_number: u32) -> bool {
+        self.seat_from
+            .is_none_or(|seat_from| seat_number >= seat_from)
+            && self.seat_to.is_none_or(|seat_to| seat_number <= seat_to)
+    }
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EventPurchaseLimits {
+    #[serde(default)]
+    pub max_per_user: Option<u32>,
+
+    #[serde(default)]
+    pub max_per_card: Option<u32>,
+
+    #[serde(default)]
+    pub max_per_household: Option<u32>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum PurchaseLimitRule {
+    User,
+    Card,
+    Household,
+}
+
+// =============================================================================================================================
+
+// A counter is shared by the tickets of an event, or of one of its ticket types, bought by the same user,
+// with the same card or for the same household. Its id is derived from them, so it is found again without a lookup.
+#[derive(Debug)]
+pub struct PurchaseCounter {
+    pub id: String,
+    pub event_id: ObjectId,
+    pub ticket_type_id: Option<ObjectId>,
+    pub rule: PurchaseLimitRule,
+    pub limit: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PurchaseLimitRejection {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "6721c2d3e4f5a6b7c8d9e0f1", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "event_id")]
+    #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    #[serde(rename = "user_id")]
+    #[schema(example = "67d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[schema(example = "Card", value_type = String)]
+    pub rule: PurchaseLimitRule,
+
+    #[schema(example = 4)]
+    pub limit: u32,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T08:37:10.975Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UpdateEventRemainingSeatsInternalResponse {
     #[serde(rename = "id", alias = "_id")]
@@ -159,3 +533,429 @@ pub struct UpdateEventRemainingSeatsInternalResponse {
 }
 
 // =============================================================================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum EventCancellationStatus {
+    Pending,
+    InProgress,
+    Completed,
+    CompletedWithFailures,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventCancellationFailure {
+    #[serde(rename = "ticket_id")]
+    #[schema(example = "67daf9aefc24646c8d3fb79e", value_type = String)]
+    pub ticket_id: ObjectId,
+
+    #[schema(example = "No payment was found for this ticket.")]
+    pub error: String,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventCancellation {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "64c3d4e5f6a7b8c9d0e1f2a3", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "event_id")]
+    #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(default)]
+    #[schema(example = "Summer Concert", value_type = Option<String>)]
+    pub event_title: Option<String>,
+
+    #[schema(example = "InProgress")]
+    pub status: EventCancellationStatus,
+
+    #[schema(example = 120)]
+    pub total_tickets: u32,
+
+    #[schema(example = 87)]
+    pub refunded_tickets: u32,
+
+    pub failures: Vec<EventCancellationFailure>,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T08:37:10.975Z", value_type = String)]
+    pub created_at: DateTime,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T08:37:15.000Z", value_type = String)]
+    pub started_at: Option<DateTime>,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T08:38:02.000Z", value_type = String)]
+    pub completed_at: Option<DateTime>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SeatAvailability {
+    #[schema(example = 500)]
+    pub capacity: u32,
+
+    #[schema(example = true)]
+    pub on_sale: bool,
+
+    #[schema(example = 495)]
+    pub available: u32,
+
+    #[schema(example = json!([{ "first": 12, "last": 13 }]))]
+    pub held: Vec<SeatRange>,
+
+    #[schema(example = json!([{ "first": 1, "last": 3 }]))]
+    pub sold: Vec<SeatRange>,
+
+    #[schema(example = json!([]))]
+    pub blocked: Vec<SeatRange>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SeatRange {
+    #[schema(example = 12)]
+    pub first: u32,
+
+    #[schema(example = 13)]
+    pub last: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct PendingHandover {
+    #[serde(rename = "_id")]
+    pub id: ObjectId,
+
+    pub ticket_id: ObjectId,
+
+    pub event_id: ObjectId,
+
+    #[serde(default)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    #[serde(deserialize_with = "deserialize_datetime_from_any")]
+    pub failed_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct TicketSeat {
+    pub seat_number: u32,
+    pub status: TicketStatus,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct NotifyTicketHoldersRequest {
+    #[validate(length(
+        min = 10,
+        max = 100,
+        message = "Message must be between 10 and 100 characters"
+    ))]
+    #[schema(example = "Summer Concert has been moved to 2025-10-15 18:00 UTC.")]
+    pub message: String,
+
+    #[serde(default)]
+    #[schema(example = false)]
+    pub active_only: bool,
+
+    #[serde(default)]
+    #[schema(example = "reminder:67da941412d5bd6dbc358950:24:2025-08-15T18:00:00Z", value_type = Option<String>)]
+    pub delivery_key: Option<String>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TicketHoldersNotification {
+    #[schema(example = 120)]
+    pub recipients: usize,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = 0, value_type = Option<usize>)]
+    pub failed: Option<usize>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum WaitlistStatus {
+    Waiting,
+    Offered,
+    Claimed,
+    Expired,
+    Left,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WaitlistEntry {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "6720b1c2d3e4f5a6b7c8d9e0", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "event_id")]
+    #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    #[serde(rename = "user_id")]
+    #[schema(example = "67d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[schema(example = "Offered", value_type = String)]
+    pub status: WaitlistStatus,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub offered_ticket_type_id: Option<ObjectId>,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T08:37:10.975Z", value_type = String)]
+    pub created_at: DateTime,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-24T10:00:00.000Z", value_type = Option<String>)]
+    pub offered_at: Option<DateTime>,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-24T10:15:00.000Z", value_type = Option<String>)]
+    pub offer_expires_at: Option<DateTime>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JoinWaitlistRequest {
+    #[serde(default)]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OfferWaitlistSeatsRequest {
+    #[schema(example = 20)]
+    pub seats: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WaitlistSeatsOffer {
+    #[schema(example = 3)]
+    pub offered: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WaitlistPosition {
+    pub entry: WaitlistEntry,
+
+    #[schema(example = 3)]
+    pub position: Option<u64>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct TicketStatisticsRequest {
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Between 1 and 500 events can be requested"
+    ))]
+    #[schema(example = json!(["67da941412d5bd6dbc358950"]))]
+    pub event_ids: Vec<String>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventTicketStatistics {
+    #[schema(example = "67da941412d5bd6dbc358950")]
+    pub event_id: String,
+
+    #[schema(example = 12)]
+    pub pending: u32,
+
+    #[schema(example = 420)]
+    pub active: u32,
+
+    #[schema(example = 9)]
+    pub cancelled: u32,
+
+    #[schema(example = 4)]
+    pub refunded: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CalendarFeed {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "6720b1c2d3e4f5a6b7c8d9e1", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "user_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[schema(example = "Xk2m9QpL4vR7tY1wZ8nB3cD6fG0hJ5sA")]
+    pub token: String,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T08:37:10.975Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CalendarFeedUrl {
+    #[schema(
+        example = "http://localhost/api/tickets/calendar-feed/Xk2m9QpL4vR7tY1wZ8nB3cD6fG0hJ5sA"
+    )]
+    pub url: String,
+
+    #[serde(serialize_with = "serialize_bson_datetime_as_rfc3339_string")]
+    #[schema(example = "2025-03-23T08:37:10.975Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct BroadcastRequest {
+    #[validate(length(
+        min = 10,
+        max = 100,
+        message = "Message must be between 10 and 100 characters"
+    ))]
+    #[schema(example = "Doors open at 19:00, please bring an ID.")]
+    pub message: String,
+
+    #[serde(default)]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Broadcast {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "6722d3e4f5a6b7c8d9e0f1a2", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "event_id")]
+    #[schema(example = "67da941412d5bd6dbc358950", value_type = String)]
+    pub event_id: ObjectId,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "65d4e5f6a7b8c9d0e1f2a3b4", value_type = Option<String>)]
+    pub ticket_type_id: Option<ObjectId>,
+
+    #[serde(rename = "sender_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub sender_id: ObjectId,
+
+    #[schema(example = "Doors open at 19:00, please bring an ID.")]
+    pub message: String,
+
+    #[schema(example = 420)]
+    pub recipients: u32,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-08-15T16:00:00.000Z", value_type = String)]
+    pub created_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeliveryStatistics {
+    #[schema(example = 120)]
+    pub pending: u32,
+
+    #[schema(example = 296)]
+    pub sent: u32,
+
+    #[schema(example = 4)]
+    pub failed: u32,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BroadcastReport {
+    pub broadcast: Broadcast,
+    pub delivery: DeliveryStatistics,
+}
+
+// =============================================================================================================================
@@ -1,23 +1,58 @@
 use common::jwt::internal::encode_internal_jwt;
-use common::models::TriggerNotificationRequest;
-use common::utils::utils::trigger_notification;
-use common::{models::AuthRole, utils::api_response::ApiResponse};
+use common::models::{EventStatus, OrganisationRole, PaymentCurrency, TriggerNotificationRequest};
+use common::utils::ical::{CalendarEvent, build_calendar, calendar_geo};
+use common::utils::utils::{
+    CALENDAR_FEED_URL, PURCHASE_FINGERPRINT_SECRET, get_event_organiser_role,
+    is_duplicate_key_error, soft_delete_purge_cutoff, trigger_notification,
+};
+use common::{
+    models::AuthRole,
+    utils::api_response::{
+        ApiResponse, BadRequestError, ConflictError, NotFoundError, TooManyRequestsError,
+    },
+};
 use futures_util::TryStreamExt;
 use mongodb::bson::oid::ObjectId;
-use mongodb::bson::{Document, doc, to_bson};
+use mongodb::bson::{Bson, Document, doc, to_bson};
 use mongodb::options::ReturnDocument;
 use mongodb::{Collection, Database, bson::DateTime};
+use rand::{Rng, distr::Alphanumeric};
+use reqwest::StatusCode;
 use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, MutexGuard};
 use validator::Validate;
 
 use crate::model::{
-    CreateTicketRequest, GetEventInternalResponse, Ticket, TicketStatus,
-    UpdateTicketSeatNumberByIdRequest,
+    Admission, Broadcast, BroadcastReport, BroadcastRequest, CalendarFeed, CalendarFeedUrl,
+    CreatePassTicketRequest, CreateTicketRequest, EventCancellation, EventCancellationFailure,
+    EventCancellationStatus, EventTicketStatistics, EventTicketType, GetEventInternalResponse,
+    GetPassInternalResponse, JoinWaitlistRequest, NotifyTicketHoldersRequest,
+    OfferWaitlistSeatsRequest, PaymentCard, PendingHandover, PurchaseCounter,
+    PurchaseLimitRejection, PurchaseLimitRule, RedeemedAccessCode, SeatAvailability, SeatRange,
+    Ticket, TicketHoldersNotification, TicketSeat, TicketStatisticsRequest, TicketStatus,
+    UpdateTicketSeatNumberByIdRequest, WaitlistEntry, WaitlistPosition, WaitlistSeatsOffer,
+    WaitlistStatus,
 };
 
 // =============================================================================================================================
 
 const COLLECTION_NAME: &str = "tickets";
+const CANCELLATIONS_COLLECTION_NAME: &str = "event_cancellations";
+const CANCELLATION_STALE_AFTER_MINUTES: i64 = 15;
+const WAITLIST_COLLECTION_NAME: &str = "waitlist_entries";
+const WAITLIST_HOLD_MINUTES: i64 = 15;
+const CALENDAR_FEEDS_COLLECTION_NAME: &str = "calendar_feeds";
+const PURCHASE_COUNTERS_COLLECTION_NAME: &str = "purchase_counters";
+const PURCHASE_REJECTIONS_COLLECTION_NAME: &str = "purchase_limit_rejections";
+const DELIVERIES_COLLECTION_NAME: &str = "notification_deliveries";
+const PENDING_HANDOVERS_COLLECTION_NAME: &str = "pending_handovers";
+const PENDING_HANDOVER_RETRY_HOURS: i64 = 24;
+const SEAT_AVAILABILITY_CACHE_MILLIS: i64 = 2000;
+
+static SEAT_AVAILABILITY_CACHE: LazyLock<Mutex<HashMap<ObjectId, (DateTime, SeatAvailability)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
 // =============================================================================================================================
 
@@ -29,8 +64,8 @@ pub async fn get_tickets(
     let user_id: ObjectId = ObjectId::parse_str(&user_id)?;
 
     let filter: Document = match role {
-        AuthRole::Admin | AuthRole::Operator => doc! {},
-        _ => doc! { "user_id": user_id },
+        AuthRole::Admin | AuthRole::Operator => doc! { "deleted_at": null },
+        _ => doc! { "user_id": user_id, "deleted_at": null },
     };
 
     let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
@@ -53,8 +88,8 @@ pub async fn get_ticket_by_id(
     let user_id = ObjectId::parse_str(&user_id)?;
 
     let filter: Document = match role {
-        AuthRole::Admin | AuthRole::Operator => doc! { "_id": ticket_id },
-        _ => doc! { "_id": ticket_id, "user_id" : user_id },
+        AuthRole::Admin | AuthRole::Operator => doc! { "_id": ticket_id, "deleted_at": null },
+        _ => organiser_ticket_filter(db, ticket_id, user_id, |_| true).await?,
     };
 
     let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
@@ -66,6 +101,60 @@ pub async fn get_ticket_by_id(
 
 // =============================================================================================================================
 
+pub async fn get_tickets_by_event_id(
+    db: &Database,
+    event_id: String,
+    role: AuthRole,
+    user_id: String,
+) -> Result<Vec<Ticket>, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+
+    if !matches!(role, AuthRole::Admin | AuthRole::Operator)
+        && get_event_organiser_role(event_id, &user_id)
+            .await?
+            .is_none()
+    {
+        return Err("Only the organisers of the event can access its tickets".into());
+    }
+
+    let mut filter = event_tickets_filter(event_id, None);
+    filter.insert("deleted_at", Bson::Null);
+
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    let cursor = collection.find(filter).await?;
+
+    let tickets = cursor.try_collect().await?;
+
+    Ok(tickets)
+}
+
+// =============================================================================================================================
+
+async fn organiser_ticket_filter(
+    db: &Database,
+    ticket_id: ObjectId,
+    user_id: ObjectId,
+    is_allowed: fn(&OrganisationRole) -> bool,
+) -> Result<Document, Box<dyn std::error::Error>> {
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+
+    let ticket = collection
+        .find_one(doc! { "_id": ticket_id, "deleted_at": null })
+        .await?
+        .filter(|ticket| ticket.user_id != user_id);
+
+    if let Some(ticket) = ticket {
+        let organiser_role = get_event_organiser_role(ticket.event_id, &user_id.to_hex()).await?;
+        if organiser_role.is_some_and(|r| is_allowed(&r)) {
+            return Ok(doc! { "_id": ticket_id, "deleted_at": null });
+        }
+    }
+
+    Ok(doc! { "_id": ticket_id, "user_id": user_id, "deleted_at": null })
+}
+
+// =============================================================================================================================
+
 pub async fn create_ticket(
     db: &Database,
     ticket_data: CreateTicketRequest,
@@ -89,41 +178,538 @@ pub async fn create_ticket(
         return Err("No Event found with this id".into());
     };
 
-    if event.remaining_seats < 1 {
-        return Err("No more seats are avalaible for this event.".into());
+    if !event.status.is_on_sale() {
+        return Err("Tickets for this event are not on sale.".into());
+    }
+
+    // A seat held for the buyer by the waitlist is already reserved, even when the event is sold out.
+    let offer = find_waitlist_offer(db, ticket_data.event_id, ticket_data.user_id).await?;
+    if let Some(offer) = &offer {
+        if offer.offered_ticket_type_id != ticket_data.ticket_type_id {
+            return Err("The seat held for you on the waitlist is for another ticket type.".into());
+        }
+    }
+
+    if offer.is_none() && event.remaining_seats < 1 {
+        return Err(Box::new(ConflictError(
+            "No more seats are avalaible for this event.".to_string(),
+        )));
     }
 
     if event.date < DateTime::now() {
         return Err("This event is not avalaible.".into());
     }
 
-    if ticket_data.seat_number > event.capacity {
-        return Err("This seat doesn't exist.".into());
+    // Before the public sale opens, only the holders of an access code of an open presale can buy.
+    let now = DateTime::now();
+    if event.sale_ends_at.is_some_and(|ends_at| now > ends_at) {
+        return Err("The sale of this event has closed.".into());
     }
+    let access_code = match event.sale_starts_at {
+        Some(starts_at) if now < starts_at => match ticket_data.access_code.as_deref() {
+            Some(access_code) => Some(access_code),
+            None if event.presale_is_open(now) => {
+                return Err("This event is in presale, an access code is required.".into());
+            }
+            None => {
+                return Err(format!(
+                    "The sale of this event has not opened yet, it opens at {}.",
+                    starts_at.try_to_rfc3339_string()?
+                )
+                .into());
+            }
+        },
+        _ => None,
+    };
+
+    check_seat_number(ticket_data.seat_number, event.capacity)?;
+
+    let (price, currency) = match ticket_data.ticket_type_id {
+        Some(ticket_type_id) => {
+            let ticket_type = event
+                .ticket_types
+                .iter()
+                .find(|t| t.id == ticket_type_id)
+                .ok_or("This ticket type doesn't exist for this event.")?;
+
+            let now = DateTime::now();
+            if ticket_type
+                .sale_starts_at
+                .is_some_and(|starts_at| now < starts_at)
+            {
+                return Err("The sale of this ticket type has not started yet.".into());
+            }
+            if ticket_type
+                .sale_ends_at
+                .is_some_and(|ends_at| now > ends_at)
+            {
+                return Err("The sale of this ticket type is over.".into());
+            }
+            if offer.is_none() && ticket_type.remaining < 1 {
+                return Err(Box::new(ConflictError(
+                    "No more tickets of this type are avalaible.".to_string(),
+                )));
+            }
+            if !ticket_type.contains_seat(ticket_data.seat_number) {
+                return Err("This seat is not available for this ticket type.".into());
+            }
+
+            (ticket_type.price, ticket_type.currency.clone())
+        }
+        None if !event.ticket_types.is_empty() => {
+            return Err("A ticket type must be chosen for this event.".into());
+        }
+        None => {
+            let currency = ticket_data
+                .currency
+                .clone()
+                .ok_or("A currency must be provided for this event.")?;
+            (event.price, currency)
+        }
+    };
+
+    let ticket_type = ticket_data
+        .ticket_type_id
+        .and_then(|ticket_type_id| event.ticket_types.iter().find(|t| t.id == ticket_type_id));
+    let counters = applicable_purchase_counters(
+        &event,
+        ticket_type,
+        ticket_data.user_id,
+        &ticket_data.card_number,
+        ticket_data.household_address.as_deref(),
+    )?;
+    let purchase_counters = reserve_purchase_counters(db, ticket_data.user_id, counters).await?;
+
+    let presale_id = match access_code {
+        Some(access_code) => {
+            match access_code_request(ticket_data.event_id, access_code, "redeem").await {
+                Ok(redeemed) => Some(redeemed.presale_id),
+                Err(e) => {
+                    shift_purchase_counters(db, &purchase_counters, -1).await;
+                    return Err(e);
+                }
+            }
+        }
+        None => None,
+    };
 
     let mut ticket = Ticket {
         id: None,
-        price: event.price,
+        price,
         seat_number: ticket_data.seat_number,
         status: TicketStatus::Pending,
         purchase_date: DateTime::now(),
         event_id: ticket_data.event_id,
+        ticket_type_id: ticket_data.ticket_type_id,
+        presale_id,
         user_id: ticket_data.user_id,
+        purchase_counters,
+        pass_id: None,
+        pass_name: None,
+        admissions: vec![],
+        deleted_at: None,
+        deleted_by: None,
     };
 
     let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
 
-    let res = collection.insert_one(&ticket).await?;
-    ticket.id = res.inserted_id.as_object_id();
+    // The ticket takes its seat before the event is reserved: the unique seat index refuses a taken seat,
+    // and the reservation refuses a seat that a concurrent capacity change has removed.
+    match collection.insert_one(&ticket).await {
+        Ok(res) => ticket.id = res.inserted_id.as_object_id(),
+        Err(e) => {
+            release_redeemed_access_code(ticket.event_id, access_code).await;
+            shift_purchase_counters(db, &ticket.purchase_counters, -1).await;
+            if is_duplicate_key_error(&e) {
+                return Err(Box::new(ConflictError(
+                    "This seat is already taken.".to_string(),
+                )));
+            }
+            return Err(e.into());
+        }
+    }
+
+    let reserved = match &offer {
+        Some(offer) => claim_waitlist_offer(db, offer).await,
+        None => {
+            update_event_remaining_seats_by_id_request(
+                ticket.event_id,
+                ticket.ticket_type_id,
+                -1,
+                Some(ticket.seat_number),
+            )
+            .await
+        }
+    };
+    if let Err(e) = reserved {
+        if let Err(e) = collection.delete_one(doc! { "_id": ticket.id }).await {
+            eprintln!("Failed to delete a ticket left without a seat: {}", e);
+        }
+        release_redeemed_access_code(ticket.event_id, access_code).await;
+        shift_purchase_counters(db, &ticket.purchase_counters, -1).await;
+        return Err(e);
+    }
+
+    if let Err(e) = create_payment(&ticket, &ticket_data.card(), currency).await {
+        if let Err(e) = collection
+            .update_one(
+                doc! { "_id": ticket.id },
+                doc! { "$set": { "status": to_bson(&TicketStatus::Cancelled)? } },
+            )
+            .await
+        {
+            eprintln!("Failed to cancel a ticket left unpaid: {}", e);
+        }
+        undo_seat_reservations(db, &ticket.seats(), std::slice::from_ref(&offer)).await;
+        release_redeemed_access_code(ticket.event_id, access_code).await;
+        shift_purchase_counters(db, &ticket.purchase_counters, -1).await;
+        return Err(e);
+    }
+
+    Ok(ticket)
+}
+
+// =============================================================================================================================
+
+pub async fn create_pass_ticket(
+    db: &Database,
+    pass_data: CreatePassTicketRequest,
+) -> Result<Ticket, Box<dyn std::error::Error>> {
+    pass_data.validate()?;
+
+    let pass = get_pass_request(pass_data.pass_id).await?;
+    let now = DateTime::now();
+    if pass.sale_starts_at.is_some_and(|starts_at| now < starts_at) {
+        return Err("The sale of this pass has not started yet.".into());
+    }
+    if pass.sale_ends_at.is_some_and(|ends_at| now > ends_at) {
+        return Err("The sale of this pass is over.".into());
+    }
+    let Some(first_event) = pass.events.first() else {
+        return Err("This pass grants entry to no event.".into());
+    };
+    let event_id = first_event.event_id;
+
+    // Every event of the pass is checked as a ticket of it would be, the seats are then reserved on all of them at once.
+    // Access codes only open the presale of single tickets, so a pass waits for the public sale of its events.
+    let mut admissions = vec![];
+    let mut offers = vec![];
+    let mut counters = vec![];
+    for (index, pass_event) in pass.events.iter().enumerate() {
+        let event = get_event_request(pass_event.event_id).await?;
+        if !event.status.is_on_sale() || event.date < now {
+            return Err(format!("{} is not on sale anymore.", event.title).into());
+        }
+        if event
+            .sale_starts_at
+            .is_some_and(|starts_at| now < starts_at)
+        {
+            return Err(format!("The sale of {} has not opened yet.", event.title).into());
+        }
+        if event.sale_ends_at.is_some_and(|ends_at| now > ends_at) {
+            return Err(format!("The sale of {} has closed.", event.title).into());
+        }
+
+        // A seat held for the buyer by the waitlist is already reserved, even when the event is sold out.
+        let offer = find_waitlist_offer(db, pass_event.event_id, pass_data.user_id)
+            .await?
+            .filter(|offer| offer.offered_ticket_type_id == pass_event.ticket_type_id);
+        if offer.is_none() && event.remaining_seats < 1 {
+            return Err(Box::new(ConflictError(format!(
+                "No more seats are avalaible for {}.",
+                event.title
+            ))));
+        }
+
+        let ticket_type = match pass_event.ticket_type_id {
+            Some(ticket_type_id) => {
+                let ticket_type = event
+                    .ticket_types
+                    .iter()
+                    .find(|t| t.id == ticket_type_id)
+                    .ok_or("A ticket type of this pass doesn't exist anymore.")?;
+                if ticket_type
+                    .sale_starts_at
+                    .is_some_and(|starts_at| now < starts_at)
+                {
+                    return Err(format!(
+                        "The sale of {} tickets for {} has not started yet.",
+                        ticket_type.name, event.title
+                    )
+                    .into());
+                }
+                if ticket_type
+                    .sale_ends_at
+                    .is_some_and(|ends_at| now > ends_at)
+                {
+                    return Err(format!(
+                        "The sale of {} tickets for {} is over.",
+                        ticket_type.name, event.title
+                    )
+                    .into());
+                }
+                if offer.is_none() && ticket_type.remaining < 1 {
+                    return Err(Box::new(ConflictError(format!(
+                        "No more {} tickets are avalaible for {}.",
+                        ticket_type.name, event.title
+                    ))));
+                }
+                Some(ticket_type)
+            }
+            None => None,
+        };
+
+        counters.extend(applicable_purchase_counters(
+            &event,
+            ticket_type,
+            pass_data.user_id,
+            &pass_data.card_number,
+            pass_data.household_address.as_deref(),
+        )?);
+        admissions.push(Admission {
+            event_id: pass_event.event_id,
+            ticket_type_id: pass_event.ticket_type_id,
+            price: admission_price(pass.price, pass.events.len(), index),
+            checked_in_at: None,
+            checked_in_by: None,
+        });
+        offers.push(offer);
+    }
+
+    let purchase_counters = reserve_purchase_counters(db, pass_data.user_id, counters).await?;
+
+    // A pass has no numbered seat, it is attached to its first event for the payment.
+    let mut ticket = Ticket {
+        id: None,
+        price: pass.price,
+        seat_number: 0,
+        status: TicketStatus::Pending,
+        purchase_date: DateTime::now(),
+        event_id,
+        ticket_type_id: None,
+        presale_id: None,
+        user_id: pass_data.user_id,
+        purchase_counters,
+        pass_id: Some(pass_data.pass_id),
+        pass_name: Some(pass.name),
+        admissions,
+        deleted_at: None,
+        deleted_by: None,
+    };
+
+    if let Err(e) = reserve_seats(db, &ticket, &offers).await {
+        shift_purchase_counters(db, &ticket.purchase_counters, -1).await;
+        return Err(e);
+    }
+
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    match collection.insert_one(&ticket).await {
+        Ok(res) => ticket.id = res.inserted_id.as_object_id(),
+        Err(e) => {
+            undo_seat_reservations(db, &ticket.seats(), &offers).await;
+            shift_purchase_counters(db, &ticket.purchase_counters, -1).await;
+            return Err(e.into());
+        }
+    }
 
-    create_payment(ticket.id.unwrap(), &ticket_data, event.price).await?;
-    update_event_remaining_seats_by_id_request(ticket.event_id, -1).await?;
+    if let Err(e) = create_payment(&ticket, &pass_data.card(), pass.currency).await {
+        if let Err(e) = collection
+            .update_one(
+                doc! { "_id": ticket.id },
+                doc! { "$set": { "status": to_bson(&TicketStatus::Cancelled)? } },
+            )
+            .await
+        {
+            eprintln!("Failed to cancel a pass left unpaid: {}", e);
+        }
+        undo_seat_reservations(db, &ticket.seats(), &offers).await;
+        shift_purchase_counters(db, &ticket.purchase_counters, -1).await;
+        return Err(e);
+    }
 
     Ok(ticket)
 }
 
 // =============================================================================================================================
 
+// Each event earns an equal part of the price of a pass, the first one also keeps what cannot be divided.
+fn admission_price(pass_price: u32, events: usize, index: usize) -> u32 {
+    let events = events.max(1) as u32;
+    let share = pass_price / events;
+    if index == 0 {
+        share + pass_price % events
+    } else {
+        share
+    }
+}
+
+// =============================================================================================================================
+
+// The availability is polled during an on-sale, so it is kept for a couple of seconds rather than rebuilt on every call.
+pub async fn get_seat_availability(
+    db: &Database,
+    event_id: String,
+) -> Result<SeatAvailability, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let now = DateTime::now();
+
+    let cached = seat_availability_cache().get(&event_id).cloned();
+    if let Some((cached_at, availability)) = cached {
+        if now.timestamp_millis() - cached_at.timestamp_millis() < SEAT_AVAILABILITY_CACHE_MILLIS {
+            return Ok(availability);
+        }
+    }
+
+    let availability = build_seat_availability(db, event_id, now).await?;
+
+    let mut cache = seat_availability_cache();
+    cache.retain(|_, (cached_at, _)| {
+        now.timestamp_millis() - cached_at.timestamp_millis() < SEAT_AVAILABILITY_CACHE_MILLIS
+    });
+    cache.insert(event_id, (now, availability.clone()));
+
+    Ok(availability)
+}
+
+// =============================================================================================================================
+
+fn seat_availability_cache() -> MutexGuard<'static, HashMap<ObjectId, (DateTime, SeatAvailability)>>
+{
+    SEAT_AVAILABILITY_CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// =============================================================================================================================
+
+async fn build_seat_availability(
+    db: &Database,
+    event_id: ObjectId,
+    now: DateTime,
+) -> Result<SeatAvailability, Box<dyn std::error::Error>> {
+    let event = get_event_request(event_id).await?;
+    if !event.status.is_public() {
+        return Err("No Event found with this id".into());
+    }
+
+    // Outside of the sale and presales no seat can be bought, so none is listed.
+    let on_sale = event.status.is_on_sale()
+        && event.date >= now
+        && (event.public_sale_is_open(now) || event.presale_is_open(now));
+    if !on_sale {
+        return Ok(SeatAvailability {
+            capacity: event.capacity,
+            on_sale,
+            available: 0,
+            held: vec![],
+            sold: vec![],
+            blocked: vec![],
+        });
+    }
+
+    let collection: Collection<TicketSeat> = db.collection(COLLECTION_NAME);
+    let filter = doc! {
+        "event_id": event_id,
+        "pass_id": null,
+        "deleted_at": null,
+        "status": { "$in": [to_bson(&TicketStatus::Pending)?, to_bson(&TicketStatus::Active)?] },
+    };
+
+    let mut cursor = collection
+        .find(filter)
+        .projection(doc! { "_id": 0, "seat_number": 1, "status": 1 })
+        .await?;
+
+    let mut held = vec![];
+    let mut sold = vec![];
+    while let Some(ticket) = cursor.try_next().await? {
+        match ticket.status {
+            TicketStatus::Pending => held.push(ticket.seat_number),
+            _ => sold.push(ticket.seat_number),
+        }
+    }
+    held.sort_unstable();
+    sold.sort_unstable();
+
+    // Seats that no ticket type can sell right now are blocked.
+    let purchasable_types: Vec<_> = event
+        .ticket_types
+        .iter()
+        .filter(|t| t.remaining > 0)
+        .filter(|t| t.sale_starts_at.is_none_or(|starts_at| now >= starts_at))
+        .filter(|t| t.sale_ends_at.is_none_or(|ends_at| now <= ends_at))
+        .collect();
+
+    let mut blocked = vec![];
+    let mut available = 0;
+    for seat_number in 1..=event.capacity {
+        if held.binary_search(&seat_number).is_ok() || sold.binary_search(&seat_number).is_ok() {
+            continue;
+        }
+
+        let can_be_sold = event.ticket_types.is_empty()
+            || purchasable_types
+                .iter()
+                .any(|t| t.contains_seat(seat_number));
+        if can_be_sold {
+            available += 1;
+        } else {
+            blocked.push(seat_number);
+        }
+    }
+
+    Ok(SeatAvailability {
+        capacity: event.capacity,
+        on_sale,
+        available,
+        held: seat_ranges(&held),
+        sold: seat_ranges(&sold),
+        blocked: seat_ranges(&blocked),
+    })
+}
+
+// =============================================================================================================================
+
+// A strong validator of the availability, quoted as HTTP expects it.
+pub fn seat_availability_etag(availability: &SeatAvailability) -> String {
+    let body = serde_json::to_vec(availability).unwrap_or_default();
+    format!("\"{:x}\"", Sha256::digest(body))
+}
+
+// =============================================================================================================================
+
+// Sorted seat numbers, grouped into ranges of consecutive seats.
+fn seat_ranges(seats: &[u32]) -> Vec<SeatRange> {
+    let mut ranges: Vec<SeatRange> = vec![];
+    for &seat_number in seats {
+        match ranges.last_mut() {
+            Some(range) if seat_number <= range.last + 1 => {
+                range.last = range.last.max(seat_number)
+            }
+            _ => ranges.push(SeatRange {
+                first: seat_number,
+                last: seat_number,
+            }),
+        }
+    }
+    ranges
+}
+
+// =============================================================================================================================
+
+// Seats are numbered from 1 to the capacity of the event.
+fn check_seat_number(seat_number: u32, capacity: u32) -> Result<(), BadRequestError> {
+    if seat_number == 0 || seat_number > capacity {
+        return Err(BadRequestError(format!(
+            "This seat doesn't exist, seats are numbered from 1 to {}.",
+            capacity
+        )));
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
 pub async fn update_ticket_seat_number_by_id(
     db: &Database,
     ticket_data: UpdateTicketSeatNumberByIdRequest,
@@ -138,7 +724,10 @@ pub async fn update_ticket_seat_number_by_id(
 
     let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
 
-    let ticket = match collection.find_one(doc! { "_id": ticket_id }).await? {
+    let ticket = match collection
+        .find_one(doc! { "_id": ticket_id, "deleted_at": null })
+        .await?
+    {
         Some(ticket) => ticket,
         None => return Err("No ticket with this id was found.".into()),
     };
@@ -146,6 +735,23 @@ pub async fn update_ticket_seat_number_by_id(
     if role != AuthRole::Admin && ticket.user_id != user_id {
         return Err("Only owner of a ticket or admin can update his seat number.".into());
     }
+    if !ticket.admissions.is_empty() {
+        return Err("A pass has no numbered seat.".into());
+    }
+
+    let event = get_event_request(ticket.event_id).await?;
+    check_seat_number(ticket_data.seat_number, event.capacity)?;
+
+    if let Some(ticket_type_id) = ticket.ticket_type_id {
+        let seat_in_range = event
+            .ticket_types
+            .iter()
+            .find(|t| t.id == ticket_type_id)
+            .is_none_or(|t| t.contains_seat(ticket_data.seat_number));
+        if !seat_in_range {
+            return Err("This seat is not available for this ticket type.".into());
+        }
+    }
 
     let update_doc = doc! {
       "seat_number": ticket_data.seat_number,
@@ -153,11 +759,19 @@ pub async fn update_ticket_seat_number_by_id(
 
     let update_doc = doc! { "$set": update_doc };
 
-    let updated_ticket = collection
-        .find_one_and_update(doc! { "_id": ticket_id }, update_doc)
+    let updated_ticket = match collection
+        .find_one_and_update(doc! { "_id": ticket_id, "deleted_at": null }, update_doc)
         .return_document(ReturnDocument::After)
-        .await?
-        .ok_or("Ticket not found after update")?;
+        .await
+    {
+        Ok(ticket) => ticket.ok_or("Ticket not found after update")?,
+        Err(e) if is_duplicate_key_error(&e) => {
+            return Err(Box::new(ConflictError(
+                "This seat is already taken.".to_string(),
+            )));
+        }
+        Err(e) => return Err(e.into()),
+    };
 
     let notification_data = TriggerNotificationRequest {
         message: String::from(format!(
@@ -191,7 +805,7 @@ pub async fn active_ticket_by_id(
     let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
 
     match collection
-        .find_one_and_update(doc! { "_id": ticket_id }, update_doc)
+        .find_one_and_update(doc! { "_id": ticket_id, "deleted_at": null }, update_doc)
         .return_document(ReturnDocument::After)
         .await?
     {
@@ -213,6 +827,68 @@ pub async fn active_ticket_by_id(
 
 // =============================================================================================================================
 
+pub async fn check_in_admission(
+    db: &Database,
+    ticket_id: String,
+    event_id: String,
+    role: AuthRole,
+    user_id: String,
+) -> Result<Ticket, Box<dyn std::error::Error>> {
+    let ticket_id = ObjectId::parse_str(&ticket_id)?;
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let checked_in_by = ObjectId::parse_str(&user_id)?;
+
+    if !matches!(role, AuthRole::Admin | AuthRole::Operator)
+        && !get_event_organiser_role(event_id, &user_id)
+            .await?
+            .is_some_and(|role| role.can_manage_tickets())
+    {
+        return Err("Only the organisers of the event can check in its passes".into());
+    }
+
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    let ticket = match collection
+        .find_one(doc! { "_id": ticket_id, "deleted_at": null })
+        .await?
+    {
+        Some(ticket) => ticket,
+        None => return Err("No ticket with this id was found.".into()),
+    };
+    if !ticket.admissions.iter().any(|a| a.event_id == event_id) {
+        return Err("This ticket is not a pass granting entry to this event.".into());
+    }
+    if !matches!(ticket.status, TicketStatus::Active) {
+        return Err("Only an active pass can be checked in.".into());
+    }
+
+    // The admission is only updated if it was not checked in yet, so a pass cannot be used twice for an event.
+    match collection
+        .find_one_and_update(
+            doc! {
+                "_id": ticket_id,
+                "status": to_bson(&TicketStatus::Active)?,
+                "deleted_at": null,
+                "admissions": { "$elemMatch": { "event_id": event_id, "checked_in_at": null } }
+            },
+            doc! {
+                "$set": {
+                    "admissions.$.checked_in_at": DateTime::now().try_to_rfc3339_string()?,
+                    "admissions.$.checked_in_by": checked_in_by
+                }
+            },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(ticket) => Ok(ticket),
+        None => Err(Box::new(ConflictError(
+            "This pass was already checked in for this event.".to_string(),
+        ))),
+    }
+}
+
+// =============================================================================================================================
+
 pub async fn cancel_ticket_by_id(
     db: &Database,
     ticket_id: String,
@@ -222,10 +898,17 @@ pub async fn cancel_ticket_by_id(
     let ticket_id = ObjectId::parse_str(&ticket_id)?;
     let user_id = ObjectId::parse_str(&user_id)?;
 
-    let filter = match role {
-        AuthRole::Admin => doc! { "_id": ticket_id },
-        _ => doc! { "_id": ticket_id, "user_id": user_id },
+    let mut filter = match role {
+        AuthRole::Admin => doc! { "_id": ticket_id, "deleted_at": null },
+        _ => {
+            organiser_ticket_filter(db, ticket_id, user_id, OrganisationRole::can_manage_tickets)
+                .await?
+        }
     };
+    filter.insert(
+        "status",
+        doc! { "$in": [to_bson(&TicketStatus::Pending)?, to_bson(&TicketStatus::Active)?] },
+    );
 
     let update_doc = doc! {
         "$set": {
@@ -241,7 +924,8 @@ pub async fn cancel_ticket_by_id(
         .await?
     {
         Some(ticket) => {
-            update_event_remaining_seats_by_id_request(ticket.event_id, 1).await?;
+            shift_purchase_counters(db, &ticket.purchase_counters, -1).await;
+            hand_over_freed_seats(db, &ticket).await;
 
             let notification_data = TriggerNotificationRequest {
                 message: String::from("Your ticket was successfully cancelled."),
@@ -254,7 +938,7 @@ pub async fn cancel_ticket_by_id(
 
             Ok(ticket)
         }
-        None => Err("No ticket found with provided id or unauthorized.".into()),
+        None => Err("No pending or active ticket found with provided id or unauthorized.".into()),
     }
 }
 
@@ -269,10 +953,14 @@ pub async fn refund_ticket_by_id(
     let ticket_id = ObjectId::parse_str(&ticket_id)?;
     let user_id = ObjectId::parse_str(&user_id)?;
 
-    let filter = match role {
-        AuthRole::Admin => doc! { "_id": ticket_id },
-        _ => doc! { "_id": ticket_id, "user_id": user_id },
+    let mut filter = match role {
+        AuthRole::Admin => doc! { "_id": ticket_id, "deleted_at": null },
+        _ => {
+            organiser_ticket_filter(db, ticket_id, user_id, OrganisationRole::can_manage_tickets)
+                .await?
+        }
     };
+    filter.insert("status", doc! { "$ne": to_bson(&TicketStatus::Refunded)? });
 
     let update_doc = doc! {
         "$set": {
@@ -284,10 +972,18 @@ pub async fn refund_ticket_by_id(
 
     match collection
         .find_one_and_update(filter, update_doc)
-        .return_document(ReturnDocument::After)
+        .return_document(ReturnDocument::Before)
         .await?
     {
-        Some(ticket) => {
+        Some(mut ticket) => {
+            let held_a_seat = ticket_holds_a_seat(&ticket);
+            ticket.status = TicketStatus::Refunded;
+
+            if held_a_seat {
+                shift_purchase_counters(db, &ticket.purchase_counters, -1).await;
+                hand_over_freed_seats(db, &ticket).await;
+            }
+
             let notification_data = TriggerNotificationRequest {
                 message: String::from("Your ticket will be refund soon."),
                 user_id: ticket.user_id.clone(),
@@ -297,25 +993,54 @@ pub async fn refund_ticket_by_id(
                 return Err(e);
             }
 
-            update_event_remaining_seats_by_id_request(ticket.event_id, 1).await?;
             Ok(ticket)
         }
-        None => Err("No ticket found with provided id or unauthorized.".into()),
+        None => Err("No unrefunded ticket found with provided id or unauthorized.".into()),
     }
 }
 
 // =============================================================================================================================
 
+pub async fn get_deleted_tickets(db: &Database) -> Result<Vec<Ticket>, Box<dyn std::error::Error>> {
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    let cursor = collection
+        .find(doc! { "deleted_at": { "$ne": null } })
+        .sort(doc! { "deleted_at": -1 })
+        .await?;
+
+    let tickets = cursor.try_collect().await?;
+
+    Ok(tickets)
+}
+
+// =============================================================================================================================
+
 pub async fn delete_ticket_by_id(
     db: &Database,
     id: String,
+    deleted_by: String,
 ) -> Result<Ticket, Box<dyn std::error::Error>> {
     let id = ObjectId::parse_str(&id)?;
+    let deleted_by = ObjectId::parse_str(&deleted_by)?;
     let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
 
-    match collection.find_one_and_delete(doc! { "_id": id}).await? {
+    let update_doc = doc! {
+        "$set": {
+            "deleted_at": DateTime::now(),
+            "deleted_by": deleted_by
+        }
+    };
+
+    match collection
+        .find_one_and_update(doc! { "_id": id, "deleted_at": null }, update_doc)
+        .return_document(ReturnDocument::After)
+        .await?
+    {
         Some(ticket) => {
-            update_event_remaining_seats_by_id_request(ticket.event_id, 1).await?;
+            if ticket_holds_a_seat(&ticket) {
+                shift_purchase_counters(db, &ticket.purchase_counters, -1).await;
+                hand_over_freed_seats(db, &ticket).await;
+            }
             Ok(ticket)
         }
         None => Err("No ticket with this id was found".into()),
@@ -324,53 +1049,1652 @@ pub async fn delete_ticket_by_id(
 
 // =============================================================================================================================
 
-async fn update_event_remaining_seats_by_id_request(
-    event_id: ObjectId,
-    delta: i32,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let client = reqwest::Client::new();
+pub async fn restore_ticket_by_id(
+    db: &Database,
+    id: String,
+) -> Result<Ticket, Box<dyn std::error::Error>> {
+    let id = ObjectId::parse_str(&id)?;
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
 
-    let internal_token = encode_internal_jwt()?;
-    let payload = json!({ "delta": delta });
+    let filter = doc! { "_id": id, "deleted_at": { "$ne": null } };
+    let deleted_ticket = match collection.find_one(filter.clone()).await? {
+        Some(ticket) => ticket,
+        None => return Err("No deleted ticket with this id was found".into()),
+    };
 
-    client
-        .patch(format!(
-            "http://events-service:8080/api/events/{}/update-seats",
-            event_id.to_hex()
-        ))
-        .header("Authorization", format!("Bearer {}", internal_token))
-        .json(&payload)
-        .send()
-        .await?;
+    // The seat of the ticket may have been sold again in the meantime, so it is reserved before the restore.
+    let holds_a_seat = ticket_holds_a_seat(&deleted_ticket);
+    if holds_a_seat {
+        reserve_seats(db, &deleted_ticket, &[]).await?;
+    }
 
-    Ok(())
+    let update_doc = doc! {
+        "$set": {
+            "deleted_at": null,
+            "deleted_by": null
+        }
+    };
+
+    let restored = collection
+        .find_one_and_update(filter, update_doc)
+        .return_document(ReturnDocument::After)
+        .await;
+    if holds_a_seat && !matches!(restored, Ok(Some(_))) {
+        release_reserved_seat(&deleted_ticket).await;
+    }
+
+    match restored {
+        Ok(Some(ticket)) => {
+            // A restore is an administrative decision, so it takes the purchase limits back without checking them.
+            if holds_a_seat {
+                shift_purchase_counters(db, &ticket.purchase_counters, 1).await;
+            }
+            Ok(ticket)
+        }
+        Ok(None) => Err("No deleted ticket with this id was found".into()),
+        Err(e) if is_duplicate_key_error(&e) => Err(Box::new(ConflictError(
+            "The seat of this ticket has been sold again.".to_string(),
+        ))),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn purge_deleted_tickets(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    let filter = doc! { "deleted_at": { "$lt": soft_delete_purge_cutoff() } };
+
+    collection.delete_many(filter).await?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_cancellation(
+    db: &Database,
+    event_id: String,
+    role: AuthRole,
+    user_id: String,
+) -> Result<EventCancellation, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+
+    if !matches!(role, AuthRole::Admin | AuthRole::Operator)
+        && get_event_organiser_role(event_id, &user_id)
+            .await?
+            .is_none()
+    {
+        return Err("Only the organisers of the event can follow its cancellation".into());
+    }
+
+    let collection: Collection<EventCancellation> = db.collection(CANCELLATIONS_COLLECTION_NAME);
+    match collection.find_one(doc! { "event_id": event_id }).await? {
+        Some(cancellation) => Ok(cancellation),
+        None => Err("No cancellation was started for this event".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn start_event_cancellation(
+    db: &Database,
+    event_id: String,
+    requested_by: Option<(String, AuthRole)>,
+) -> Result<EventCancellation, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+
+    if let Some((user_id, role)) = requested_by {
+        let organiser_role = get_event_organiser_role(event_id, &user_id).await?;
+        if role != AuthRole::Admin && !organiser_role.is_some_and(|r| r.can_manage_events()) {
+            return Err("Only the owners and managers of the event can refund its tickets".into());
+        }
+    }
+
+    let event = get_event_request(event_id).await?;
+    if event.status != EventStatus::Cancelled {
+        return Err("Only the tickets of a cancelled event can be refunded".into());
+    }
+
+    let collection: Collection<EventCancellation> = db.collection(CANCELLATIONS_COLLECTION_NAME);
+    let in_progress = to_bson(&EventCancellationStatus::InProgress)?;
+    if let Some(cancellation) = collection
+        .find_one(doc! { "event_id": event_id, "status": in_progress })
+        .await?
+    {
+        return Ok(cancellation);
+    }
+
+    // Starting it again only processes the tickets that were not refunded yet.
+    let update_doc = doc! {
+        "$set": {
+            "event_title": &event.title,
+            "status": to_bson(&EventCancellationStatus::Pending)?,
+            "failures": [],
+            "completed_at": null
+        },
+        "$setOnInsert": {
+            "total_tickets": 0,
+            "refunded_tickets": 0,
+            "created_at": DateTime::now().try_to_rfc3339_string()?,
+            "started_at": null
+        }
+    };
+
+    match collection
+        .find_one_and_update(doc! { "event_id": event_id }, update_doc)
+        .upsert(true)
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(cancellation) => Ok(cancellation),
+        None => Err("Failed to start the cancellation of the event".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn notify_ticket_holders(
+    db: &Database,
+    event_id: String,
+    notification: NotifyTicketHoldersRequest,
+) -> Result<TicketHoldersNotification, Box<dyn std::error::Error>> {
+    notification.validate()?;
+
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+
+    let mut statuses = vec![to_bson(&TicketStatus::Active)?];
+    if !notification.active_only {
+        statuses.push(to_bson(&TicketStatus::Pending)?);
+    }
+
+    let mut filter = event_tickets_filter(event_id, None);
+    filter.insert("status", doc! { "$in": statuses });
+    filter.insert("deleted_at", Bson::Null);
+
+    let user_ids: Vec<ObjectId> = collection
+        .distinct("user_id", filter)
+        .await?
+        .into_iter()
+        .filter_map(|user_id| user_id.as_object_id())
+        .collect();
+
+    // Without a delivery key, the holders are notified in the background so that large events do not hold the request.
+    let Some(delivery_key) = notification.delivery_key.clone() else {
+        let recipients = user_ids.len();
+        actix_rt::spawn(async move {
+            for user_id in user_ids {
+                let notification_data = TriggerNotificationRequest {
+                    message: notification.message.clone(),
+                    user_id,
+                };
+                if let Err(e) = trigger_notification(notification_data).await {
+                    eprintln!(
+                        "Failed to notify the ticket holder {}: {}",
+                        user_id.to_hex(),
+                        e
+                    );
+                }
+            }
+        });
+
+        return Ok(TicketHoldersNotification {
+            recipients,
+            failed: None,
+        });
+    };
+
+    // With a delivery key, each holder is claimed before being notified and given back on failure, so sending the
+    // notification again, after a failure or a restart, only reaches the holders it missed.
+    let deliveries: Collection<Document> = db.collection(DELIVERIES_COLLECTION_NAME);
+    let mut recipients = 0;
+    let mut failed = 0;
+    for user_id in user_ids {
+        let claim = doc! { "delivery_key": &delivery_key, "user_id": user_id };
+        let mut delivery = claim.clone();
+        delivery.insert("notified_at", DateTime::now().try_to_rfc3339_string()?);
+        match deliveries.insert_one(delivery).await {
+            Ok(_) => recipients += 1,
+            Err(e) if is_duplicate_key_error(&e) => continue,
+            Err(e) => return Err(e.into()),
+        }
+
+        let notification_data = TriggerNotificationRequest {
+            message: notification.message.clone(),
+            user_id,
+        };
+        if let Err(e) = trigger_notification(notification_data).await {
+            eprintln!(
+                "Failed to notify the ticket holder {}: {}",
+                user_id.to_hex(),
+                e
+            );
+            failed += 1;
+            if let Err(e) = deliveries.delete_one(claim).await {
+                eprintln!(
+                    "Failed to give back the delivery of ticket holder {}: {}",
+                    user_id.to_hex(),
+                    e
+                );
+            }
+        }
+    }
+
+    Ok(TicketHoldersNotification {
+        recipients,
+        failed: Some(failed),
+    })
+}
+
+// =============================================================================================================================
+
+pub async fn broadcast_to_ticket_holders(
+    db: &Database,
+    event_id: String,
+    broadcast: BroadcastRequest,
+    role: AuthRole,
+    user_id: String,
+) -> Result<Broadcast, Box<dyn std::error::Error>> {
+    broadcast.validate()?;
+
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let sender_id = ObjectId::parse_str(&user_id)?;
+
+    if !matches!(role, AuthRole::Admin)
+        && !get_event_organiser_role(event_id, &user_id)
+            .await?
+            .is_some_and(|role| role.can_manage_events())
+    {
+        return Err("Only the owners and managers of the event can send broadcasts".into());
+    }
+
+    if let Some(ticket_type_id) = broadcast.ticket_type_id {
+        let event = get_event_request(event_id).await?;
+        if !event.ticket_types.iter().any(|t| t.id == ticket_type_id) {
+            return Err("This ticket type doesn't exist for this event.".into());
+        }
+    }
+    let mut filter = event_tickets_filter(event_id, broadcast.ticket_type_id);
+    filter.insert("status", to_bson(&TicketStatus::Active)?);
+    filter.insert("deleted_at", Bson::Null);
+
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    let user_ids: Vec<String> = collection
+        .distinct("user_id", filter)
+        .await?
+        .into_iter()
+        .filter_map(|user_id| user_id.as_object_id())
+        .map(|user_id| user_id.to_hex())
+        .collect();
+    if user_ids.is_empty() {
+        return Err("No active ticket holder matches this broadcast.".into());
+    }
+
+    let payload = json!({
+        "event_id": event_id.to_hex(),
+        "ticket_type_id": broadcast.ticket_type_id.map(|id| id.to_hex()),
+        "sender_id": sender_id.to_hex(),
+        "message": broadcast.message,
+        "user_ids": user_ids
+    });
+    create_broadcast_request(payload).await
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_broadcasts(
+    event_id: String,
+    role: AuthRole,
+    user_id: String,
+) -> Result<Vec<BroadcastReport>, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+
+    if !matches!(role, AuthRole::Admin | AuthRole::Operator)
+        && get_event_organiser_role(event_id, &user_id)
+            .await?
+            .is_none()
+    {
+        return Err("Only the organisers of the event can access its broadcasts".into());
+    }
+
+    get_event_broadcasts_request(event_id).await
+}
+
+// =============================================================================================================================
+
+pub async fn get_ticket_statistics(
+    db: &Database,
+    request: TicketStatisticsRequest,
+) -> Result<Vec<EventTicketStatistics>, Box<dyn std::error::Error>> {
+    request.validate()?;
+
+    let event_ids = request
+        .event_ids
+        .iter()
+        .map(ObjectId::parse_str)
+        .collect::<Result<Vec<ObjectId>, _>>()?;
+    let mut statistics: Vec<EventTicketStatistics> = event_ids
+        .iter()
+        .map(|event_id| EventTicketStatistics {
+            event_id: event_id.to_hex(),
+            pending: 0,
+            active: 0,
+            cancelled: 0,
+            refunded: 0,
+        })
+        .collect();
+
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    let mut cursor = collection
+        .aggregate(vec![
+            doc! {
+                "$match": {
+                    "$or": [
+                        { "event_id": { "$in": &event_ids } },
+                        { "admissions.event_id": { "$in": &event_ids } }
+                    ],
+                    "deleted_at": null
+                }
+            },
+            // A pass is counted once for each of its events.
+            doc! {
+                "$project": {
+                    "status": 1,
+                    "event_id": {
+                        "$cond": [
+                            { "$gt": [{ "$size": { "$ifNull": ["$admissions", []] } }, 0] },
+                            "$admissions.event_id",
+                            ["$event_id"]
+                        ]
+                    }
+                }
+            },
+            doc! { "$unwind": "$event_id" },
+            doc! { "$match": { "event_id": { "$in": &event_ids } } },
+            doc! {
+                "$group": {
+                    "_id": { "event_id": "$event_id", "status": "$status" },
+                    "count": { "$sum": 1 }
+                }
+            },
+        ])
+        .await?;
+    while let Some(group) = cursor.try_next().await? {
+        let key = group.get_document("_id")?;
+        let event_id = key.get_object_id("event_id")?.to_hex();
+        let Some(event) = statistics
+            .iter_mut()
+            .find(|event| event.event_id == event_id)
+        else {
+            continue;
+        };
+        let count = group.get_i32("count")? as u32;
+
+        match mongodb::bson::from_bson(key.get("status").cloned().unwrap_or(Bson::Null))? {
+            TicketStatus::Pending => event.pending += count,
+            TicketStatus::Active => event.active += count,
+            TicketStatus::Cancelled => event.cancelled += count,
+            TicketStatus::Refunded => event.refunded += count,
+        }
+    }
+
+    Ok(statistics)
+}
+
+// =============================================================================================================================
+
+pub async fn create_calendar_feed(
+    db: &Database,
+    user_id: String,
+) -> Result<CalendarFeedUrl, Box<dyn std::error::Error>> {
+    let user_id = ObjectId::parse_str(&user_id)?;
+    let token: String = rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect();
+
+    // A user has a single feed, creating it again replaces its token and revokes the previous URL.
+    let collection: Collection<CalendarFeed> = db.collection(CALENDAR_FEEDS_COLLECTION_NAME);
+    let feed = collection
+        .find_one_and_update(
+            doc! { "user_id": user_id },
+            doc! {
+                "$set": {
+                    "token": &token,
+                    "created_at": DateTime::now().try_to_rfc3339_string()?
+                }
+            },
+        )
+        .upsert(true)
+        .return_document(ReturnDocument::After)
+        .await?
+        .ok_or("The calendar feed could not be created")?;
+
+    Ok(CalendarFeedUrl {
+        url: format!("{}{}", *CALENDAR_FEED_URL, feed.token),
+        created_at: feed.created_at,
+    })
+}
+
+// =============================================================================================================================
+
+pub async fn delete_calendar_feed(
+    db: &Database,
+    user_id: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let user_id = ObjectId::parse_str(&user_id)?;
+    let collection: Collection<CalendarFeed> = db.collection(CALENDAR_FEEDS_COLLECTION_NAME);
+
+    let result = collection.delete_one(doc! { "user_id": user_id }).await?;
+    if result.deleted_count == 0 {
+        return Err("You have no calendar feed".into());
+    }
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn get_calendar_feed(
+    db: &Database,
+    token: String,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let feeds: Collection<CalendarFeed> = db.collection(CALENDAR_FEEDS_COLLECTION_NAME);
+    let feed = match feeds.find_one(doc! { "token": &token }).await? {
+        Some(feed) => feed,
+        None => {
+            return Err(Box::new(NotFoundError(
+                "This calendar feed does not exist".to_string(),
+            )));
+        }
+    };
+
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    let filter = doc! {
+        "user_id": feed.user_id,
+        "status": to_bson(&TicketStatus::Active)?,
+        "deleted_at": null
+    };
+    let mut event_ids = collection.distinct("event_id", filter.clone()).await?;
+    event_ids.extend(collection.distinct("admissions.event_id", filter).await?);
+    let mut event_ids: Vec<ObjectId> = event_ids
+        .into_iter()
+        .filter_map(|event_id| event_id.as_object_id())
+        .collect();
+    event_ids.sort_unstable();
+    event_ids.dedup();
+
+    let mut events = vec![];
+    for event_id in event_ids {
+        match get_event_request(event_id).await {
+            Ok(event) => events.push(CalendarEvent {
+                event_id: event_id.to_hex(),
+                title: event.title,
+                description: event.description,
+                location: event.location,
+                start: event.date,
+                status: event.status,
+                geo: event
+                    .geo_location
+                    .map(|point| calendar_geo(point.coordinates)),
+            }),
+            Err(e) => eprintln!(
+                "Failed to add the event {} to a calendar feed: {}",
+                event_id.to_hex(),
+                e
+            ),
+        }
+    }
+    events.sort_by_key(|event| event.start);
+
+    Ok(build_calendar("My tickets", &events))
+}
+
+// =============================================================================================================================
+
+pub async fn get_purchase_limit_rejections(
+    db: &Database,
+    event_id: String,
+    role: AuthRole,
+    user_id: String,
+) -> Result<Vec<PurchaseLimitRejection>, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+
+    if !matches!(role, AuthRole::Admin | AuthRole::Operator)
+        && get_event_organiser_role(event_id, &user_id)
+            .await?
+            .is_none()
+    {
+        return Err("Only the organisers of the event can access its refused purchases".into());
+    }
+
+    let collection: Collection<PurchaseLimitRejection> =
+        db.collection(PURCHASE_REJECTIONS_COLLECTION_NAME);
+    let rejections = collection
+        .find(doc! { "event_id": event_id })
+        .sort(doc! { "created_at": -1 })
+        .await?
+        .try_collect()
+        .await?;
+
+    Ok(rejections)
+}
+
+// =============================================================================================================================
+
+pub async fn process_event_cancellations(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    let collection: Collection<EventCancellation> = db.collection(CANCELLATIONS_COLLECTION_NAME);
+
+    // Cancellations left in progress by a crashed run are picked up again.
+    let stale_before = DateTime::from_millis(
+        DateTime::now().timestamp_millis() - CANCELLATION_STALE_AFTER_MINUTES * 60 * 1000,
+    );
+    let filter = doc! {
+        "$or": [
+            { "status": to_bson(&EventCancellationStatus::Pending)? },
+            {
+                "status": to_bson(&EventCancellationStatus::InProgress)?,
+                "started_at": { "$lt": stale_before.try_to_rfc3339_string()? }
+            }
+        ]
+    };
+
+    while let Some(cancellation) = collection
+        .find_one_and_update(
+            filter.clone(),
+            doc! {
+                "$set": {
+                    "status": to_bson(&EventCancellationStatus::InProgress)?,
+                    "started_at": DateTime::now().try_to_rfc3339_string()?
+                }
+            },
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        println!(
+            "Processing the cancellation of the event {}",
+            cancellation.event_id
+        );
+        if let Err(e) = run_event_cancellation(db, &cancellation).await {
+            eprintln!(
+                "Failed to process the cancellation of the event {}: {:?}",
+                cancellation.event_id, e
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+async fn run_event_cancellation(
+    db: &Database,
+    cancellation: &EventCancellation,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // The title is kept with the cancellation, as a deleted event cannot be retrieved anymore.
+    let event_title = match &cancellation.event_title {
+        Some(event_title) => event_title.clone(),
+        None => get_event_request(cancellation.event_id).await?.title,
+    };
+
+    let tickets_collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    let tickets: Vec<Ticket> = tickets_collection
+        .find(doc! {
+            "$and": [
+                event_tickets_filter(cancellation.event_id, None),
+                {
+                    "status": { "$in": [to_bson(&TicketStatus::Pending)?, to_bson(&TicketStatus::Active)?] },
+                    "deleted_at": null
+                }
+            ]
+        })
+        .await?
+        .try_collect()
+        .await?;
+
+    let collection: Collection<EventCancellation> = db.collection(CANCELLATIONS_COLLECTION_NAME);
+    let filter = doc! { "_id": cancellation.id };
+    let total_tickets = cancellation.refunded_tickets + tickets.len() as u32;
+    collection
+        .update_one(
+            filter.clone(),
+            doc! { "$set": { "total_tickets": total_tickets } },
+        )
+        .await?;
+
+    let mut has_failures = false;
+    for ticket in tickets {
+        let update_doc =
+            match refund_cancelled_event_ticket(db, &ticket, cancellation.event_id, &event_title)
+                .await
+            {
+                Ok(()) => doc! { "$inc": { "refunded_tickets": 1 } },
+                Err(e) => {
+                    has_failures = true;
+                    let failure = EventCancellationFailure {
+                        ticket_id: ticket.id.unwrap_or_default(),
+                        error: e.to_string(),
+                    };
+                    doc! { "$push": { "failures": to_bson(&failure)? } }
+                }
+            };
+        collection.update_one(filter.clone(), update_doc).await?;
+    }
+
+    // The seats of a cancelled event are not sold anymore, so nobody is left waiting for them.
+    db.collection::<WaitlistEntry>(WAITLIST_COLLECTION_NAME)
+        .update_many(
+            doc! { "event_id": cancellation.event_id, "status": { "$in": active_waitlist_statuses()? } },
+            doc! { "$set": { "status": to_bson(&WaitlistStatus::Expired)? } },
+        )
+        .await?;
+
+    let status = if has_failures {
+        EventCancellationStatus::CompletedWithFailures
+    } else {
+        EventCancellationStatus::Completed
+    };
+    collection
+        .update_one(
+            filter,
+            doc! {
+                "$set": {
+                    "status": to_bson(&status)?,
+                    "completed_at": DateTime::now().try_to_rfc3339_string()?
+                }
+            },
+        )
+        .await?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+// A pass is refunded as a whole when one of its events is cancelled, its seats on the other events go back to the sale.
+async fn refund_cancelled_event_ticket(
+    db: &Database,
+    ticket: &Ticket,
+    event_id: ObjectId,
+    event_title: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let ticket_id = ticket.id.ok_or("The ticket has no id")?;
+
+    refund_payment_request(ticket_id, "The event was cancelled.").await?;
+
+    let collection: Collection<Ticket> = db.collection(COLLECTION_NAME);
+    let refunded = collection
+        .find_one_and_update(
+            doc! {
+                "_id": ticket_id,
+                "status": { "$in": [to_bson(&TicketStatus::Pending)?, to_bson(&TicketStatus::Active)?] }
+            },
+            doc! { "$set": { "status": to_bson(&TicketStatus::Refunded)? } },
+        )
+        .await?;
+
+    if let Some(pass) = refunded.filter(|refunded| !refunded.admissions.is_empty()) {
+        shift_purchase_counters(db, &pass.purchase_counters, -1).await;
+        for (other_event_id, ticket_type_id) in pass.seats() {
+            if other_event_id == event_id {
+                continue;
+            }
+            let handover = hand_over_freed_seat(db, other_event_id, ticket_type_id)
+                .await
+                .map_err(|e| e.to_string());
+            if let Err(error) = handover {
+                record_pending_handover(db, ticket_id, other_event_id, ticket_type_id, error).await;
+            }
+        }
+    }
+
+    let event_title: String = event_title.chars().take(40).collect();
+    let ticket_kind = if ticket.admissions.is_empty() {
+        "ticket"
+    } else {
+        "pass"
+    };
+    let notification_data = TriggerNotificationRequest {
+        message: format!(
+            "{} was cancelled, your {} has been refunded.",
+            event_title, ticket_kind
+        ),
+        user_id: ticket.user_id,
+    };
+
+    // The refund is done at this point, a lost notification must not report it as failed.
+    if let Err(e) = trigger_notification(notification_data).await {
+        eprintln!(
+            "Failed to notify the refund of ticket {}: {}",
+            ticket_id.to_hex(),
+            e
+        );
+    }
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn join_waitlist(
+    db: &Database,
+    event_id: String,
+    user_id: String,
+    request: JoinWaitlistRequest,
+) -> Result<WaitlistEntry, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let event = get_event_request(event_id).await?;
+    if !event.status.is_on_sale() || event.date < DateTime::now() {
+        return Err("Tickets for this event are not on sale.".into());
+    }
+    let sold_out = match request.ticket_type_id {
+        Some(ticket_type_id) => {
+            let ticket_type = event
+                .ticket_types
+                .iter()
+                .find(|t| t.id == ticket_type_id)
+                .ok_or("This ticket type doesn't exist for this event.")?;
+            event.remaining_seats < 1 || ticket_type.remaining < 1
+        }
+        None => event.remaining_seats < 1,
+    };
+    if !sold_out {
+        return Err("Seats are still available for this event, buy one instead.".into());
+    }
+
+    let mut entry = WaitlistEntry {
+        id: None,
+        event_id,
+        ticket_type_id: request.ticket_type_id,
+        user_id,
+        status: WaitlistStatus::Waiting,
+        offered_ticket_type_id: None,
+        created_at: DateTime::now(),
+        offered_at: None,
+        offer_expires_at: None,
+    };
+
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+    match collection.insert_one(&entry).await {
+        Ok(res) => entry.id = res.inserted_id.as_object_id(),
+        Err(e) if is_duplicate_key_error(&e) => {
+            return Err(Box::new(ConflictError(
+                "You are already on the waitlist of this event.".to_string(),
+            )));
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(entry)
+}
+
+// =============================================================================================================================
+
+pub async fn get_waitlist_position(
+    db: &Database,
+    event_id: String,
+    user_id: String,
+) -> Result<WaitlistPosition, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+    let entry = match collection
+        .find_one(doc! {
+            "event_id": event_id,
+            "user_id": user_id,
+            "status": { "$in": active_waitlist_statuses()? }
+        })
+        .await?
+    {
+        Some(entry) => entry,
+        None => return Err("You are not on the waitlist of this event".into()),
+    };
+
+    // The line is served in the order of arrival, which is the order of the ids.
+    let position = match entry.status {
+        WaitlistStatus::Waiting => Some(
+            collection
+                .count_documents(doc! {
+                    "event_id": event_id,
+                    "status": to_bson(&WaitlistStatus::Waiting)?,
+                    "_id": { "$lt": entry.id }
+                })
+                .await?
+                + 1,
+        ),
+        _ => None,
+    };
+
+    Ok(WaitlistPosition { entry, position })
+}
+
+// =============================================================================================================================
+
+pub async fn get_event_waitlist(
+    db: &Database,
+    event_id: String,
+    role: AuthRole,
+    user_id: String,
+) -> Result<Vec<WaitlistEntry>, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+
+    if !matches!(role, AuthRole::Admin | AuthRole::Operator)
+        && get_event_organiser_role(event_id, &user_id)
+            .await?
+            .is_none()
+    {
+        return Err("Only the organisers of the event can access its waitlist".into());
+    }
+
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+    let entries = collection
+        .find(doc! { "event_id": event_id, "status": { "$in": active_waitlist_statuses()? } })
+        .sort(doc! { "_id": 1 })
+        .await?
+        .try_collect()
+        .await?;
+
+    Ok(entries)
+}
+
+// =============================================================================================================================
+
+pub async fn leave_waitlist(
+    db: &Database,
+    event_id: String,
+    user_id: String,
+) -> Result<WaitlistEntry, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let user_id = ObjectId::parse_str(&user_id)?;
+
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+    let entry = match collection
+        .find_one_and_update(
+            doc! {
+                "event_id": event_id,
+                "user_id": user_id,
+                "status": { "$in": active_waitlist_statuses()? }
+            },
+            doc! { "$set": { "status": to_bson(&WaitlistStatus::Left)? } },
+        )
+        .return_document(ReturnDocument::Before)
+        .await?
+    {
+        Some(entry) => entry,
+        None => return Err("You are not on the waitlist of this event".into()),
+    };
+
+    // A seat held for the user goes to the next person in line.
+    if entry.status == WaitlistStatus::Offered {
+        hand_over_freed_seat(db, event_id, entry.offered_ticket_type_id).await?;
+    }
+
+    Ok(entry)
+}
+
+// =============================================================================================================================
+
+// An expired offer stays Offered until its seat is handed over, so a failed handover is retried by the next run.
+pub async fn expire_waitlist_offers(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+    let expired_offers: Vec<WaitlistEntry> = collection
+        .find(doc! {
+            "status": to_bson(&WaitlistStatus::Offered)?,
+            "offer_expires_at": { "$lt": DateTime::now().try_to_rfc3339_string()? }
+        })
+        .await?
+        .try_collect()
+        .await?;
+
+    for entry in expired_offers {
+        if let Err(e) = hand_over_freed_seat(db, entry.event_id, entry.offered_ticket_type_id).await
+        {
+            eprintln!(
+                "Failed to hand over the seat held for event {}: {}",
+                entry.event_id.to_hex(),
+                e
+            );
+            continue;
+        }
+
+        collection
+            .update_one(
+                doc! { "_id": entry.id, "status": to_bson(&WaitlistStatus::Offered)? },
+                doc! { "$set": { "status": to_bson(&WaitlistStatus::Expired)? } },
+            )
+            .await?;
+
+        let notification_data = TriggerNotificationRequest {
+            message: "The seat held for you on a waitlist has expired and was offered to the next person.".to_string(),
+            user_id: entry.user_id,
+        };
+        if let Err(e) = trigger_notification(notification_data).await {
+            eprintln!(
+                "Failed to notify the expired waitlist offer of {}: {}",
+                entry.user_id.to_hex(),
+                e
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+// The seats added by a capacity increase are offered to the users waiting for the event, as freed seats would be.
+// Each seat is taken back from the sale before it is held, so a seat bought in the meantime is not offered.
+pub async fn offer_waitlist_seats(
+    db: &Database,
+    event_id: String,
+    request: OfferWaitlistSeatsRequest,
+) -> Result<WaitlistSeatsOffer, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event_id)?;
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+    let waiting_filter = doc! {
+        "event_id": event_id,
+        "status": to_bson(&WaitlistStatus::Waiting)?,
+        "ticket_type_id": null
+    };
+
+    let mut offered = 0;
+    while offered < request.seats && collection.count_documents(waiting_filter.clone()).await? > 0 {
+        if update_event_remaining_seats_by_id_request(event_id, None, -1, None)
+            .await
+            .is_err()
+        {
+            break;
+        }
+        if let Err(e) = hand_over_freed_seat(db, event_id, None).await {
+            release_seats(&[(event_id, None)]).await;
+            return Err(e);
+        }
+        offered += 1;
+    }
+
+    Ok(WaitlistSeatsOffer { offered })
+}
+
+// =============================================================================================================================
+
+// A freed seat is held for the next person waiting for the event, or returned to the sale when nobody is.
+// The seats are handed over once the change of the ticket is committed, so a failed hand-over does not fail the request:
+// it is recorded and retried in the background.
+async fn hand_over_freed_seats(db: &Database, ticket: &Ticket) {
+    for (event_id, ticket_type_id) in ticket.seats() {
+        let handover = hand_over_freed_seat(db, event_id, ticket_type_id)
+            .await
+            .map_err(|e| e.to_string());
+        if let Err(error) = handover {
+            let ticket_id = ticket.id.unwrap_or_default();
+            record_pending_handover(db, ticket_id, event_id, ticket_type_id, error).await;
+        }
+    }
+}
+
+// =============================================================================================================================
+
+async fn record_pending_handover(
+    db: &Database,
+    ticket_id: ObjectId,
+    event_id: ObjectId,
+    ticket_type_id: Option<ObjectId>,
+    error: String,
+) {
+    eprintln!(
+        "Failed to hand over the seat of ticket {} on event {}, it will be retried: {}",
+        ticket_id.to_hex(),
+        event_id.to_hex(),
+        error
+    );
+
+    let collection: Collection<Document> = db.collection(PENDING_HANDOVERS_COLLECTION_NAME);
+    let failed_at = match DateTime::now().try_to_rfc3339_string() {
+        Ok(failed_at) => failed_at,
+        Err(e) => {
+            eprintln!("Failed to record the pending hand-over: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = collection
+        .update_one(
+            doc! { "ticket_id": ticket_id, "event_id": event_id },
+            doc! {
+                "$set": { "ticket_type_id": ticket_type_id, "last_error": &error },
+                "$setOnInsert": { "failed_at": failed_at }
+            },
+        )
+        .upsert(true)
+        .await
+    {
+        eprintln!(
+            "Failed to record the pending hand-over of ticket {} on event {}: {}",
+            ticket_id.to_hex(),
+            event_id.to_hex(),
+            e
+        );
+    }
+}
+
+// =============================================================================================================================
+
+// A seat that cannot go back anymore, because the capacity of the event was reduced, or that could not be handed over
+// for a day, for instance because the event was deleted, is given up.
+pub async fn retry_pending_handovers(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    let collection: Collection<PendingHandover> = db.collection(PENDING_HANDOVERS_COLLECTION_NAME);
+    let pending_handovers: Vec<PendingHandover> =
+        collection.find(doc! {}).await?.try_collect().await?;
+    let give_up_before = DateTime::from_millis(
+        DateTime::now().timestamp_millis() - PENDING_HANDOVER_RETRY_HOURS * 60 * 60 * 1000,
+    );
+
+    for handover in pending_handovers {
+        let result = hand_over_freed_seat(db, handover.event_id, handover.ticket_type_id)
+            .await
+            .map_err(|e| (e.is::<ConflictError>(), e.to_string()));
+        match result {
+            Ok(()) => {}
+            Err((conflict, error)) if conflict || handover.failed_at < give_up_before => {
+                eprintln!(
+                    "Gave up handing over the seat of ticket {} on event {}: {}",
+                    handover.ticket_id.to_hex(),
+                    handover.event_id.to_hex(),
+                    error
+                );
+            }
+            Err((_, error)) => {
+                collection
+                    .update_one(
+                        doc! { "_id": handover.id },
+                        doc! { "$set": { "last_error": error } },
+                    )
+                    .await?;
+                continue;
+            }
+        }
+        collection.delete_one(doc! { "_id": handover.id }).await?;
+    }
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+async fn hand_over_freed_seat(
+    db: &Database,
+    event_id: ObjectId,
+    ticket_type_id: Option<ObjectId>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut ticket_types = vec![Bson::Null];
+    if let Some(ticket_type_id) = ticket_type_id {
+        ticket_types.push(Bson::ObjectId(ticket_type_id));
+    }
+
+    let now = DateTime::now();
+    let expires_at =
+        DateTime::from_millis(now.timestamp_millis() + WAITLIST_HOLD_MINUTES * 60 * 1000);
+
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+    let offered = collection
+        .find_one_and_update(
+            doc! {
+                "event_id": event_id,
+                "status": to_bson(&WaitlistStatus::Waiting)?,
+                "ticket_type_id": { "$in": ticket_types }
+            },
+            doc! {
+                "$set": {
+                    "status": to_bson(&WaitlistStatus::Offered)?,
+                    "offered_ticket_type_id": ticket_type_id,
+                    "offered_at": now.try_to_rfc3339_string()?,
+                    "offer_expires_at": expires_at.try_to_rfc3339_string()?
+                }
+            },
+        )
+        .sort(doc! { "_id": 1 })
+        .await?;
+
+    match offered {
+        Some(entry) => {
+            let notification_data = TriggerNotificationRequest {
+                message: format!(
+                    "A seat is held for you on a waitlist, buy it within {} minutes.",
+                    WAITLIST_HOLD_MINUTES
+                ),
+                user_id: entry.user_id,
+            };
+            if let Err(e) = trigger_notification(notification_data).await {
+                eprintln!(
+                    "Failed to notify the waitlist offer of {}: {}",
+                    entry.user_id.to_hex(),
+                    e
+                );
+            }
+            Ok(())
+        }
+        None => update_event_remaining_seats_by_id_request(event_id, ticket_type_id, 1, None).await,
+    }
+}
+
+// =============================================================================================================================
+
+async fn find_waitlist_offer(
+    db: &Database,
+    event_id: ObjectId,
+    user_id: ObjectId,
+) -> Result<Option<WaitlistEntry>, Box<dyn std::error::Error>> {
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+
+    let offer = collection
+        .find_one(doc! {
+            "event_id": event_id,
+            "user_id": user_id,
+            "status": to_bson(&WaitlistStatus::Offered)?,
+            "offer_expires_at": { "$gt": DateTime::now().try_to_rfc3339_string()? }
+        })
+        .await?;
+
+    Ok(offer)
+}
+
+// =============================================================================================================================
+
+async fn claim_waitlist_offer(
+    db: &Database,
+    offer: &WaitlistEntry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+
+    let claimed = collection
+        .update_one(
+            doc! {
+                "_id": offer.id,
+                "status": to_bson(&WaitlistStatus::Offered)?,
+                "offer_expires_at": { "$gt": DateTime::now().try_to_rfc3339_string()? }
+            },
+            doc! { "$set": { "status": to_bson(&WaitlistStatus::Claimed)? } },
+        )
+        .await?;
+
+    match claimed.modified_count {
+        1 => Ok(()),
+        _ => Err(Box::new(ConflictError(
+            "The seat held for you on the waitlist has expired.".to_string(),
+        ))),
+    }
+}
+
+// =============================================================================================================================
+
+// A seat taken from the sale goes back to it, a seat claimed from the waitlist goes back to the buyer's offer.
+async fn undo_seat_reservations(
+    db: &Database,
+    seats: &[(ObjectId, Option<ObjectId>)],
+    offers: &[Option<WaitlistEntry>],
+) {
+    for (index, seat) in seats.iter().enumerate() {
+        match offers.get(index).and_then(Option::as_ref) {
+            Some(offer) => reopen_waitlist_offer(db, offer).await,
+            None => release_seats(std::slice::from_ref(seat)).await,
+        }
+    }
+}
+
+// =============================================================================================================================
+
+// The offer is given back until it expires, then the cron job hands the seat over.
+async fn reopen_waitlist_offer(db: &Database, offer: &WaitlistEntry) {
+    let collection: Collection<WaitlistEntry> = db.collection(WAITLIST_COLLECTION_NAME);
+    let reopened = match to_bson(&WaitlistStatus::Offered) {
+        Ok(status) => {
+            collection
+                .update_one(
+                    doc! { "_id": offer.id },
+                    doc! { "$set": { "status": status } },
+                )
+                .await
+        }
+        Err(e) => {
+            eprintln!("Failed to reopen the waitlist offer: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = reopened {
+        eprintln!(
+            "Failed to reopen the waitlist offer of {}: {}",
+            offer.user_id.to_hex(),
+            e
+        );
+    }
+}
+
+// =============================================================================================================================
+
+fn active_waitlist_statuses() -> Result<Vec<Bson>, Box<dyn std::error::Error>> {
+    Ok(vec![
+        to_bson(&WaitlistStatus::Waiting)?,
+        to_bson(&WaitlistStatus::Offered)?,
+    ])
+}
+
+// =============================================================================================================================
+
+fn ticket_holds_a_seat(ticket: &Ticket) -> bool {
+    matches!(ticket.status, TicketStatus::Pending | TicketStatus::Active)
+}
+
+// =============================================================================================================================
+
+// The tickets of an event, with the passes granting entry to it.
+fn event_tickets_filter(event_id: ObjectId, ticket_type_id: Option<ObjectId>) -> Document {
+    match ticket_type_id {
+        Some(ticket_type_id) => doc! {
+            "$or": [
+                { "event_id": event_id, "ticket_type_id": ticket_type_id },
+                { "admissions": { "$elemMatch": { "event_id": event_id, "ticket_type_id": ticket_type_id } } }
+            ]
+        },
+        None => doc! {
+            "$or": [
+                { "event_id": event_id },
+                { "admissions.event_id": event_id }
+            ]
+        },
+    }
+}
+
+// =============================================================================================================================
+
+async fn release_reserved_seat(ticket: &Ticket) {
+    release_seats(&ticket.seats()).await;
+}
+
+// =============================================================================================================================
+
+async fn release_seats(seats: &[(ObjectId, Option<ObjectId>)]) {
+    for (event_id, ticket_type_id) in seats {
+        if let Err(e) =
+            update_event_remaining_seats_by_id_request(*event_id, *ticket_type_id, 1, None).await
+        {
+            eprintln!(
+                "Failed to release a seat reserved for event {}: {}",
+                event_id.to_hex(),
+                e
+            );
+        }
+    }
+}
+
+// =============================================================================================================================
+
+// The seats are taken one event after the other, so those already taken are given back if one of them is sold out.
+// A seat held for the buyer by the waitlist of an event is claimed instead of being taken from the sale.
+async fn reserve_seats(
+    db: &Database,
+    ticket: &Ticket,
+    offers: &[Option<WaitlistEntry>],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let seats = ticket.seats();
+    for (index, (event_id, ticket_type_id)) in seats.iter().enumerate() {
+        let reserved = match offers.get(index).and_then(Option::as_ref) {
+            Some(offer) => claim_waitlist_offer(db, offer).await,
+            None => {
+                update_event_remaining_seats_by_id_request(*event_id, *ticket_type_id, -1, None)
+                    .await
+            }
+        };
+        if let Err(e) = reserved {
+            undo_seat_reservations(db, &seats[..index], offers).await;
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+async fn release_redeemed_access_code(event_id: ObjectId, access_code: Option<&str>) {
+    let Some(access_code) = access_code else {
+        return;
+    };
+    if let Err(e) = access_code_request(event_id, access_code, "release").await {
+        eprintln!(
+            "Failed to release an access code redeemed for event {}: {}",
+            event_id.to_hex(),
+            e
+        );
+    }
+}
+
+// =============================================================================================================================
+
+// The limits of the event and those of the ticket type are counted apart, so each of them applies on its own.
+fn applicable_purchase_counters(
+    event: &GetEventInternalResponse,
+    ticket_type: Option<&EventTicketType>,
+    user_id: ObjectId,
+    card_number: &str,
+    household_address: Option<&str>,
+) -> Result<Vec<PurchaseCounter>, Box<dyn std::error::Error>> {
+    let event_id = ObjectId::parse_str(&event.id)?;
+    let mut scopes = vec![(None, &event.purchase_limits)];
+    if let Some(ticket_type) = ticket_type {
+        scopes.push((Some(ticket_type.id), &ticket_type.purchase_limits));
+    }
+
+    let mut counters = vec![];
+    for (ticket_type_id, limits) in scopes {
+        let scope = match ticket_type_id {
+            Some(ticket_type_id) => ticket_type_id.to_hex(),
+            None => "event".to_string(),
+        };
+        let counter_id = |rule: &str, key: &str| format!("{}:{}:{}:{}", event.id, scope, rule, key);
+
+        if let Some(limit) = limits.max_per_user {
+            counters.push(PurchaseCounter {
+                id: counter_id("user", &user_id.to_hex()),
+                event_id,
+                ticket_type_id,
+                rule: PurchaseLimitRule::User,
+                limit,
+            });
+        }
+        if let Some(limit) = limits.max_per_card {
+            let card_number: String = card_number.chars().filter(|c| c.is_ascii_digit()).collect();
+            counters.push(PurchaseCounter {
+                id: counter_id("card", &fingerprint(&card_number)),
+                event_id,
+                ticket_type_id,
+                rule: PurchaseLimitRule::Card,
+                limit,
+            });
+        }
+        if let Some(limit) = limits.max_per_household {
+            let address = household_address
+                .ok_or("A household address is required to buy tickets for this event.")?;
+            counters.push(PurchaseCounter {
+                id: counter_id("household", &fingerprint(&normalize_address(address))),
+                event_id,
+                ticket_type_id,
+                rule: PurchaseLimitRule::Household,
+                limit,
+            });
+        }
+    }
+
+    Ok(counters)
+}
+
+// =============================================================================================================================
+
+// Card numbers and addresses are only kept as keyed hashes, which cannot be reversed without the secret.
+fn fingerprint(value: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(PURCHASE_FINGERPRINT_SECRET.as_bytes());
+    hasher.update(b":");
+    hasher.update(value.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+// =============================================================================================================================
+
+// "12, Rue de la Paix" and "12 rue de la paix" are the same household.
+fn normalize_address(address: &str) -> String {
+    address
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+// =============================================================================================================================
+
+// Each counter is checked and incremented by a single conditional upsert, so concurrent purchases cannot
+// go beyond a limit. Once a limit is reached the filter misses the counter and the upsert collides with it.
+async fn reserve_purchase_counters(
+    db: &Database,
+    user_id: ObjectId,
+    counters: Vec<PurchaseCounter>,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let collection: Collection<Document> = db.collection(PURCHASE_COUNTERS_COLLECTION_NAME);
+    let mut reserved: Vec<String> = vec![];
+
+    for counter in counters {
+        let filter = doc! { "_id": &counter.id, "count": { "$lt": counter.limit } };
+        let update = doc! {
+            "$inc": { "count": 1 },
+            "$setOnInsert": { "event_id": counter.event_id },
+        };
+
+        let incremented = match collection
+            .update_one(filter.clone(), update.clone())
+            .upsert(true)
+            .await
+        {
+            Ok(_) => Ok(true),
+            // Two first purchases can race to create the counter, the loser retries on the existing one.
+            Err(e) if is_duplicate_key_error(&e) => collection
+                .update_one(filter, update)
+                .await
+                .map(|res| res.modified_count == 1),
+            Err(e) => Err(e),
+        };
+
+        match incremented {
+            Ok(true) => reserved.push(counter.id),
+            Ok(false) => {
+                shift_purchase_counters(db, &reserved, -1).await;
+                log_purchase_limit_rejection(db, user_id, &counter).await;
+                return Err(Box::new(ConflictError(purchase_limit_message(&counter))));
+            }
+            Err(e) => {
+                shift_purchase_counters(db, &reserved, -1).await;
+                return Err(e.into());
+            }
+        }
+    }
+
+    Ok(reserved)
+}
+
+// =============================================================================================================================
+
+async fn shift_purchase_counters(db: &Database, counter_ids: &[String], delta: i32) {
+    if counter_ids.is_empty() {
+        return;
+    }
+
+    let mut filter = doc! { "_id": { "$in": counter_ids } };
+    if delta < 0 {
+        filter.insert("count", doc! { "$gte": -delta });
+    }
+
+    let collection: Collection<Document> = db.collection(PURCHASE_COUNTERS_COLLECTION_NAME);
+    if let Err(e) = collection
+        .update_many(filter, doc! { "$inc": { "count": delta } })
+        .await
+    {
+        eprintln!(
+            "Failed to update the purchase counters {:?}: {}",
+            counter_ids, e
+        );
+    }
+}
+
+// =============================================================================================================================
+
+async fn log_purchase_limit_rejection(db: &Database, user_id: ObjectId, counter: &PurchaseCounter) {
+    eprintln!(
+        "Refused a ticket of event {} to user {}: {:?} limit of {} reached",
+        counter.event_id.to_hex(),
+        user_id.to_hex(),
+        counter.rule,
+        counter.limit
+    );
+
+    let rejection = PurchaseLimitRejection {
+        id: None,
+        event_id: counter.event_id,
+        ticket_type_id: counter.ticket_type_id,
+        user_id,
+        rule: counter.rule,
+        limit: counter.limit,
+        created_at: DateTime::now(),
+    };
+    let collection: Collection<PurchaseLimitRejection> =
+        db.collection(PURCHASE_REJECTIONS_COLLECTION_NAME);
+    if let Err(e) = collection.insert_one(&rejection).await {
+        eprintln!("Failed to log a refused purchase: {}", e);
+    }
+}
+
+// =============================================================================================================================
+
+fn purchase_limit_message(counter: &PurchaseCounter) -> String {
+    let per = match counter.rule {
+        PurchaseLimitRule::User => "per person",
+        PurchaseLimitRule::Card => "per payment card",
+        PurchaseLimitRule::Household => "per household",
+    };
+    let of = match counter.ticket_type_id {
+        Some(_) => "this ticket type",
+        None => "this event",
+    };
+    format!(
+        "The limit of {} tickets {} is reached for {}.",
+        counter.limit, per, of
+    )
+}
+
+// =============================================================================================================================
+
+async fn update_event_remaining_seats_by_id_request(
+    event_id: ObjectId,
+    ticket_type_id: Option<ObjectId>,
+    delta: i32,
+    seat_number: Option<u32>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+
+    let internal_token = encode_internal_jwt()?;
+    let payload = json!({
+        "delta": delta,
+        "ticket_type_id": ticket_type_id.map(|id| id.to_hex()),
+        "seat_number": seat_number
+    });
+
+    let res = client
+        .patch(format!(
+            "http://events-service:8080/api/events/{}/update-seats",
+            event_id.to_hex()
+        ))
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&payload)
+        .send()
+        .await?;
+    let conflict = res.status() == StatusCode::CONFLICT;
+
+    let error = match res
+        .json::<ApiResponse<serde_json::Value>>()
+        .await?
+        .into_result()
+    {
+        Ok(_) => return Ok(()),
+        Err(error) => error,
+    };
+
+    if conflict {
+        Err(Box::new(ConflictError(error)))
+    } else {
+        Err(error.into())
+    }
+}
+
+// =============================================================================================================================
+
+async fn access_code_request(
+    event_id: ObjectId,
+    access_code: &str,
+    action: &str,
+) -> Result<RedeemedAccessCode, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .patch(format!(
+            "http://events-service:8080/api/events/{}/access-codes/{}",
+            event_id.to_hex(),
+            action
+        ))
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&json!({ "code": access_code }))
+        .send()
+        .await?;
+    let conflict = res.status() == StatusCode::CONFLICT;
+
+    let error = match res
+        .json::<ApiResponse<RedeemedAccessCode>>()
+        .await?
+        .into_result()
+    {
+        Ok(Some(redeemed)) => return Ok(redeemed),
+        Ok(None) => "The access code could not be used.".to_string(),
+        Err(error) => error,
+    };
+
+    if conflict {
+        Err(Box::new(ConflictError(error)))
+    } else {
+        Err(error.into())
+    }
 }
 
 // =============================================================================================================================
 
 async fn create_payment(
-    ticket_id: ObjectId,
-    ticket_data: &CreateTicketRequest,
-    amount: u32,
+    ticket: &Ticket,
+    card: &PaymentCard,
+    currency: PaymentCurrency,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let client = reqwest::Client::new();
 
-    let user_id = ticket_data.user_id.to_hex();
-    let event_id = ticket_data.event_id.to_hex();
-    let ticket_id = ticket_id.to_hex();
-    let expiration_date = ticket_data.expiration_date.try_to_rfc3339_string()?;
+    let user_id = ticket.user_id.to_hex();
+    let event_id = ticket.event_id.to_hex();
+    let ticket_id = ticket.id.ok_or("The ticket has no id")?.to_hex();
+    let expiration_date = card.expiration_date.try_to_rfc3339_string()?;
+
+    // A pass is paid once, and each of its events earns the price of its admission.
+    let event_shares: Vec<serde_json::Value> = ticket
+        .admissions
+        .iter()
+        .map(|admission| {
+            json!({
+                "event_id": admission.event_id.to_hex(),
+                "amount": admission.price
+            })
+        })
+        .collect();
 
     let internal_token = encode_internal_jwt()?;
     let payload = json!({
-        "card_number": ticket_data.card_number,
+        "card_number": card.card_number,
         "expiration_date": expiration_date,
-        "cvv": ticket_data.cvv,
-        "card_holder": ticket_data.card_holder,
-        "amount": amount,
-        "currency": ticket_data.currency,
+        "cvv": card.cvv,
+        "card_holder": card.card_holder,
+        "amount": ticket.price,
+        "currency": currency,
         "user_id": user_id,
         "event_id": event_id,
-        "ticket_id": ticket_id
+        "ticket_id": ticket_id,
+        "event_shares": event_shares
     });
 
     let res = client
@@ -382,10 +2706,186 @@ async fn create_payment(
         .json::<ApiResponse<serde_json::Value>>()
         .await?;
 
-    match res {
-        ApiResponse::Success { .. } => Ok(()),
-        ApiResponse::Error { error, .. } => Err(error.into()),
+    res.into_result()?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+async fn get_event_request(
+    event_id: ObjectId,
+) -> Result<GetEventInternalResponse, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .get(format!(
+            "http://events-service:8080/api/events/{}",
+            event_id.to_hex()
+        ))
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .send()
+        .await?
+        .json::<ApiResponse<GetEventInternalResponse>>()
+        .await?;
+
+    Ok(res.into_result()?.ok_or("No Event found with this id")?)
+}
+
+// =============================================================================================================================
+
+async fn get_pass_request(
+    pass_id: ObjectId,
+) -> Result<GetPassInternalResponse, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .get(format!(
+            "http://events-service:8080/api/events/passes/{}",
+            pass_id.to_hex()
+        ))
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .send()
+        .await?
+        .json::<ApiResponse<GetPassInternalResponse>>()
+        .await?;
+
+    Ok(res.into_result()?.ok_or("No pass was found with this id")?)
+}
+
+// =============================================================================================================================
+
+async fn refund_payment_request(
+    ticket_id: ObjectId,
+    reason: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+
+    let internal_token = encode_internal_jwt()?;
+    let payload = json!({
+        "ticket_id": ticket_id.to_hex(),
+        "reason": reason
+    });
+
+    let res = client
+        .post("http://payments-service:8080/api/payments/refunds")
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&payload)
+        .send()
+        .await?
+        .json::<ApiResponse<serde_json::Value>>()
+        .await?;
+
+    res.into_result()?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+async fn create_broadcast_request(
+    payload: serde_json::Value,
+) -> Result<Broadcast, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .post("http://notifications-service:8080/api/notifications/broadcasts")
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&payload)
+        .send()
+        .await?;
+    let too_many_requests = res.status() == StatusCode::TOO_MANY_REQUESTS;
+
+    let error = match res.json::<ApiResponse<Broadcast>>().await?.into_result() {
+        Ok(Some(broadcast)) => return Ok(broadcast),
+        Ok(None) => "The broadcast was not returned".to_string(),
+        Err(error) => error,
+    };
+
+    if too_many_requests {
+        Err(Box::new(TooManyRequestsError(error)))
+    } else {
+        Err(error.into())
     }
 }
 
 // =============================================================================================================================
+
+async fn get_event_broadcasts_request(
+    event_id: ObjectId,
+) -> Result<Vec<BroadcastReport>, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .get(format!(
+            "http://notifications-service:8080/api/notifications/broadcasts/events/{}",
+            event_id.to_hex()
+        ))
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .send()
+        .await?
+        .json::<ApiResponse<Vec<BroadcastReport>>>()
+        .await?;
+
+    Ok(res.into_result()?.unwrap_or_default())
+}
+
+// =============================================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seat_numbers_are_within_the_capacity() {
+        assert!(check_seat_number(1, 10).is_ok());
+        assert!(check_seat_number(10, 10).is_ok());
+        assert!(check_seat_number(0, 10).is_err());
+        assert!(check_seat_number(11, 10).is_err());
+    }
+
+    #[test]
+    fn seat_ranges_group_consecutive_seats() {
+        let range = |first, last| SeatRange { first, last };
+
+        assert_eq!(seat_ranges(&[]), vec![]);
+        assert_eq!(
+            seat_ranges(&[1, 2, 3, 7, 9, 10]),
+            vec![range(1, 3), range(7, 7), range(9, 10)]
+        );
+        assert_eq!(seat_ranges(&[4, 4, 5]), vec![range(4, 5)]);
+    }
+
+    #[test]
+    fn normalize_address_ignores_case_and_punctuation() {
+        assert_eq!(
+            normalize_address("12, Rue de la Paix"),
+            normalize_address("12 rue de la paix")
+        );
+        assert_eq!(
+            normalize_address("  12-Rue   de la PAIX.\n75002 Paris "),
+            "12 rue de la paix 75002 paris"
+        );
+    }
+
+    #[test]
+    fn normalize_address_keeps_accented_letters() {
+        assert_eq!(
+            normalize_address("3 Place de l'Église"),
+            "3 place de l église"
+        );
+    }
+
+    #[test]
+    fn normalize_address_tells_different_households_apart() {
+        assert_ne!(
+            normalize_address("12 rue de la Paix"),
+            normalize_address("14 rue de la Paix")
+        );
+        assert_eq!(normalize_address(" ,. "), "");
+    }
+}
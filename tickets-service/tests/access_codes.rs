@@ -0,0 +1,98 @@
+mod support;
+
+use mongodb::bson::oid::ObjectId;
+use serde_json::{Value, json};
+use support::{Api, assert_sold, in_hours, object_id, purchase};
+
+// Concurrent presale purchases against a running stack, see "Integration Tests" in the readme:
+//     INTEGRATION_API_URL=http://localhost cargo test -p tickets-service --test access_codes -- --ignored
+
+// =============================================================================================================================
+
+const BUYERS: usize = 10;
+const MAX_USES: usize = 3;
+const CODE: &str = "INTEGRATION";
+
+// =============================================================================================================================
+
+#[actix_web::test]
+#[ignore = "needs a running stack and INTEGRATION_API_URL"]
+async fn an_access_code_is_used_up_to_its_quota() {
+    let api = Api::from_env();
+    let event_id = api.create_event(BUYERS as u32, 48).await;
+    let presale_id = open_presale(&api, event_id).await;
+
+    // Every buyer picks a seat of their own, only the quota of the code can refuse them.
+    let purchases: Vec<Value> = (1..=BUYERS as u32)
+        .map(|seat_number| {
+            purchase(
+                ObjectId::new(),
+                json!({
+                    "currency": "Eur",
+                    "seat_number": seat_number,
+                    "event_id": event_id.to_hex(),
+                    "access_code": CODE.to_lowercase()
+                }),
+            )
+        })
+        .collect();
+    let statuses = api.buy_concurrently("/api/tickets", &purchases).await;
+    let uses = access_code_uses(&api, event_id, presale_id).await;
+    let remaining_seats = api.remaining_seats(event_id).await;
+    api.delete_event(event_id).await;
+
+    assert_sold(&statuses, MAX_USES);
+    assert_eq!(uses, MAX_USES as i64);
+    assert_eq!(remaining_seats, (BUYERS - MAX_USES) as i64);
+}
+
+// =============================================================================================================================
+
+// Publishes the event with its public sale in a day and a presale open until then, with a single code.
+async fn open_presale(api: &Api, event_id: ObjectId) -> ObjectId {
+    let event_url = format!("{}/api/events/{}", api.url, event_id);
+    api.send(
+        api.http
+            .put(format!("{}/sale-window", event_url))
+            .json(&json!({ "sale_starts_at": in_hours(24) })),
+    )
+    .await;
+    let event = api
+        .send(
+            api.http
+                .post(format!("{}/presales", event_url))
+                .json(&json!({
+                    "name": "Integration tests presale",
+                    "starts_at": in_hours(-1),
+                    "ends_at": in_hours(24)
+                })),
+        )
+        .await;
+    let presale_id = object_id(&event["presales"][0]["id"]);
+    api.send(
+        api.http
+            .post(format!(
+                "{}/presales/{}/access-codes",
+                event_url, presale_id
+            ))
+            .json(&json!({ "code": CODE, "max_uses": MAX_USES })),
+    )
+    .await;
+    api.publish_event(event_id).await;
+
+    presale_id
+}
+
+// =============================================================================================================================
+
+async fn access_code_uses(api: &Api, event_id: ObjectId, presale_id: ObjectId) -> i64 {
+    let access_codes = api
+        .send(api.http.get(format!(
+            "{}/api/events/{}/presales/{}/access-codes",
+            api.url, event_id, presale_id
+        )))
+        .await;
+    access_codes[0]["uses"].as_i64().unwrap()
+}
+
+// =============================================================================================================================
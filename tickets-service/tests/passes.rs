@@ -0,0 +1,69 @@
+mod support;
+
+use mongodb::bson::oid::ObjectId;
+use serde_json::{Value, json};
+use support::{Api, assert_sold, object_id, purchase};
+
+// Concurrent pass purchases against a running stack, see "Integration Tests" in the readme:
+//     INTEGRATION_API_URL=http://localhost cargo test -p tickets-service --test passes -- --ignored
+
+// =============================================================================================================================
+
+const BUYERS: usize = 20;
+
+// =============================================================================================================================
+
+#[actix_web::test]
+#[ignore = "needs a running stack and INTEGRATION_API_URL"]
+async fn the_last_seat_of_an_event_is_sold_with_one_pass() {
+    let api = Api::from_env();
+    let capacity = BUYERS as u32;
+    let first_event_id = api.create_event(capacity, 24).await;
+    let last_event_id = api.create_event(capacity, 48).await;
+    api.publish_event(first_event_id).await;
+    api.publish_event(last_event_id).await;
+    api.take_seats(last_event_id, capacity - 1).await;
+    let pass_id = create_pass(&api, &[first_event_id, last_event_id]).await;
+
+    // The first event has a seat for every buyer, the seats of the refused passes must be given back.
+    let purchases: Vec<Value> = (0..BUYERS)
+        .map(|_| purchase(ObjectId::new(), json!({ "pass_id": pass_id.to_hex() })))
+        .collect();
+    let statuses = api
+        .buy_concurrently("/api/tickets/passes", &purchases)
+        .await;
+    let first_remaining_seats = api.remaining_seats(first_event_id).await;
+    let last_remaining_seats = api.remaining_seats(last_event_id).await;
+    api.delete(format!("{}/api/events/passes/{}", api.url, pass_id))
+        .await;
+    api.delete_event(first_event_id).await;
+    api.delete_event(last_event_id).await;
+
+    assert_sold(&statuses, 1);
+    assert_eq!(first_remaining_seats, capacity as i64 - 1);
+    assert_eq!(last_remaining_seats, 0);
+}
+
+// =============================================================================================================================
+
+async fn create_pass(api: &Api, event_ids: &[ObjectId]) -> ObjectId {
+    let events: Vec<Value> = event_ids
+        .iter()
+        .map(|event_id| json!({ "event_id": event_id.to_hex() }))
+        .collect();
+    let pass = api
+        .send(
+            api.http
+                .post(format!("{}/api/events/passes", api.url))
+                .json(&json!({
+                    "name": "Integration tests pass",
+                    "events": events,
+                    "price": 15,
+                    "currency": "Eur"
+                })),
+        )
+        .await;
+    object_id(&pass["id"])
+}
+
+// =============================================================================================================================
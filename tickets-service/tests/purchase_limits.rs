@@ -0,0 +1,125 @@
+mod support;
+
+use mongodb::bson::oid::ObjectId;
+use serde_json::{Value, json};
+use support::{Api, assert_sold, purchase};
+
+// Concurrent purchases against the purchase limits of an event, on a running stack, see "Integration Tests" in the readme:
+//     INTEGRATION_API_URL=http://localhost cargo test -p tickets-service --test purchase_limits -- --ignored
+
+// =============================================================================================================================
+
+const PURCHASES: u32 = 10;
+const LIMIT: usize = 3;
+
+// =============================================================================================================================
+
+#[actix_web::test]
+#[ignore = "needs a running stack and INTEGRATION_API_URL"]
+async fn a_user_buys_up_to_the_limit() {
+    let api = Api::from_env();
+    let event_id = create_limited_event(&api, json!({ "max_per_user": LIMIT })).await;
+
+    let user_id = ObjectId::new();
+    let statuses = api
+        .buy_concurrently(
+            "/api/tickets",
+            &purchases(event_id, |_| purchase(user_id, json!({}))),
+        )
+        .await;
+    let remaining_seats = api.remaining_seats(event_id).await;
+    api.delete_event(event_id).await;
+
+    assert_sold(&statuses, LIMIT);
+    assert_eq!(remaining_seats, (PURCHASES as usize - LIMIT) as i64);
+}
+
+// =============================================================================================================================
+
+#[actix_web::test]
+#[ignore = "needs a running stack and INTEGRATION_API_URL"]
+async fn a_card_buys_up_to_the_limit() {
+    let api = Api::from_env();
+    let event_id = create_limited_event(&api, json!({ "max_per_card": LIMIT })).await;
+
+    // Every buyer pays with the same card.
+    let statuses = api
+        .buy_concurrently(
+            "/api/tickets",
+            &purchases(event_id, |_| purchase(ObjectId::new(), json!({}))),
+        )
+        .await;
+    let remaining_seats = api.remaining_seats(event_id).await;
+    api.delete_event(event_id).await;
+
+    assert_sold(&statuses, LIMIT);
+    assert_eq!(remaining_seats, (PURCHASES as usize - LIMIT) as i64);
+}
+
+// =============================================================================================================================
+
+#[actix_web::test]
+#[ignore = "needs a running stack and INTEGRATION_API_URL"]
+async fn a_household_buys_up_to_the_limit() {
+    let api = Api::from_env();
+    let event_id = create_limited_event(&api, json!({ "max_per_household": LIMIT })).await;
+
+    // Every buyer has a card of their own and writes the same address their own way.
+    let statuses = api
+        .buy_concurrently(
+            "/api/tickets",
+            &purchases(event_id, |seat_number| {
+                let address = match seat_number % 2 {
+                    0 => "12, Rue de la Paix",
+                    _ => "12 rue de la paix",
+                };
+                purchase(
+                    ObjectId::new(),
+                    json!({
+                        "card_number": format!("49701012345678{:02}", seat_number),
+                        "household_address": address
+                    }),
+                )
+            }),
+        )
+        .await;
+    let remaining_seats = api.remaining_seats(event_id).await;
+    api.delete_event(event_id).await;
+
+    assert_sold(&statuses, LIMIT);
+    assert_eq!(remaining_seats, (PURCHASES as usize - LIMIT) as i64);
+}
+
+// =============================================================================================================================
+
+async fn create_limited_event(api: &Api, purchase_limits: Value) -> ObjectId {
+    let event_id = api.create_event(PURCHASES, 24).await;
+    api.send(
+        api.http
+            .put(format!(
+                "{}/api/events/{}/purchase-limits",
+                api.url, event_id
+            ))
+            .json(&purchase_limits),
+    )
+    .await;
+    api.publish_event(event_id).await;
+    event_id
+}
+
+// =============================================================================================================================
+
+// One purchase per seat, only the purchase limits can refuse them.
+fn purchases(event_id: ObjectId, buyer: impl Fn(u32) -> Value) -> Vec<Value> {
+    (1..=PURCHASES)
+        .map(|seat_number| {
+            let mut purchase = buyer(seat_number);
+            purchase["currency"] = json!("Eur");
+            purchase["seat_number"] = json!(seat_number);
+            purchase["event_id"] = json!(event_id.to_hex());
+            purchase
+        })
+        .collect()
+}
+
+// =============================================================================================================================
@@ -0,0 +1,73 @@
+mod support;
+
+use mongodb::bson::oid::ObjectId;
+use serde_json::{Value, json};
+use support::{Api, assert_sold, purchase};
+
+// Concurrent purchases against a running stack, see "Integration Tests" in the readme:
+//     INTEGRATION_API_URL=http://localhost cargo test -p tickets-service --test seat_contention -- --ignored
+
+// =============================================================================================================================
+
+const BUYERS: usize = 20;
+
+// =============================================================================================================================
+
+#[actix_web::test]
+#[ignore = "needs a running stack and INTEGRATION_API_URL"]
+async fn a_seat_is_sold_once() {
+    let api = Api::from_env();
+    let event_id = api.create_published_event(10).await;
+
+    let statuses = api
+        .buy_concurrently("/api/tickets", &seat_purchases(event_id, &[7; BUYERS]))
+        .await;
+    let remaining_seats = api.remaining_seats(event_id).await;
+    api.delete_event(event_id).await;
+
+    assert_sold(&statuses, 1);
+    assert_eq!(remaining_seats, 9);
+}
+
+// =============================================================================================================================
+
+#[actix_web::test]
+#[ignore = "needs a running stack and INTEGRATION_API_URL"]
+async fn the_last_seat_is_sold_once() {
+    let api = Api::from_env();
+    let capacity = BUYERS as u32;
+    let event_id = api.create_published_event(capacity).await;
+    api.take_seats(event_id, capacity - 1).await;
+
+    // Every buyer picks a free seat of their own, only the remaining seats can refuse them.
+    let seats: Vec<u32> = (1..=capacity).collect();
+    let statuses = api
+        .buy_concurrently("/api/tickets", &seat_purchases(event_id, &seats))
+        .await;
+    let remaining_seats = api.remaining_seats(event_id).await;
+    api.delete_event(event_id).await;
+
+    assert_sold(&statuses, 1);
+    assert_eq!(remaining_seats, 0);
+}
+
+// =============================================================================================================================
+
+// One buyer per seat.
+fn seat_purchases(event_id: ObjectId, seats: &[u32]) -> Vec<Value> {
+    seats
+        .iter()
+        .map(|seat_number| {
+            purchase(
+                ObjectId::new(),
+                json!({
+                    "currency": "Eur",
+                    "seat_number": seat_number,
+                    "event_id": event_id.to_hex()
+                }),
+            )
+        })
+        .collect()
+}
+
+// =============================================================================================================================
@@ -0,0 +1,193 @@
+#![allow(dead_code)]
+
+use common::jwt::{external::encode_external_jwt, internal::encode_internal_jwt};
+use common::models::AuthRole;
+use futures_util::future::join_all;
+use mongodb::bson::{DateTime, oid::ObjectId};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+// Helpers shared by the integration tests, which run against a development stack, see "Integration Tests"
+// in the readme.
+
+// =============================================================================================================================
+
+pub fn assert_sold(statuses: &[StatusCode], sold: usize) {
+    let succeeded = statuses.iter().filter(|s| **s == StatusCode::OK).count();
+    let conflicts = statuses
+        .iter()
+        .filter(|s| **s == StatusCode::CONFLICT)
+        .count();
+
+    assert_eq!(succeeded, sold, "statuses: {:?}", statuses);
+    assert_eq!(conflicts, statuses.len() - sold, "statuses: {:?}", statuses);
+}
+
+// =============================================================================================================================
+
+// The payment details of a purchase by the given user, completed with what is bought.
+pub fn purchase(user_id: ObjectId, items: Value) -> Value {
+    let mut purchase = json!({
+        "card_number": "4970101234567890",
+        "expiration_date": "2099-12-01T00:00:00Z",
+        "cvv": "123",
+        "card_holder": "Integration Tests",
+        "user_id": user_id.to_hex()
+    });
+    if let (Some(purchase), Some(items)) = (purchase.as_object_mut(), items.as_object()) {
+        purchase.extend(items.clone());
+    }
+    purchase
+}
+
+// =============================================================================================================================
+
+pub fn in_hours(hours: i64) -> String {
+    DateTime::from_millis(DateTime::now().timestamp_millis() + hours * 3600 * 1000)
+        .try_to_rfc3339_string()
+        .unwrap()
+}
+
+// =============================================================================================================================
+
+// Ids nested in a document are serialized either as hex strings or as { "$oid": ... }.
+pub fn object_id(value: &Value) -> ObjectId {
+    let hex = value
+        .as_str()
+        .or_else(|| value["$oid"].as_str())
+        .unwrap_or_else(|| panic!("{} is not an id", value));
+    ObjectId::parse_str(hex).unwrap()
+}
+
+// =============================================================================================================================
+
+pub struct Api {
+    pub url: String,
+    pub http: reqwest::Client,
+    admin_token: String,
+}
+
+impl Api {
+    pub fn from_env() -> Self {
+        dotenv::dotenv().ok();
+        let url = std::env::var("INTEGRATION_API_URL")
+            .expect("INTEGRATION_API_URL must point to the running stack");
+        let admin_token = encode_external_jwt(ObjectId::new().to_hex(), AuthRole::Admin)
+            .expect("JWT_EXTERNAL_SIGNATURE must be set");
+
+        Api {
+            url,
+            http: reqwest::Client::new(),
+            admin_token,
+        }
+    }
+
+    // A draft event, held in `hours`.
+    pub async fn create_event(&self, capacity: u32, hours: i64) -> ObjectId {
+        let created = self
+            .send(
+                self.http
+                    .post(format!("{}/api/events", self.url))
+                    .json(&json!({
+                        "title": "Integration tests",
+                        "description": "Throwaway event created by the integration tests.",
+                        "location": "Nowhere",
+                        "date": in_hours(hours),
+                        "capacity": capacity,
+                        "price": 10
+                    })),
+            )
+            .await;
+        object_id(&created["id"])
+    }
+
+    pub async fn publish_event(&self, event_id: ObjectId) {
+        self.send(
+            self.http
+                .patch(format!("{}/api/events/{}/publish", self.url, event_id)),
+        )
+        .await;
+    }
+
+    pub async fn create_published_event(&self, capacity: u32) -> ObjectId {
+        let event_id = self.create_event(capacity, 24).await;
+        self.publish_event(event_id).await;
+        event_id
+    }
+
+    // Takes the seats as the Tickets Service would, without tickets holding their numbers.
+    pub async fn take_seats(&self, event_id: ObjectId, seats: u32) {
+        let internal_token = encode_internal_jwt().expect("JWT_INTERNAL_SIGNATURE must be set");
+        let res = self
+            .http
+            .patch(format!("{}/api/events/{}/update-seats", self.url, event_id))
+            .header("Authorization", format!("Bearer {}", internal_token))
+            .json(&json!({ "delta": -(seats as i32) }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    // Sends every purchase at once, each with the token of its buyer.
+    pub async fn buy_concurrently(&self, path: &str, purchases: &[Value]) -> Vec<StatusCode> {
+        let requests = purchases.iter().map(|purchase| {
+            let user_id = purchase["user_id"].as_str().unwrap().to_string();
+            let token = encode_external_jwt(user_id, AuthRole::User).unwrap();
+            self.http
+                .post(format!("{}{}", self.url, path))
+                .header("Authorization", format!("Bearer {}", token))
+                .json(purchase)
+                .send()
+        });
+
+        join_all(requests)
+            .await
+            .into_iter()
+            .map(|res| res.unwrap().status())
+            .collect()
+    }
+
+    pub async fn remaining_seats(&self, event_id: ObjectId) -> i64 {
+        let event = self
+            .send(
+                self.http
+                    .get(format!("{}/api/events/{}", self.url, event_id)),
+            )
+            .await;
+        event["remaining_seats"].as_i64().unwrap()
+    }
+
+    pub async fn delete_event(&self, event_id: ObjectId) {
+        self.delete(format!("{}/api/events/{}", self.url, event_id))
+            .await;
+    }
+
+    // Cleanup failures are logged, so they do not hide the outcome of a test.
+    pub async fn delete(&self, url: String) {
+        let res = self
+            .http
+            .delete(&url)
+            .header("Authorization", format!("Bearer {}", self.admin_token))
+            .send()
+            .await;
+        if let Err(e) = res {
+            eprintln!("Failed to delete {}: {}", url, e);
+        }
+    }
+
+    // Sends a request as an admin and returns the data of its successful response.
+    pub async fn send(&self, request: reqwest::RequestBuilder) -> Value {
+        let res = request
+            .header("Authorization", format!("Bearer {}", self.admin_token))
+            .send()
+            .await
+            .unwrap();
+        let status = res.status();
+        let body: Value = res.json().await.unwrap();
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        body["data"].clone()
+    }
+}
+
+// =============================================================================================================================
@@ -1,6 +1,6 @@
 use actix_web::{
-    HttpRequest, HttpResponse, Responder, delete, get, post, put,
-    web::{self, Data, Json, Path},
+    HttpMessage, HttpRequest, HttpResponse, Responder, delete, get, patch, post, put,
+    web::{self, Bytes, Data, Json, Path, Query},
 };
 use common::{
     jwt::{
@@ -14,10 +14,16 @@ use common::{
 };
 use mongodb::Database;
 use utoipa::OpenApi;
+use validator::Validate;
 
 use crate::{
     doc::ApiDoc,
-    model::{CreateUserRequest, GetUserIdByEmailRequest, UpdateUserRequest, User},
+    model::{
+        AcceptTermsRequest, ConsentHistory, ConsentStatus, CreateTermsRequest, CreateUserRequest,
+        GetUserIdByEmailRequest, MarketingConsent, SearchUsersQuery, TermsAcceptance,
+        TermsDocument, UpdateMarketingConsentRequest, UpdateUserRequest, User, UserImportReport,
+        UserSearchResponse,
+    },
     service,
 };
 
@@ -27,13 +33,24 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     let scope = web::scope("/api/users")
         .service(health_check)
         .service(get_users)
+        .service(search_users)
+        .service(get_deleted_users)
         .service(get_me)
+        .service(get_terms)
+        .service(get_current_terms)
+        .service(create_terms)
+        .service(get_my_consents)
+        .service(accept_terms)
+        .service(update_marketing_consent)
         .service(get_user_id_by_email)
         .service(get_user_by_id)
+        .service(get_user_consent_history)
         .service(create_user)
+        .service(import_users)
         .service(update_me)
         .service(update_user_by_id)
         .service(delete_user)
+        .service(restore_user)
         .service(web::resource("/doc").route(web::get().to(|| async {
             HttpResponse::Found()
                 .append_header(("Location", "./"))
@@ -105,6 +122,55 @@ async fn get_users(db: Data<Database>, req: HttpRequest) -> impl Responder {
 
 // =============================================================================================================================
 
+#[utoipa::path(
+    get,
+    path = "/api/users/search",
+    tag = "Protected Endpoints",
+    summary = "Search users",
+    description = "Searches users by name or email prefix, registration date and role, with pagination. Each result includes the user's role from the Auth Service. Access is restricted to Admin or Operator roles.",
+    params(SearchUsersQuery),
+    responses(
+        (status = 200, description = "Users have been successfully retrieved", body = DocSuccessApiResponse<UserSearchResponse>),
+        (status = 400, description = "Invalid query parameters", body = DocErrorApiResponse),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred while searching users", body = DocErrorApiResponse)
+    )
+)]
+#[get("/search")]
+async fn search_users(
+    db: Data<Database>,
+    query: Query<SearchUsersQuery>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::Operator];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let query = query.into_inner();
+    if let Err(e) = query.validate() {
+        let response: ApiResponse<()> =
+            ApiResponse::error("An error occured while searching users.", e.to_string());
+        return HttpResponse::BadRequest().json(response);
+    }
+
+    match service::search_users(&db, query).await {
+        Ok(result) => {
+            let response: ApiResponse<UserSearchResponse> =
+                ApiResponse::success("Users have been successfully recovered", Some(result));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("An error occured while searching users.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
 #[utoipa::path(
     get,
     path = "/api/users/id-by-email",
@@ -236,7 +302,7 @@ async fn get_user_by_id(db: Data<Database>, id: Path<String>, req: HttpRequest)
     path = "/api/users",
     tag = "Protected Endpoints",
     summary = "Register a new user",
-    description = "Creates a new user profile. This may be a two-step process if registration is handled separately from authentication.",
+    description = "Creates a new user profile. This may be a two-step process if registration is handled separately from authentication. Once terms of service are published, the current terms_version must be accepted; the acceptance and the optional marketing consent are recorded with the Registration source.",
     request_body = CreateUserRequest,
     responses(
         (status = 200, description = "User created successfully", body = DocSuccessApiResponse<User>),
@@ -256,7 +322,7 @@ async fn create_user(
     };
 
     let data = payload.into_inner();
-    match service::create_user(&db, data).await {
+    match service::register_user(&db, data).await {
         Ok(user) => {
             let response: ApiResponse<User> =
                 ApiResponse::success("User created successfully", Some(user));
@@ -366,7 +432,7 @@ async fn update_user_by_id(
     path = "/api/users/{id}",
     tag = "Protected Endpoints",
     summary = "Delete a user",
-    description = "Permanently deletes a user specified by its ID. Access is restricted to Admin users.",
+    description = "Soft deletes a user specified by its ID. The user is hidden from every other endpoint and permanently purged once the retention period is over. Access is restricted to Admin users.",
     responses(
         (status = 200, description = "User was successfully deleted", body = DocSuccessApiResponse<User>),
         (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
@@ -379,14 +445,14 @@ async fn update_user_by_id(
 #[delete("/{id}")]
 async fn delete_user(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
     let required_roles = &[AuthRole::Admin];
-    match user_has_any_of_these_roles(&req, required_roles) {
+    let jwt_payload = match user_has_any_of_these_roles(&req, required_roles) {
         Ok(claims) => claims,
         Err(err_res) => return err_res,
     };
 
     let id = id.into_inner();
 
-    match service::delete_user(&db, id).await {
+    match service::delete_user(&db, id, jwt_payload.user_id).await {
         Ok(user) => {
             let response: ApiResponse<User> =
                 ApiResponse::success("User was successfully deleted.", Some(user));
@@ -400,3 +466,413 @@ async fn delete_user(db: Data<Database>, id: Path<String>, req: HttpRequest) ->
 }
 
 // =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/users/deleted",
+    tag = "Protected Endpoints",
+    summary = "Retrieve deleted users",
+    description = "Fetches the list of soft deleted users that have not been purged yet. Access is restricted to Admin users.",
+    responses(
+        (status = 200, description = "Deleted users have been successfully retrieved", body = DocSuccessApiResponse<Vec<User>>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred while retrieving deleted users", body = DocErrorApiResponse)
+    )
+)]
+#[get("/deleted")]
+async fn get_deleted_users(db: Data<Database>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    match service::get_deleted_users(&db).await {
+        Ok(users) => {
+            let response: ApiResponse<Vec<User>> = ApiResponse::success(
+                "Deleted users have been successfully retrieved.",
+                Some(users),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured while retrieving deleted users.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    patch,
+    path = "/api/users/{id}/restore",
+    tag = "Protected Endpoints",
+    summary = "Restore a deleted user",
+    description = "Restores a soft deleted user specified by its ID. Access is restricted to Admin users.",
+    responses(
+        (status = 200, description = "User was successfully restored", body = DocSuccessApiResponse<User>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "An error occurred", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "User ID")
+    )
+)]
+#[patch("/{id}/restore")]
+async fn restore_user(db: Data<Database>, id: Path<String>, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+
+    match service::restore_user(&db, id).await {
+        Ok(user) => {
+            let response: ApiResponse<User> =
+                ApiResponse::success("User was successfully restored.", Some(user));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error("An error occurred", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/users/import",
+    tag = "Protected Endpoints",
+    summary = "Bulk import users",
+    description = "Creates pending accounts from a CSV file (with a first_name,last_name,email header) or a JSON array of users. Every row is validated like a regular user creation and each imported user receives an invitation to set their password. Returns a row-by-row report. Access is restricted to Admin or EventCreator roles.",
+    request_body(
+        content(
+            (Vec<CreateUserRequest> = "application/json"),
+            (String = "text/csv")
+        )
+    ),
+    responses(
+        (status = 200, description = "Users were imported.", body = DocSuccessApiResponse<UserImportReport>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to import users.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/import")]
+async fn import_users(db: Data<Database>, body: Bytes, req: HttpRequest) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::EventCreator];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let rows = match req.content_type() {
+        "text/csv" => service::parse_users_csv(&body),
+        _ => service::parse_users_json(&body),
+    };
+
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("The import file could not be read.", e.to_string());
+            return HttpResponse::BadRequest().json(response);
+        }
+    };
+
+    match service::import_users(&db, rows).await {
+        Ok(report) => {
+            let response: ApiResponse<UserImportReport> =
+                ApiResponse::success("Users were imported.", Some(report));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to import users.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/users/terms",
+    tag = "Public Endpoints",
+    summary = "Retrieve every terms of service version",
+    description = "Fetches every published version of the terms of service, the most recent first.",
+    responses(
+        (status = 200, description = "Terms of service were successfully retrieved.", body = DocSuccessApiResponse<Vec<TermsDocument>>),
+        (status = 500, description = "An error occurred while retrieving the terms of service.", body = DocErrorApiResponse)
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/terms")]
+async fn get_terms(db: Data<Database>) -> impl Responder {
+    match service::get_terms(&db).await {
+        Ok(terms) => {
+            let response: ApiResponse<Vec<TermsDocument>> =
+                ApiResponse::success("Terms of service were successfully retrieved.", Some(terms));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "An error occured while retrieving the terms of service.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/users/terms/current",
+    tag = "Public Endpoints",
+    summary = "Retrieve the current terms of service",
+    description = "Fetches the most recently published version of the terms of service, the one users have to accept.",
+    responses(
+        (status = 200, description = "Current terms of service were successfully retrieved.", body = DocSuccessApiResponse<TermsDocument>),
+        (status = 500, description = "Failed to retrieve the current terms of service.", body = DocErrorApiResponse)
+    ),
+    security(
+        ("public_routes" = [])
+    )
+)]
+#[get("/terms/current")]
+async fn get_current_terms(db: Data<Database>) -> impl Responder {
+    match service::get_current_terms(&db).await {
+        Ok(terms) => {
+            let response: ApiResponse<TermsDocument> = ApiResponse::success(
+                "Current terms of service were successfully retrieved.",
+                Some(terms),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> = ApiResponse::error(
+                "Failed to retrieve the current terms of service.",
+                e.to_string(),
+            );
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/users/terms",
+    tag = "Protected Endpoints",
+    summary = "Publish a new terms of service version",
+    description = "Publishes a new version of the terms of service. It becomes the current version immediately and every user has to accept it again. Access is restricted to Admin users.",
+    request_body = CreateTermsRequest,
+    responses(
+        (status = 200, description = "Terms of service were successfully published.", body = DocSuccessApiResponse<TermsDocument>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to publish the terms of service.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/terms")]
+async fn create_terms(
+    db: Data<Database>,
+    payload: Json<CreateTermsRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let payload = payload.into_inner();
+    match service::create_terms(&db, payload).await {
+        Ok(terms) => {
+            let response: ApiResponse<TermsDocument> =
+                ApiResponse::success("Terms of service were successfully published.", Some(terms));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to publish the terms of service.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/users/me/consents",
+    tag = "Protected Endpoints",
+    summary = "Retrieve current user's consents",
+    description = "Returns the terms version accepted by the current user, whether they must accept the current terms again, and their marketing consent.",
+    responses(
+        (status = 200, description = "Consents were successfully retrieved.", body = DocSuccessApiResponse<ConsentStatus>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the consents.", body = DocErrorApiResponse)
+    )
+)]
+#[get("/me/consents")]
+async fn get_my_consents(db: Data<Database>, req: HttpRequest) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    match service::get_consent_status(&db, jwt_payload.user_id).await {
+        Ok(status) => {
+            let response: ApiResponse<ConsentStatus> =
+                ApiResponse::success("Consents were successfully retrieved.", Some(status));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the consents.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/terms/accept",
+    tag = "Protected Endpoints",
+    summary = "Accept the current terms of service",
+    description = "Records the acceptance of the current terms of service by the current user, with the Prompt source.",
+    request_body = AcceptTermsRequest,
+    responses(
+        (status = 200, description = "Terms of service were successfully accepted.", body = DocSuccessApiResponse<TermsAcceptance>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to accept the terms of service.", body = DocErrorApiResponse)
+    )
+)]
+#[post("/me/terms/accept")]
+async fn accept_terms(
+    db: Data<Database>,
+    payload: Json<AcceptTermsRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    let payload = payload.into_inner();
+    match service::accept_terms(&db, jwt_payload.user_id, payload).await {
+        Ok(acceptance) => {
+            let response: ApiResponse<TermsAcceptance> = ApiResponse::success(
+                "Terms of service were successfully accepted.",
+                Some(acceptance),
+            );
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to accept the terms of service.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    put,
+    path = "/api/users/me/marketing-consent",
+    tag = "Protected Endpoints",
+    summary = "Update current user's marketing consent",
+    description = "Grants or withdraws the marketing consent of the current user. Every change is recorded with its timestamp and the Settings source.",
+    request_body = UpdateMarketingConsentRequest,
+    responses(
+        (status = 200, description = "Marketing consent was successfully updated.", body = DocSuccessApiResponse<MarketingConsent>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to update the marketing consent.", body = DocErrorApiResponse)
+    )
+)]
+#[put("/me/marketing-consent")]
+async fn update_marketing_consent(
+    db: Data<Database>,
+    payload: Json<UpdateMarketingConsentRequest>,
+    req: HttpRequest,
+) -> impl Responder {
+    let jwt_payload = match get_authenticated_user(&req) {
+        Ok(payload) => payload,
+        Err(err_res) => return err_res,
+    };
+
+    match service::update_marketing_consent(&db, jwt_payload.user_id, payload.granted).await {
+        Ok(consent) => {
+            let response: ApiResponse<MarketingConsent> =
+                ApiResponse::success("Marketing consent was successfully updated.", Some(consent));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to update the marketing consent.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/users/{id}/consents",
+    tag = "Protected Endpoints",
+    summary = "Retrieve the consent history of a user",
+    description = "Fetches every terms of service acceptance and marketing consent change recorded for a user. Access is restricted to Admin or Operator roles.",
+    responses(
+        (status = 200, description = "Consent history was successfully retrieved.", body = DocSuccessApiResponse<ConsentHistory>),
+        (status = 401, description = "Error: Unauthorized", body = DocErrorApiResponse),
+        (status = 500, description = "Failed to retrieve the consent history.", body = DocErrorApiResponse)
+    ),
+    params(
+        ("id" = String, Path, description = "User ID")
+    )
+)]
+#[get("/{id}/consents")]
+async fn get_user_consent_history(
+    db: Data<Database>,
+    id: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let required_roles = &[AuthRole::Admin, AuthRole::Operator];
+    match user_has_any_of_these_roles(&req, required_roles) {
+        Ok(claims) => claims,
+        Err(err_res) => return err_res,
+    };
+
+    let id = id.into_inner();
+    match service::get_consent_history(&db, id).await {
+        Ok(history) => {
+            let response: ApiResponse<ConsentHistory> =
+                ApiResponse::success("Consent history was successfully retrieved.", Some(history));
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => {
+            let response: ApiResponse<()> =
+                ApiResponse::error("Failed to retrieve the consent history.", e.to_string());
+            HttpResponse::InternalServerError().json(response)
+        }
+    }
+}
+
+// =============================================================================================================================
@@ -0,0 +1,23 @@
+use actix_rt::spawn;
+use mongodb::Database;
+use tokio_schedule::{Job, every};
+
+use crate::service::purge_deleted_users;
+
+// =============================================================================================================================
+
+pub async fn cron_jobs(db: Database) {
+    let every_hour: std::pin::Pin<Box<dyn Future<Output = ()> + Send>> =
+        every(1).hour().perform(move || {
+            let local_db = db.clone();
+            async move {
+                println!("Running cron job: Purge deleted users");
+                if let Err(e) = purge_deleted_users(&local_db).await {
+                    eprintln!("Erreur dans le cron job: {:?}", e);
+                }
+            }
+        });
+    spawn(every_hour);
+}
+
+// =============================================================================================================================
@@ -2,11 +2,19 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder};
 use utoipa::{Modify, OpenApi, openapi::security::SecurityScheme};
 
 use crate::controller::{
-    __path_create_user, __path_delete_user, __path_get_me, __path_get_user_by_id,
-    __path_get_user_id_by_email, __path_get_users, __path_health_check, __path_update_me,
+    __path_accept_terms, __path_create_terms, __path_create_user, __path_delete_user,
+    __path_get_current_terms, __path_get_deleted_users, __path_get_me, __path_get_my_consents,
+    __path_get_terms, __path_get_user_by_id, __path_get_user_consent_history,
+    __path_get_user_id_by_email, __path_get_users, __path_health_check, __path_import_users,
+    __path_restore_user, __path_search_users, __path_update_marketing_consent, __path_update_me,
     __path_update_user_by_id,
 };
-use crate::model::{CreateUserRequest, GetUserIdByEmailRequest, UpdateUserRequest, User};
+use crate::model::{
+    AcceptTermsRequest, ConsentHistory, ConsentSource, ConsentStatus, CreateTermsRequest,
+    CreateUserRequest, GetUserIdByEmailRequest, MarketingConsent, TermsAcceptance, TermsDocument,
+    UpdateMarketingConsentRequest, UpdateUserRequest, User, UserImportReport, UserImportRowReport,
+    UserImportStatus, UserSearchResponse, UserSearchResult,
+};
 use common::models::AuthRole;
 
 // =============================================================================================================================
@@ -24,13 +32,24 @@ It provides endpoints for retrieving, creating, updating, and deleting user prof
     paths(
         health_check,
         get_users,
+        search_users,
         get_user_id_by_email,
         get_me,
         get_user_by_id,
         create_user,
+        import_users,
         update_me,
         update_user_by_id,
-        delete_user
+        delete_user,
+        get_deleted_users,
+        restore_user,
+        get_terms,
+        get_current_terms,
+        create_terms,
+        get_my_consents,
+        accept_terms,
+        update_marketing_consent,
+        get_user_consent_history
     ),
     components(
         schemas(
@@ -38,6 +57,20 @@ It provides endpoints for retrieving, creating, updating, and deleting user prof
             GetUserIdByEmailRequest,
             CreateUserRequest,
             UpdateUserRequest,
+            UserSearchResult,
+            UserSearchResponse,
+            UserImportReport,
+            UserImportRowReport,
+            UserImportStatus,
+            TermsDocument,
+            CreateTermsRequest,
+            TermsAcceptance,
+            AcceptTermsRequest,
+            MarketingConsent,
+            UpdateMarketingConsentRequest,
+            ConsentStatus,
+            ConsentHistory,
+            ConsentSource,
             AuthRole,
         )
     ),
@@ -1,7 +1,7 @@
 use actix_web::{
     HttpResponse,
     error::InternalError,
-    web::{self, JsonConfig},
+    web::{self, JsonConfig, QueryConfig},
 };
 use common::utils::api_response::ApiResponse;
 
@@ -15,3 +15,12 @@ pub fn deserialize_error_extractor() -> JsonConfig {
 }
 
 // =============================================================================================================================
+
+pub fn deserialize_query_error_extractor() -> QueryConfig {
+    web::QueryConfig::default().error_handler(|err, _req| {
+        let api_response = ApiResponse::<()>::error("Invalid query parameters", err.to_string());
+        InternalError::from_response(err, HttpResponse::BadRequest().json(api_response)).into()
+    })
+}
+
+// =============================================================================================================================
@@ -1,10 +1,14 @@
+use common::models::AuthRole;
 use common::utils::utils::{
-    LETTERS_REGEX, serialize_option_object_id_as_hex_string, trim_lowercase,
+    LETTERS_REGEX, deserialize_datetime_from_any, deserialize_option_datetime_from_any,
+    serialize_option_datetime_as_rfc3339_string, serialize_option_object_id_as_hex_string, trim,
+    trim_lowercase,
 };
-use mongodb::bson::oid::ObjectId;
+use mongodb::bson::serde_helpers::serialize_bson_datetime_as_rfc3339_string;
+use mongodb::bson::{DateTime, oid::ObjectId};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
-use validator::Validate;
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
 
 // =============================================================================================================================
 
@@ -27,6 +31,18 @@ pub struct User {
 
     #[schema(example = "john.doe@example.com", value_type = String)]
     pub email: String,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_datetime_from_any",
+        serialize_with = "serialize_option_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T09:00:00.000Z", value_type = String)]
+    pub deleted_at: Option<DateTime>,
+
+    #[serde(default, serialize_with = "serialize_option_object_id_as_hex_string")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub deleted_by: Option<ObjectId>,
 }
 
 // =============================================================================================================================
@@ -73,6 +89,14 @@ pub struct CreateUserRequest {
     #[validate(email(message = "Email must be valid"))]
     #[schema(example = "john.doe@example.com", value_type = String)]
     pub email: String,
+
+    #[serde(default)]
+    #[schema(example = "2025-03", value_type = Option<String>)]
+    pub terms_version: Option<String>,
+
+    #[serde(default)]
+    #[schema(example = false)]
+    pub marketing_consent: Option<bool>,
 }
 
 // =============================================================================================================================
@@ -104,3 +128,334 @@ pub struct UpdateUserRequest {
 }
 
 // =============================================================================================================================
+
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+#[validate(schema(function = "validate_search_users_query", skip_on_field_errors = false))]
+pub struct SearchUsersQuery {
+    #[validate(length(max = 100, message = "Search query must be at most 100 characters"))]
+    #[param(example = "john do")]
+    pub q: Option<String>,
+
+    #[param(example = "EventCreator", value_type = Option<String>)]
+    pub role: Option<AuthRole>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[param(example = "2025-01-01T00:00:00Z", value_type = Option<String>)]
+    pub registered_from: Option<DateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_option_datetime_from_any")]
+    #[param(example = "2025-12-31T23:59:59Z", value_type = Option<String>)]
+    pub registered_to: Option<DateTime>,
+
+    #[validate(range(min = 1, message = "Page must be at least one."))]
+    #[param(example = 1)]
+    pub page: Option<u64>,
+
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100."))]
+    #[param(example = 20)]
+    pub limit: Option<i64>,
+}
+
+impl SearchUsersQuery {
+    pub fn page(&self) -> u64 {
+        self.page.unwrap_or(1)
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(20)
+    }
+
+    // None when the page is too far for the number of users to skip to fit in the database.
+    pub fn offset(&self) -> Option<u64> {
+        self.page()
+            .saturating_sub(1)
+            .checked_mul(self.limit().max(0) as u64)
+            .filter(|offset| *offset <= i64::MAX as u64)
+    }
+}
+
+fn validate_search_users_query(query: &SearchUsersQuery) -> Result<(), ValidationError> {
+    if query.offset().is_none() {
+        let mut error = ValidationError::new("page_out_of_range");
+        error.message = Some("Page is out of range.".into());
+        return Err(error);
+    }
+    Ok(())
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserSearchResult {
+    #[serde(
+        rename = "id",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "63f7b1c0a1234567890abcdef", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[schema(example = "john", value_type = String)]
+    pub first_name: String,
+
+    #[schema(example = "doe", value_type = String)]
+    pub last_name: String,
+
+    #[schema(example = "john.doe@example.com", value_type = String)]
+    pub email: String,
+
+    #[schema(example = "User", value_type = String)]
+    pub role: Option<AuthRole>,
+
+    #[serde(serialize_with = "serialize_bson_datetime_as_rfc3339_string")]
+    #[schema(example = "2025-03-23T08:37:10.000Z", value_type = String)]
+    pub registered_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserSearchResponse {
+    pub users: Vec<UserSearchResult>,
+
+    #[schema(example = 1)]
+    pub page: u64,
+
+    #[schema(example = 20)]
+    pub limit: i64,
+
+    #[schema(example = 1250)]
+    pub total: u64,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserRoleInternalResponse {
+    #[serde(rename = "user_id")]
+    pub user_id: String,
+    pub role: AuthRole,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub enum UserImportStatus {
+    Imported,
+    Failed,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserImportRowReport {
+    #[schema(example = 1)]
+    pub row: usize,
+
+    #[schema(example = "john.doe@example.com", value_type = Option<String>)]
+    pub email: Option<String>,
+
+    #[schema(example = "Imported", value_type = String)]
+    pub status: UserImportStatus,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "63f7b1c0a1234567890abcdef", value_type = Option<String>)]
+    pub user_id: Option<ObjectId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "A user with this email already exists", value_type = Option<String>)]
+    pub error: Option<String>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserImportReport {
+    #[schema(example = 30)]
+    pub total: usize,
+
+    #[schema(example = 28)]
+    pub imported: usize,
+
+    #[schema(example = 2)]
+    pub failed: usize,
+
+    pub rows: Vec<UserImportRowReport>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum ConsentSource {
+    Registration,
+    Prompt,
+    Settings,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TermsDocument {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "63f7b1c0a1234567890abcdef", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[schema(example = "2025-03", value_type = String)]
+    pub version: String,
+
+    #[schema(example = "By using 4WEBD you agree to...", value_type = String)]
+    pub content: String,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-01T00:00:00.000Z", value_type = String)]
+    pub published_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateTermsRequest {
+    #[serde(deserialize_with = "trim")]
+    #[validate(length(
+        min = 1,
+        max = 20,
+        message = "Version must be between 1 and 20 characters"
+    ))]
+    #[schema(example = "2025-03", value_type = String)]
+    pub version: String,
+
+    #[serde(deserialize_with = "trim")]
+    #[validate(length(min = 10, message = "Content must be at least 10 characters"))]
+    #[schema(example = "By using 4WEBD you agree to...", value_type = String)]
+    pub content: String,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TermsAcceptance {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "63f7b1c0a1234567890abcdef", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "user_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[schema(example = "2025-03", value_type = String)]
+    pub version: String,
+
+    #[schema(example = "Registration", value_type = String)]
+    pub source: ConsentSource,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T08:37:10.975Z", value_type = String)]
+    pub accepted_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AcceptTermsRequest {
+    #[serde(deserialize_with = "trim")]
+    #[validate(length(
+        min = 1,
+        max = 20,
+        message = "Version must be between 1 and 20 characters"
+    ))]
+    #[schema(example = "2025-03", value_type = String)]
+    pub version: String,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MarketingConsent {
+    #[serde(
+        rename = "id",
+        alias = "_id",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_object_id_as_hex_string"
+    )]
+    #[schema(example = "63f7b1c0a1234567890abcdef", value_type = String)]
+    pub id: Option<ObjectId>,
+
+    #[serde(rename = "user_id")]
+    #[schema(example = "63d88106c3f7903ba0f9211a", value_type = String)]
+    pub user_id: ObjectId,
+
+    #[schema(example = true)]
+    pub granted: bool,
+
+    #[schema(example = "Settings", value_type = String)]
+    pub source: ConsentSource,
+
+    #[serde(
+        deserialize_with = "deserialize_datetime_from_any",
+        serialize_with = "serialize_bson_datetime_as_rfc3339_string"
+    )]
+    #[schema(example = "2025-03-23T08:37:10.975Z", value_type = String)]
+    pub recorded_at: DateTime,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateMarketingConsentRequest {
+    #[schema(example = true)]
+    pub granted: bool,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ConsentStatus {
+    #[schema(example = "2025-03", value_type = Option<String>)]
+    pub current_terms_version: Option<String>,
+
+    #[schema(example = "2024-09", value_type = Option<String>)]
+    pub accepted_terms_version: Option<String>,
+
+    #[serde(serialize_with = "serialize_option_datetime_as_rfc3339_string")]
+    #[schema(example = "2024-09-12T10:00:00.000Z", value_type = Option<String>)]
+    pub terms_accepted_at: Option<DateTime>,
+
+    #[schema(example = true)]
+    pub must_accept_terms: bool,
+
+    #[schema(example = false)]
+    pub marketing_consent: bool,
+
+    #[serde(serialize_with = "serialize_option_datetime_as_rfc3339_string")]
+    #[schema(example = "2024-09-12T10:00:00.000Z", value_type = Option<String>)]
+    pub marketing_consent_updated_at: Option<DateTime>,
+}
+
+// =============================================================================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ConsentHistory {
+    pub terms_acceptances: Vec<TermsAcceptance>,
+    pub marketing_consents: Vec<MarketingConsent>,
+}
+
+// =============================================================================================================================
@@ -1,25 +1,144 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
-use common::{jwt::internal::encode_internal_jwt, utils::api_response::ApiResponse};
+use common::{
+    jwt::internal::encode_internal_jwt,
+    models::AuthRole,
+    utils::{api_response::ApiResponse, utils::soft_delete_purge_cutoff},
+};
 use futures_util::TryStreamExt;
 use mongodb::{
     Collection, Cursor, Database,
-    bson::{doc, oid::ObjectId, to_document},
+    bson::{DateTime, Document, doc, oid::ObjectId, to_document},
     options::ReturnDocument,
 };
+use serde_json::json;
 use validator::Validate;
 
-use crate::model::{CreateUserRequest, GetUserIdByEmailRequest, UpdateUserRequest, User};
+use crate::model::{
+    AcceptTermsRequest, ConsentHistory, ConsentSource, ConsentStatus, CreateTermsRequest,
+    CreateUserRequest, GetUserIdByEmailRequest, MarketingConsent, SearchUsersQuery,
+    TermsAcceptance, TermsDocument, UpdateUserRequest, User, UserImportReport, UserImportRowReport,
+    UserImportStatus, UserRoleInternalResponse, UserSearchResponse, UserSearchResult,
+};
 
 // =============================================================================================================================
 
 const COLLECTION_NAME: &str = "users";
+const TERMS_COLLECTION_NAME: &str = "terms";
+const TERMS_ACCEPTANCES_COLLECTION_NAME: &str = "terms_acceptances";
+const MARKETING_CONSENTS_COLLECTION_NAME: &str = "marketing_consents";
+const MAX_IMPORTED_USERS: usize = 500;
 
 // =============================================================================================================================
 
 pub async fn get_users(db: &Database) -> Result<Vec<User>, Box<dyn std::error::Error>> {
     let collection: Collection<User> = db.collection(COLLECTION_NAME);
-    let cursor: Cursor<User> = collection.find(doc! {}).await?;
+    let cursor: Cursor<User> = collection.find(doc! { "deleted_at": null }).await?;
+    let users: Vec<User> = cursor.try_collect().await?;
+
+    Ok(users)
+}
+
+// =============================================================================================================================
+
+pub async fn search_users(
+    db: &Database,
+    query: SearchUsersQuery,
+) -> Result<UserSearchResponse, Box<dyn std::error::Error>> {
+    query.validate()?;
+
+    let page = query.page();
+    let limit = query.limit();
+    let offset = query.offset().ok_or("Page is out of range.")?;
+
+    let mut conditions: Vec<Document> = vec![doc! { "deleted_at": null }];
+
+    if let Some(q) = &query.q {
+        for term in q.split_whitespace() {
+            let pattern = format!("^{}", regex::escape(&term.to_lowercase()));
+            conditions.push(doc! {
+                "$or": [
+                    { "first_name": { "$regex": &pattern } },
+                    { "last_name": { "$regex": &pattern } },
+                    { "email": { "$regex": &pattern } },
+                ]
+            });
+        }
+    }
+
+    if let Some(registered_from) = query.registered_from {
+        let lower_bound = object_id_from_datetime(registered_from, 0x00);
+        conditions.push(doc! { "_id": { "$gte": lower_bound } });
+    }
+
+    if let Some(registered_to) = query.registered_to {
+        let upper_bound = object_id_from_datetime(registered_to, 0xff);
+        conditions.push(doc! { "_id": { "$lte": upper_bound } });
+    }
+
+    if let Some(role) = query.role {
+        // Almost every account is a plain User, so it is cheaper to exclude the few elevated ones.
+        let role_condition = match role {
+            AuthRole::User => {
+                let elevated_roles = [AuthRole::EventCreator, AuthRole::Operator, AuthRole::Admin];
+                let user_ids = get_user_ids_by_roles(&elevated_roles).await?;
+                doc! { "_id": { "$nin": user_ids } }
+            }
+            role => {
+                let user_ids = get_user_ids_by_roles(&[role]).await?;
+                doc! { "_id": { "$in": user_ids } }
+            }
+        };
+        conditions.push(role_condition);
+    }
+
+    let filter = doc! { "$and": conditions };
+    let collection: Collection<User> = db.collection(COLLECTION_NAME);
+
+    let total = collection.count_documents(filter.clone()).await?;
+    let users: Vec<User> = collection
+        .find(filter)
+        .sort(doc! { "last_name": 1, "first_name": 1, "_id": 1 })
+        .skip(offset)
+        .limit(limit)
+        .await?
+        .try_collect()
+        .await?;
+
+    let user_ids: Vec<ObjectId> = users.iter().filter_map(|user| user.id).collect();
+    let roles = get_roles_by_user_ids(&user_ids).await?;
+
+    let users = users
+        .into_iter()
+        .filter_map(|user| {
+            let id = user.id?;
+            Some(UserSearchResult {
+                id: Some(id),
+                role: roles.get(&id.to_hex()).copied(),
+                registered_at: id.timestamp(),
+                first_name: user.first_name,
+                last_name: user.last_name,
+                email: user.email,
+            })
+        })
+        .collect();
+
+    Ok(UserSearchResponse {
+        users,
+        page,
+        limit,
+        total,
+    })
+}
+
+// =============================================================================================================================
+
+pub async fn get_deleted_users(db: &Database) -> Result<Vec<User>, Box<dyn std::error::Error>> {
+    let collection: Collection<User> = db.collection(COLLECTION_NAME);
+    let cursor: Cursor<User> = collection
+        .find(doc! { "deleted_at": { "$ne": null } })
+        .sort(doc! { "deleted_at": -1 })
+        .await?;
     let users: Vec<User> = cursor.try_collect().await?;
 
     Ok(users)
@@ -31,7 +150,10 @@ pub async fn get_user_by_id(db: &Database, id: String) -> Result<User, Box<dyn s
     let id = ObjectId::from_str(&id)?;
     let collection: Collection<User> = db.collection(COLLECTION_NAME);
 
-    match collection.find_one(doc! { "_id": id }).await? {
+    match collection
+        .find_one(doc! { "_id": id, "deleted_at": null })
+        .await?
+    {
         Some(user) => Ok(user),
         None => Err("No user found with the given id".into()),
     }
@@ -45,7 +167,10 @@ pub async fn get_user_id_by_email(
 ) -> Result<ObjectId, Box<dyn std::error::Error>> {
     let collection: Collection<User> = db.collection("users");
 
-    match collection.find_one(doc! { "email": payload.email }).await? {
+    match collection
+        .find_one(doc! { "email": payload.email, "deleted_at": null })
+        .await?
+    {
         Some(user) => match user.id {
             Some(id) => Ok(id),
             None => Err("No user_id found".into()),
@@ -68,6 +193,8 @@ pub async fn create_user(
         first_name: payload.first_name,
         last_name: payload.last_name,
         email: payload.email,
+        deleted_at: None,
+        deleted_by: None,
     };
 
     let res = collection.insert_one(&user).await?;
@@ -78,6 +205,169 @@ pub async fn create_user(
 
 // =============================================================================================================================
 
+pub async fn register_user(
+    db: &Database,
+    payload: CreateUserRequest,
+) -> Result<User, Box<dyn std::error::Error>> {
+    payload.validate()?;
+
+    let current_terms = find_current_terms(db).await?;
+    if let Some(current_terms) = &current_terms {
+        match &payload.terms_version {
+            Some(version) if *version == current_terms.version => {}
+            Some(_) => return Err("The accepted terms version is not the current one".into()),
+            None => return Err("The current terms of service must be accepted".into()),
+        }
+    }
+
+    let marketing_consent = payload.marketing_consent;
+    let user = create_user(db, payload).await?;
+    let Some(user_id) = user.id else {
+        return Err("No user_id found".into());
+    };
+
+    if let Some(current_terms) = current_terms {
+        record_terms_acceptance(
+            db,
+            user_id,
+            current_terms.version,
+            ConsentSource::Registration,
+        )
+        .await?;
+    }
+
+    if let Some(granted) = marketing_consent {
+        record_marketing_consent(db, user_id, granted, ConsentSource::Registration).await?;
+    }
+
+    Ok(user)
+}
+
+// =============================================================================================================================
+
+pub fn parse_users_csv(
+    body: &[u8],
+) -> Result<Vec<Result<CreateUserRequest, String>>, Box<dyn std::error::Error>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(body);
+
+    let rows = reader
+        .deserialize::<CreateUserRequest>()
+        .map(|row| row.map_err(|e| e.to_string()))
+        .collect();
+
+    Ok(rows)
+}
+
+// =============================================================================================================================
+
+pub fn parse_users_json(
+    body: &[u8],
+) -> Result<Vec<Result<CreateUserRequest, String>>, Box<dyn std::error::Error>> {
+    let rows: Vec<serde_json::Value> = serde_json::from_slice(body)?;
+
+    let rows = rows
+        .into_iter()
+        .map(|row| serde_json::from_value::<CreateUserRequest>(row).map_err(|e| e.to_string()))
+        .collect();
+
+    Ok(rows)
+}
+
+// =============================================================================================================================
+
+pub async fn import_users(
+    db: &Database,
+    rows: Vec<Result<CreateUserRequest, String>>,
+) -> Result<UserImportReport, Box<dyn std::error::Error>> {
+    if rows.is_empty() {
+        return Err("The import does not contain any user".into());
+    }
+
+    if rows.len() > MAX_IMPORTED_USERS {
+        return Err(format!(
+            "An import cannot contain more than {} users",
+            MAX_IMPORTED_USERS
+        )
+        .into());
+    }
+
+    let collection: Collection<User> = db.collection(COLLECTION_NAME);
+    let mut reports: Vec<UserImportRowReport> = Vec::with_capacity(rows.len());
+
+    for (index, row) in rows.into_iter().enumerate() {
+        let row_number = index + 1;
+        let email = row.as_ref().ok().map(|user| user.email.clone());
+
+        let result = match row {
+            Ok(user) => import_user(db, &collection, user).await,
+            Err(e) => Err(e.into()),
+        };
+
+        reports.push(match result {
+            Ok(user_id) => UserImportRowReport {
+                row: row_number,
+                email,
+                status: UserImportStatus::Imported,
+                user_id: Some(user_id),
+                error: None,
+            },
+            Err(e) => UserImportRowReport {
+                row: row_number,
+                email,
+                status: UserImportStatus::Failed,
+                user_id: None,
+                error: Some(e.to_string()),
+            },
+        });
+    }
+
+    let imported = reports
+        .iter()
+        .filter(|report| matches!(report.status, UserImportStatus::Imported))
+        .count();
+
+    Ok(UserImportReport {
+        total: reports.len(),
+        imported,
+        failed: reports.len() - imported,
+        rows: reports,
+    })
+}
+
+// =============================================================================================================================
+
+async fn import_user(
+    db: &Database,
+    collection: &Collection<User>,
+    user: CreateUserRequest,
+) -> Result<ObjectId, Box<dyn std::error::Error>> {
+    user.validate()?;
+
+    if collection
+        .count_documents(doc! { "email": &user.email })
+        .await?
+        > 0
+    {
+        return Err("A user with this email already exists".into());
+    }
+
+    let user = create_user(db, user).await?;
+    let Some(user_id) = user.id else {
+        return Err("No user_id found".into());
+    };
+
+    if let Err(e) = create_invitation_request(user_id).await {
+        collection.delete_one(doc! { "_id": user_id }).await?;
+        return Err(e);
+    }
+
+    Ok(user_id)
+}
+
+// =============================================================================================================================
+
 pub async fn update_user_by_id(
     db: &Database,
     id: String,
@@ -88,7 +378,10 @@ pub async fn update_user_by_id(
     let update_doc = to_document(&user)?;
 
     match collection
-        .find_one_and_update(doc! { "_id": id }, doc! { "$set": update_doc })
+        .find_one_and_update(
+            doc! { "_id": id, "deleted_at": null },
+            doc! { "$set": update_doc },
+        )
         .return_document(ReturnDocument::After)
         .await?
     {
@@ -99,20 +392,150 @@ pub async fn update_user_by_id(
 
 // =============================================================================================================================
 
-pub async fn delete_user(db: &Database, id: String) -> Result<User, Box<dyn std::error::Error>> {
+pub async fn delete_user(
+    db: &Database,
+    id: String,
+    deleted_by: String,
+) -> Result<User, Box<dyn std::error::Error>> {
     let id = ObjectId::from_str(&id)?;
+    let deleted_by = ObjectId::from_str(&deleted_by)?;
     let collection: Collection<User> = db.collection(COLLECTION_NAME);
-    match collection.find_one_and_delete(doc! { "_id": id }).await? {
-        Some(user) => {
-            delete_auth_by_user_id(user.id.unwrap()).await?;
-            Ok(user)
+
+    let update_doc = doc! {
+        "$set": {
+            "deleted_at": DateTime::now(),
+            "deleted_by": deleted_by
         }
+    };
+
+    match collection
+        .find_one_and_update(doc! { "_id": id, "deleted_at": null }, update_doc)
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(user) => Ok(user),
         None => Err("No user found with the given id".into()),
     }
 }
 
 // =============================================================================================================================
 
+pub async fn restore_user(db: &Database, id: String) -> Result<User, Box<dyn std::error::Error>> {
+    let id = ObjectId::from_str(&id)?;
+    let collection: Collection<User> = db.collection(COLLECTION_NAME);
+
+    let update_doc = doc! {
+        "$set": {
+            "deleted_at": null,
+            "deleted_by": null
+        }
+    };
+
+    match collection
+        .find_one_and_update(
+            doc! { "_id": id, "deleted_at": { "$ne": null } },
+            update_doc,
+        )
+        .return_document(ReturnDocument::After)
+        .await?
+    {
+        Some(user) => Ok(user),
+        None => Err("No deleted user found with the given id".into()),
+    }
+}
+
+// =============================================================================================================================
+
+pub async fn purge_deleted_users(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    let collection: Collection<User> = db.collection(COLLECTION_NAME);
+    let filter = doc! { "deleted_at": { "$lt": soft_delete_purge_cutoff() } };
+
+    let users: Vec<User> = collection.find(filter).await?.try_collect().await?;
+
+    for user in users {
+        let Some(user_id) = user.id else { continue };
+
+        if let Err(e) = delete_auth_by_user_id(user_id).await {
+            eprintln!(
+                "Failed to delete credentials of purged user {}: {}",
+                user_id.to_hex(),
+                e
+            );
+            continue;
+        }
+
+        collection.delete_one(doc! { "_id": user_id }).await?;
+    }
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+fn object_id_from_datetime(date: DateTime, fill: u8) -> ObjectId {
+    let seconds = (date.timestamp_millis() / 1000).clamp(0, u32::MAX as i64) as u32;
+
+    let mut bytes = [fill; 12];
+    bytes[..4].copy_from_slice(&seconds.to_be_bytes());
+
+    ObjectId::from_bytes(bytes)
+}
+
+// =============================================================================================================================
+
+async fn get_user_ids_by_roles(
+    roles: &[AuthRole],
+) -> Result<Vec<ObjectId>, Box<dyn std::error::Error>> {
+    let user_roles = get_user_roles_request(json!({ "roles": roles })).await?;
+
+    let user_ids = user_roles
+        .iter()
+        .map(|user_role| ObjectId::parse_str(&user_role.user_id))
+        .collect::<Result<Vec<ObjectId>, _>>()?;
+
+    Ok(user_ids)
+}
+
+// =============================================================================================================================
+
+async fn get_roles_by_user_ids(
+    user_ids: &[ObjectId],
+) -> Result<HashMap<String, AuthRole>, Box<dyn std::error::Error>> {
+    if user_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let user_ids: Vec<String> = user_ids.iter().map(|id| id.to_hex()).collect();
+    let user_roles = get_user_roles_request(json!({ "user_ids": user_ids })).await?;
+
+    Ok(user_roles
+        .into_iter()
+        .map(|user_role| (user_role.user_id, user_role.role))
+        .collect())
+}
+
+// =============================================================================================================================
+
+async fn get_user_roles_request(
+    payload: serde_json::Value,
+) -> Result<Vec<UserRoleInternalResponse>, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .post("http://auth-service:8080/api/auth/roles")
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&payload)
+        .send()
+        .await?
+        .json::<ApiResponse<Vec<UserRoleInternalResponse>>>()
+        .await?;
+
+    Ok(res.into_result()?.unwrap_or_default())
+}
+
+// =============================================================================================================================
+
 async fn delete_auth_by_user_id(user_id: ObjectId) -> Result<(), Box<dyn std::error::Error>> {
     let user_id = user_id.to_hex();
 
@@ -134,3 +557,237 @@ async fn delete_auth_by_user_id(user_id: ObjectId) -> Result<(), Box<dyn std::er
 }
 
 // =============================================================================================================================
+
+async fn create_invitation_request(user_id: ObjectId) -> Result<(), Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let internal_token = encode_internal_jwt()?;
+
+    let res = client
+        .post("http://auth-service:8080/api/auth/invitations")
+        .header("Authorization", format!("Bearer {}", internal_token))
+        .json(&json!({ "user_id": user_id.to_hex() }))
+        .send()
+        .await?
+        .json::<ApiResponse<serde_json::Value>>()
+        .await?;
+
+    res.into_result()?;
+
+    Ok(())
+}
+
+// =============================================================================================================================
+
+pub async fn get_terms(db: &Database) -> Result<Vec<TermsDocument>, Box<dyn std::error::Error>> {
+    let collection: Collection<TermsDocument> = db.collection(TERMS_COLLECTION_NAME);
+    let cursor: Cursor<TermsDocument> = collection
+        .find(doc! {})
+        .sort(doc! { "published_at": -1 })
+        .await?;
+    let terms: Vec<TermsDocument> = cursor.try_collect().await?;
+
+    Ok(terms)
+}
+
+// =============================================================================================================================
+
+pub async fn get_current_terms(db: &Database) -> Result<TermsDocument, Box<dyn std::error::Error>> {
+    match find_current_terms(db).await? {
+        Some(terms) => Ok(terms),
+        None => Err("No terms of service have been published yet".into()),
+    }
+}
+
+// =============================================================================================================================
+
+async fn find_current_terms(
+    db: &Database,
+) -> Result<Option<TermsDocument>, Box<dyn std::error::Error>> {
+    let collection: Collection<TermsDocument> = db.collection(TERMS_COLLECTION_NAME);
+    let terms = collection
+        .find_one(doc! {})
+        .sort(doc! { "published_at": -1 })
+        .await?;
+
+    Ok(terms)
+}
+
+// =============================================================================================================================
+
+pub async fn create_terms(
+    db: &Database,
+    payload: CreateTermsRequest,
+) -> Result<TermsDocument, Box<dyn std::error::Error>> {
+    payload.validate()?;
+
+    let collection: Collection<TermsDocument> = db.collection(TERMS_COLLECTION_NAME);
+
+    if collection
+        .count_documents(doc! { "version": &payload.version })
+        .await?
+        > 0
+    {
+        return Err("A terms document with this version already exists".into());
+    }
+
+    let mut terms = TermsDocument {
+        id: None,
+        version: payload.version,
+        content: payload.content,
+        published_at: DateTime::now(),
+    };
+
+    let res = collection.insert_one(&terms).await?;
+    terms.id = res.inserted_id.as_object_id();
+
+    Ok(terms)
+}
+
+// =============================================================================================================================
+
+pub async fn accept_terms(
+    db: &Database,
+    user_id: String,
+    payload: AcceptTermsRequest,
+) -> Result<TermsAcceptance, Box<dyn std::error::Error>> {
+    payload.validate()?;
+
+    let user_id = ObjectId::from_str(&user_id)?;
+    let current_terms = get_current_terms(db).await?;
+
+    if payload.version != current_terms.version {
+        return Err("Only the current terms of service can be accepted".into());
+    }
+
+    record_terms_acceptance(db, user_id, current_terms.version, ConsentSource::Prompt).await
+}
+
+// =============================================================================================================================
+
+async fn record_terms_acceptance(
+    db: &Database,
+    user_id: ObjectId,
+    version: String,
+    source: ConsentSource,
+) -> Result<TermsAcceptance, Box<dyn std::error::Error>> {
+    let collection: Collection<TermsAcceptance> = db.collection(TERMS_ACCEPTANCES_COLLECTION_NAME);
+
+    let mut acceptance = TermsAcceptance {
+        id: None,
+        user_id,
+        version,
+        source,
+        accepted_at: DateTime::now(),
+    };
+
+    let res = collection.insert_one(&acceptance).await?;
+    acceptance.id = res.inserted_id.as_object_id();
+
+    Ok(acceptance)
+}
+
+// =============================================================================================================================
+
+pub async fn update_marketing_consent(
+    db: &Database,
+    user_id: String,
+    granted: bool,
+) -> Result<MarketingConsent, Box<dyn std::error::Error>> {
+    let user_id = ObjectId::from_str(&user_id)?;
+
+    record_marketing_consent(db, user_id, granted, ConsentSource::Settings).await
+}
+
+// =============================================================================================================================
+
+async fn record_marketing_consent(
+    db: &Database,
+    user_id: ObjectId,
+    granted: bool,
+    source: ConsentSource,
+) -> Result<MarketingConsent, Box<dyn std::error::Error>> {
+    let collection: Collection<MarketingConsent> =
+        db.collection(MARKETING_CONSENTS_COLLECTION_NAME);
+
+    let mut consent = MarketingConsent {
+        id: None,
+        user_id,
+        granted,
+        source,
+        recorded_at: DateTime::now(),
+    };
+
+    let res = collection.insert_one(&consent).await?;
+    consent.id = res.inserted_id.as_object_id();
+
+    Ok(consent)
+}
+
+// =============================================================================================================================
+
+pub async fn get_consent_status(
+    db: &Database,
+    user_id: String,
+) -> Result<ConsentStatus, Box<dyn std::error::Error>> {
+    let user_id = ObjectId::from_str(&user_id)?;
+
+    let current_terms = find_current_terms(db).await?;
+
+    let acceptances: Collection<TermsAcceptance> = db.collection(TERMS_ACCEPTANCES_COLLECTION_NAME);
+    let last_acceptance = acceptances
+        .find_one(doc! { "user_id": user_id })
+        .sort(doc! { "accepted_at": -1 })
+        .await?;
+
+    let consents: Collection<MarketingConsent> = db.collection(MARKETING_CONSENTS_COLLECTION_NAME);
+    let last_consent = consents
+        .find_one(doc! { "user_id": user_id })
+        .sort(doc! { "recorded_at": -1 })
+        .await?;
+
+    let current_terms_version = current_terms.map(|terms| terms.version);
+    let accepted_terms_version = last_acceptance.as_ref().map(|a| a.version.clone());
+    let must_accept_terms =
+        current_terms_version.is_some() && current_terms_version != accepted_terms_version;
+
+    Ok(ConsentStatus {
+        current_terms_version,
+        accepted_terms_version,
+        terms_accepted_at: last_acceptance.map(|a| a.accepted_at),
+        must_accept_terms,
+        marketing_consent: last_consent.as_ref().is_some_and(|c| c.granted),
+        marketing_consent_updated_at: last_consent.map(|c| c.recorded_at),
+    })
+}
+
+// =============================================================================================================================
+
+pub async fn get_consent_history(
+    db: &Database,
+    user_id: String,
+) -> Result<ConsentHistory, Box<dyn std::error::Error>> {
+    let user_id = ObjectId::from_str(&user_id)?;
+
+    let acceptances: Collection<TermsAcceptance> = db.collection(TERMS_ACCEPTANCES_COLLECTION_NAME);
+    let terms_acceptances: Vec<TermsAcceptance> = acceptances
+        .find(doc! { "user_id": user_id })
+        .sort(doc! { "accepted_at": -1 })
+        .await?
+        .try_collect()
+        .await?;
+
+    let consents: Collection<MarketingConsent> = db.collection(MARKETING_CONSENTS_COLLECTION_NAME);
+    let marketing_consents: Vec<MarketingConsent> = consents
+        .find(doc! { "user_id": user_id })
+        .sort(doc! { "recorded_at": -1 })
+        .await?
+        .try_collect()
+        .await?;
+
+    Ok(ConsentHistory {
+        terms_acceptances,
+        marketing_consents,
+    })
+}
+
+// =============================================================================================================================